@@ -0,0 +1,60 @@
+// Toplu genişletmenin kazancını ölçer: 5000 küçük engelli dağınık bir haritada ağaç aynı tohumla
+// tek tek (`extend`) ve toplu olarak (`extend_batch`) büyütülür. Engel sayısı arttıkça süreyi
+// çarpışma kontrolleri belirler; paralel denetim yalnızca `rayon` özelliğiyle açılır.
+//
+//   cargo bench --bench batch_extension --features rayon
+
+use std::time::{Duration, Instant};
+
+use rrt_visualization::mapgen::random_clutter;
+use rrt_visualization::{Bounds, NeighborIndexKind, Point, RRT};
+
+const OBSTACLES: usize = 5000;
+const ITERATIONS: usize = 5000;
+const ROUNDS: usize = 3;
+
+fn cluttered_tree() -> RRT {
+    let (start, goal) = (Point { x: 10.0, y: 10.0 }, Point { x: 1e6, y: 1e6 });
+    let mut rrt = RRT::new(start, goal, 10.0, 1.0);
+    rrt.bounds = Bounds { min_x: 0.0, min_y: 0.0, max_x: 2000.0, max_y: 2000.0 };
+    rrt.obstacles = random_clutter(&rrt.bounds, &start, &goal, OBSTACLES, 2.0, 8.0, 3);
+    rrt.set_neighbor_index(NeighborIndexKind::KdTree, 20.0);
+    rrt.reseed(11);
+    rrt
+}
+
+// Her turda ağacı yeniden kurar; en iyi süreyi ve son ağacın düğüm sayısını döndürür
+fn measure(batch_size: usize) -> (Duration, usize) {
+    let mut best = (Duration::MAX, 0);
+    for _ in 0..ROUNDS {
+        let mut rrt = cluttered_tree();
+        let started = Instant::now();
+        if batch_size == 1 {
+            for _ in 0..ITERATIONS {
+                rrt.extend();
+            }
+        } else {
+            for _ in 0..ITERATIONS / batch_size {
+                rrt.extend_batch(batch_size);
+            }
+        }
+        best = (best.0.min(started.elapsed()), rrt.node_count());
+    }
+    best
+}
+
+fn main() {
+    let parallel = if cfg!(feature = "rayon") { "parallel" } else { "sequential (build with --features rayon)" };
+    println!("{} obstacles, {} iterations, best of {} rounds, batches {}", OBSTACLES, ITERATIONS, ROUNDS, parallel);
+    let (baseline, _) = measure(1);
+    for batch_size in [1, 8, 64, 250] {
+        let (time, nodes) = measure(batch_size);
+        println!(
+            "batch {:>4}: {:>9.3} ms, {:>5} nodes, speedup {:.2}x",
+            batch_size,
+            time.as_secs_f64() * 1000.0,
+            nodes,
+            baseline.as_secs_f64() / time.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,81 @@
+// k-d ağacının yeniden dengelemesini ölçer: ağaç bir köşeden çapraz olarak ilerleyen (eklemelerin
+// sıralı geldiği, dolayısıyla ağacın bir yöne doğru derinleştiği) 50 bin noktayla kurulur. Her 10
+// bin eklemede ağacın çevresinden rastgele sorgular yanıtlanır; yeniden dengeleme olmadan sorgu
+// süresi ağaç büyüdükçe artar, dengelemeyle sınırlı kalır. Sorgu sonuçlarının iki ağaçta aynı
+// olduğu da denetlenir.
+//
+//   cargo bench --bench kd_tree_rebalance
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rrt_visualization::{KdTree, Point};
+
+const POINTS: usize = 50_000;
+const CHECKPOINT: usize = 10_000;
+const QUERIES: usize = 2_000;
+const REBALANCE_FRACTION: f32 = 0.3;
+
+// Köşeden çapraza doğru ilerleyen, çevresinde küçük sapmalar olan noktalar
+fn skewed_points(rng: &mut StdRng) -> Vec<Point> {
+    (0..POINTS)
+        .map(|i| {
+            let t = i as f32 * 0.01;
+            Point { x: t + rng.gen_range(-2.0..2.0), y: t + rng.gen_range(-2.0..2.0) }
+        })
+        .collect()
+}
+
+fn query_time(tree: &KdTree, queries: &[Point]) -> (Duration, Vec<Option<usize>>) {
+    let started = Instant::now();
+    let found: Vec<Option<usize>> = queries.iter().map(|q| black_box(tree.nearest(q))).collect();
+    (started.elapsed(), found)
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let points = skewed_points(&mut rng);
+    // Sorgular o ana kadar kurulan ağacın çevresinden çekilir: çapraz boyunca oran ve sapma
+    let offsets: Vec<(f32, Point)> = (0..QUERIES)
+        .map(|_| (rng.gen_range(0.0..1.0), Point { x: rng.gen_range(-20.0..20.0), y: rng.gen_range(-20.0..20.0) }))
+        .collect();
+    let us_per_query = |d: Duration| d.as_secs_f64() * 1e6 / QUERIES as f64;
+
+    let mut plain = KdTree::new();
+    let mut balanced = KdTree::with_rebalance_fraction(REBALANCE_FRACTION);
+    let (mut plain_build, mut balanced_build) = (Duration::ZERO, Duration::ZERO);
+    println!("{} skewed points, {} queries per checkpoint, rebalance fraction {}", POINTS, QUERIES, REBALANCE_FRACTION);
+    for (chunk_index, chunk) in points.chunks(CHECKPOINT).enumerate() {
+        for (offset, &point) in chunk.iter().enumerate() {
+            let index = chunk_index * CHECKPOINT + offset;
+            let started = Instant::now();
+            plain.insert(index, point);
+            plain_build += started.elapsed();
+            let started = Instant::now();
+            balanced.insert(index, point);
+            balanced_build += started.elapsed();
+        }
+        let extent = ((chunk_index + 1) * CHECKPOINT) as f32 * 0.01;
+        let queries: Vec<Point> =
+            offsets.iter().map(|&(u, offset)| Point { x: u * extent + offset.x, y: u * extent + offset.y }).collect();
+        let (plain_query, plain_found) = query_time(&plain, &queries);
+        let (balanced_query, balanced_found) = query_time(&balanced, &queries);
+        assert_eq!(plain_found, balanced_found, "rebalancing changed nearest-neighbour results");
+        println!(
+            "{:>6} points: without rebalance depth {:>6}, query {:>9.3} us | with rebalance depth {:>3}, query {:>7.3} us",
+            (chunk_index + 1) * CHECKPOINT,
+            plain.depth(),
+            us_per_query(plain_query),
+            balanced.depth(),
+            us_per_query(balanced_query)
+        );
+    }
+    println!(
+        "build: without rebalance {:.3} ms, with rebalance {:.3} ms ({} rebuilds)",
+        plain_build.as_secs_f64() * 1000.0,
+        balanced_build.as_secs_f64() * 1000.0,
+        balanced.rebuilds()
+    );
+}
@@ -0,0 +1,61 @@
+// En yakın düğüm indekslerini karşılaştırır: alana düzgün dağılmış 10 bin düğümlük bir ağaç
+// doğrusal tarama, ızgara ve k-d ağacıyla kurulur ve aynı rastgele sorgular yanıtlanır. Kurulum
+// süresi indeksin `add_node` içindeki güncelleme maliyetini, sorgu süresi `find_nearest`i ölçer.
+//
+//   cargo bench --bench nearest_neighbor
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rrt_visualization::{NeighborIndexKind, Point, RRT};
+
+const NODES: usize = 10_000;
+const QUERIES: usize = 10_000;
+const ROUNDS: usize = 5;
+const GRID_CELL_SIZE: f32 = 20.0;
+
+fn random_points(rng: &mut StdRng, n: usize) -> Vec<Point> {
+    (0..n).map(|_| Point { x: rng.gen_range(0.0..400.0), y: rng.gen_range(0.0..400.0) }).collect()
+}
+
+// Ağacı kurar ve sorguları yanıtlar; en iyi kurulum ve sorgu sürelerini döndürür
+fn measure(kind: NeighborIndexKind, points: &[Point], queries: &[Point]) -> (Duration, Duration) {
+    let mut best = (Duration::MAX, Duration::MAX);
+    for _ in 0..ROUNDS {
+        let started = Instant::now();
+        // Hedef hiçbir zaman ulaşılmayacak kadar uzakta tutulur
+        let mut rrt = RRT::with_capacity(points[0], Point { x: 1e6, y: 1e6 }, 10.0, 1.0, points.len());
+        rrt.set_neighbor_index(kind, GRID_CELL_SIZE);
+        for (i, &point) in points.iter().enumerate().skip(1) {
+            rrt.add_node(point, i - 1);
+        }
+        let build = started.elapsed();
+
+        let started = Instant::now();
+        for query in queries {
+            black_box(rrt.find_nearest(query));
+        }
+        best = (best.0.min(build), best.1.min(started.elapsed()));
+    }
+    best
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let points = random_points(&mut rng, NODES);
+    let queries = random_points(&mut rng, QUERIES);
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    let us_per_query = |d: Duration| d.as_secs_f64() * 1e6 / QUERIES as f64;
+
+    println!("{} nodes, {} queries, best of {} rounds", NODES, QUERIES, ROUNDS);
+    for (name, kind) in [
+        ("linear", NeighborIndexKind::Linear),
+        ("grid", NeighborIndexKind::Grid),
+        ("k-d tree", NeighborIndexKind::KdTree),
+    ] {
+        let (build, query) = measure(kind, &points, &queries);
+        println!("{:<9} build {:>8.3} ms, query {:>8.3} us", name, ms(build), us_per_query(query));
+    }
+}
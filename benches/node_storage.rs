@@ -0,0 +1,55 @@
+// Düğüm vektörünü önceden ayırmanın etkisini ölçer: 50 bin düğümlük bir ağaç varsayılan büyüme
+// ile ve `RRT::with_capacity` ile kurulur. Toplam sürenin yanında en yavaş tek ekleme de
+// raporlanır; görselleştirmedeki kare takılmalarına yeniden ayırma anındaki bu duraklamalar yol açar.
+//
+//   cargo bench --bench node_storage
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rrt_visualization::{Point, RRT};
+
+const NODES: usize = 50_000;
+const ROUNDS: usize = 20;
+
+// Kökten başlayıp zincir halinde `NODES` düğüm ekler; örnekleme ve en yakın düğüm araması
+// ölçüme karışmasın diye düğümler doğrudan eklenir. Toplam süreyi ve en yavaş eklemeyi döndürür.
+fn grow(rrt: &mut RRT) -> (Duration, Duration) {
+    let mut slowest = Duration::ZERO;
+    let started = Instant::now();
+    for i in 0..NODES {
+        let point = Point { x: (i % 400) as f32, y: (i / 400) as f32 };
+        let before = Instant::now();
+        rrt.add_node(point, i);
+        slowest = slowest.max(before.elapsed());
+    }
+    (started.elapsed(), slowest)
+}
+
+// Her turda ağacı yeniden oluşturur (ayırma süresi ölçüme dahildir) ve en iyi sonuçları döndürür
+fn best_of<F: FnMut() -> RRT>(mut build: F) -> (Duration, Duration) {
+    let mut best = (Duration::MAX, Duration::MAX);
+    for _ in 0..ROUNDS {
+        let started = Instant::now();
+        let mut rrt = build();
+        let allocation = started.elapsed();
+        let (total, slowest) = grow(&mut rrt);
+        black_box(&rrt);
+        best = (best.0.min(allocation + total), best.1.min(slowest));
+    }
+    best
+}
+
+fn main() {
+    let start = Point { x: 0.0, y: 0.0 };
+    // Hedef hiçbir zaman ulaşılmayacak kadar uzakta tutulur
+    let goal = Point { x: 1e6, y: 1e6 };
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    let us = |d: Duration| d.as_secs_f64() * 1e6;
+
+    let (total, slowest) = best_of(|| RRT::new(start, goal, 10.0, 1.0));
+    println!("{} nodes, best of {} rounds", NODES, ROUNDS);
+    println!("default growth: {:>8.3} ms total, slowest push {:>8.2} us", ms(total), us(slowest));
+    let (total, slowest) = best_of(|| RRT::with_capacity(start, goal, 10.0, 1.0, NODES + 1));
+    println!("with_capacity:  {:>8.3} ms total, slowest push {:>8.2} us", ms(total), us(slowest));
+}
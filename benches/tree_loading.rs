@@ -0,0 +1,48 @@
+// Kaydedilmiş bir ağacı geri yüklemenin süresini ölçer: 100 bin düğümlük rastgele bir ağaç hem
+// ikili biçimde (`RRT::write_binary`) hem TOML oturumu olarak (`RRT::save_session`) yazılır ve
+// her biri yeniden okunur. Okunan ağaçların düğüm sayısının aynı olduğu da denetlenir.
+//
+//   cargo bench --bench tree_loading
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rrt_visualization::{Point, RRT};
+
+const NODES: usize = 100_000;
+const ROUNDS: usize = 5;
+
+// Her turda `load` çağrılır ve en kısa süre döner
+fn best_of<F: FnMut() -> RRT>(mut load: F) -> (Duration, usize) {
+    let mut best = (Duration::MAX, 0);
+    for _ in 0..ROUNDS {
+        let started = Instant::now();
+        let rrt = black_box(load());
+        best = (best.0.min(started.elapsed()), rrt.node_count());
+    }
+    best
+}
+
+fn main() {
+    let mut rrt = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+    let mut rng = StdRng::seed_from_u64(8);
+    for i in 1..NODES {
+        rrt.add_node(Point { x: rng.gen_range(0.0..400.0), y: rng.gen_range(0.0..400.0) }, rng.gen_range(0..i));
+    }
+    let mut bytes = Vec::new();
+    rrt.write_binary(&mut bytes).expect("tree serializes");
+    let path = std::env::temp_dir().join(format!("rrt_tree_loading_{}.toml", std::process::id()));
+    rrt.save_session(&path).expect("session saves");
+    let session_size = std::fs::metadata(&path).map_or(0, |m| m.len());
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+
+    println!("{} nodes, best of {} rounds", NODES, ROUNDS);
+    let (binary, binary_nodes) = best_of(|| RRT::read_binary(bytes.as_slice()).expect("binary tree loads"));
+    println!("binary:  {:>9.3} ms  {:>10} bytes", ms(binary), bytes.len());
+    let (session, session_nodes) = best_of(|| RRT::load_session(&path).expect("session loads"));
+    println!("session: {:>9.3} ms  {:>10} bytes", ms(session), session_size);
+    std::fs::remove_file(&path).expect("session file is removed");
+    assert_eq!(binary_nodes, session_nodes, "both formats restore the same tree");
+}
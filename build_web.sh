@@ -0,0 +1,8 @@
+#!/bin/sh
+# Tarayıcı sürümünü derler ve web/ klasörüne kopyalar.
+# Çalıştırmak için: ./build_web.sh && python3 -m http.server --directory web
+set -e
+
+cargo build --release --target wasm32-unknown-unknown
+cp target/wasm32-unknown-unknown/release/rrt_visualization.wasm web/
+echo "Built web/rrt_visualization.wasm"
@@ -0,0 +1,65 @@
+// Düzlemsel üç eklemli kol için eklem uzayında planlama; çizim yapılmaz.
+// cargo run --release --example arm_3dof
+use std::f32::consts::PI;
+
+use rrt_visualization::{Obstacle, Point, State, StateTree};
+
+// Kökten uca bağlantı uzunlukları
+const LINKS: [f32; 3] = [1.0, 0.8, 0.6];
+// Bağlantılar ve eklem uzayındaki hareketler bu aralıklarla örneklenerek kontrol edilir
+const LINK_RESOLUTION: f32 = 0.05;
+const MOTION_RESOLUTION: f32 = 0.02;
+
+type Joints = [f32; 3];
+
+// İleri kinematik: kökten başlayarak eklem konumları ve uç nokta
+fn joint_positions(q: &Joints) -> [Point; 4] {
+    let mut positions = [Point::default(); 4];
+    let mut angle = 0.0;
+    for (i, length) in LINKS.iter().enumerate() {
+        angle += q[i];
+        positions[i + 1] = positions[i] + Point { x: angle.cos(), y: angle.sin() } * *length;
+    }
+    positions
+}
+
+fn is_state_free(q: &Joints, obstacles: &[Obstacle]) -> bool {
+    joint_positions(q).windows(2).all(|link| {
+        let samples = (link[0].distance(&link[1]) / LINK_RESOLUTION).ceil() as usize;
+        (0..=samples).all(|i| {
+            let p = Point::lerp(link[0], link[1], i as f32 / samples as f32);
+            !obstacles.iter().any(|o| o.contains(&p))
+        })
+    })
+}
+
+fn is_motion_free(from: &Joints, to: &Joints, obstacles: &[Obstacle]) -> bool {
+    let steps = (from.distance(to) / MOTION_RESOLUTION).ceil().max(1.0) as usize;
+    (0..=steps).all(|i| is_state_free(&from.interpolate(to, i as f32 / steps as f32), obstacles))
+}
+
+fn main() {
+    let obstacles = [
+        Obstacle::Circle { center: Point { x: 1.3, y: 1.3 }, radius: 0.3 },
+        Obstacle::Circle { center: Point { x: -1.2, y: 1.0 }, radius: 0.3 },
+    ];
+    let start: Joints = [0.0, 0.0, 0.0];
+    let goal: Joints = [1.6, 0.3, 0.2];
+    assert!(is_state_free(&start, &obstacles) && is_state_free(&goal, &obstacles));
+    // Doğrudan eklem uzayı hareketi birinci engelin içinden geçer
+    println!("straight motion collision free: {}", is_motion_free(&start, &goal, &obstacles));
+
+    let mut tree = StateTree::new(start, goal, [(-PI, PI); 3], 0.1, 0.05);
+    tree.goal_bias = 0.1;
+    tree.reseed(1);
+    let Some(path) = tree.plan(50_000, |a, b| is_motion_free(a, b, &obstacles)) else {
+        println!("no path found with {} nodes", tree.nodes.len());
+        return;
+    };
+    let length = path.windows(2).map(|w| w[0].distance(&w[1])).sum::<f32>();
+    println!("path found: {} nodes in tree, {} waypoints, joint-space length {:.3}", tree.nodes.len(), path.len(), length);
+    for q in &path {
+        let tip = joint_positions(q)[3];
+        println!("  q = ({:6.3}, {:6.3}, {:6.3})  tip = ({:6.3}, {:6.3})", q[0], q[1], q[2], tip.x, tip.y);
+    }
+}
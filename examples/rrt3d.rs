@@ -0,0 +1,152 @@
+// Üç boyutlu kutu içinde RRT; ağaç macroquad'ın 3B çizim hattıyla gösterilir.
+// cargo run --release --example rrt3d [senaryo.toml]
+//
+// Sol fare tuşu basılıyken sürükleyerek etrafa bakılır; W/A/S/D ileri-geri ve yanlara, Q/E aşağı
+// ve yukarı hareket ettirir. R ağacı yeni bir tohumla yeniden başlatır.
+use macroquad::prelude::*;
+use rrt_visualization::{Obstacle3, Point3, Scenario3};
+
+const DEFAULT_SCENARIO: &str = "scenarios/rrt3d.toml";
+const LOOK_SENSITIVITY: f32 = 0.005; // Piksel başına radyan
+const MOVE_SPEED: f32 = 0.5; // Alanın köşegeninin saniyedeki oranı
+
+fn vec(p: &Point3) -> Vec3 {
+    vec3(p[0], p[1], p[2])
+}
+
+// Konumu, yatay (yaw) ve dikey (pitch) bakış açısıyla tanımlanan serbest kamera
+struct FreeLook {
+    position: Vec3,
+    yaw: f32,
+    pitch: f32,
+    last_mouse: Vec2,
+}
+
+impl FreeLook {
+    // Alanı bir köşesinin dışından merkezine bakarak gösterir
+    fn looking_at(position: Vec3, target: Vec3) -> Self {
+        let d = (target - position).normalize();
+        FreeLook { position, yaw: d.z.atan2(d.x), pitch: d.y.asin(), last_mouse: Vec2::from(mouse_position()) }
+    }
+
+    fn front(&self) -> Vec3 {
+        vec3(self.yaw.cos() * self.pitch.cos(), self.pitch.sin(), self.yaw.sin() * self.pitch.cos())
+    }
+
+    fn update(&mut self, speed: f32) {
+        let mouse = Vec2::from(mouse_position());
+        if is_mouse_button_down(MouseButton::Left) {
+            let delta = mouse - self.last_mouse;
+            self.yaw += delta.x * LOOK_SENSITIVITY;
+            // Tam dikey bakışta "yukarı" yönü tanımsız kalacağından biraz öncesinde durulur
+            self.pitch = (self.pitch - delta.y * LOOK_SENSITIVITY).clamp(-1.5, 1.5);
+        }
+        self.last_mouse = mouse;
+
+        let front = self.front();
+        let right = front.cross(Vec3::Y).normalize();
+        let step = speed * get_frame_time();
+        let pressed = |key| if is_key_down(key) { step } else { 0.0 };
+        self.position += front * (pressed(KeyCode::W) - pressed(KeyCode::S));
+        self.position += right * (pressed(KeyCode::D) - pressed(KeyCode::A));
+        self.position += Vec3::Y * (pressed(KeyCode::E) - pressed(KeyCode::Q));
+    }
+
+    fn camera(&self) -> Camera3D {
+        Camera3D { position: self.position, target: self.position + self.front(), up: Vec3::Y, ..Default::default() }
+    }
+}
+
+fn draw_obstacle(obstacle: &Obstacle3) {
+    let color = Color::new(0.5, 0.5, 0.5, 0.6);
+    match obstacle {
+        Obstacle3::Sphere { center, radius } => {
+            draw_sphere(vec(center), *radius, None, color);
+            draw_sphere_wires(vec(center), *radius, None, DARKGRAY);
+        }
+        Obstacle3::Aabb { min, max } => {
+            let (min, max) = (vec(min), vec(max));
+            draw_cube((min + max) / 2.0, max - min, None, color);
+            draw_cube_wires((min + max) / 2.0, max - min, DARKGRAY);
+        }
+    }
+}
+
+#[macroquad::main("RRT 3D")]
+async fn main() {
+    let path = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_SCENARIO.to_string());
+    let mut scenario = match Scenario3::from_toml_file(&path) {
+        Ok(scenario) => scenario,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            std::process::exit(1);
+        }
+    };
+    let b = scenario.bounds;
+    let (min, max) = (vec3(b.min_x, b.min_y, b.min_z), vec3(b.max_x, b.max_y, b.max_z));
+    let center = (min + max) / 2.0;
+    let diagonal = (max - min).length();
+    let mut camera = FreeLook::looking_at(center + (max - min) * vec3(-0.6, 0.9, -1.2), center);
+
+    let mut tree = scenario.tree();
+    let mut path: Option<Vec<Point3>> = None;
+    let mut iterations = 0;
+
+    loop {
+        if is_key_pressed(KeyCode::Escape) {
+            break;
+        }
+        if is_key_pressed(KeyCode::R) {
+            scenario.seed = Some(scenario.seed.map_or(0, |s| s + 1));
+            tree = scenario.tree();
+            path = None;
+            iterations = 0;
+        }
+        camera.update(diagonal * MOVE_SPEED);
+
+        let checker = scenario.checker();
+        for _ in 0..scenario.iterations_per_frame {
+            if path.is_some() || iterations >= scenario.max_iters {
+                break;
+            }
+            tree.extend(&mut |from, to| checker.is_edge_free(from, to));
+            iterations += 1;
+            path = tree.goal_node.map(|goal| tree.path_to(goal));
+        }
+
+        clear_background(WHITE);
+        set_camera(&camera.camera());
+        draw_cube_wires(center, max - min, LIGHTGRAY);
+        for node in &tree.nodes {
+            if let Some(parent) = node.parent {
+                draw_line_3d(vec(&tree.nodes[parent].state), vec(&node.state), BLUE);
+            }
+        }
+        if let Some(path) = &path {
+            for segment in path.windows(2) {
+                draw_line_3d(vec(&segment[0]), vec(&segment[1]), GREEN);
+            }
+            // 3B çizgilerin kalınlığı olmadığından yol ayrıca küçük kürelerle vurgulanır
+            for p in path {
+                draw_sphere(vec(p), diagonal * 0.003, None, GREEN);
+            }
+        }
+        for obstacle in &scenario.obstacles {
+            draw_obstacle(obstacle);
+        }
+        draw_sphere(vec(&scenario.start), diagonal * 0.008, None, GREEN);
+        draw_sphere(vec(&scenario.goal), diagonal * 0.008, None, RED);
+        draw_sphere_wires(vec(&scenario.goal), scenario.goal_threshold, None, RED);
+
+        set_default_camera();
+        let status = match &path {
+            Some(path) => format!("goal reached: {} waypoints", path.len()),
+            None if iterations >= scenario.max_iters => "no path within max_iters".to_string(),
+            None => "searching...".to_string(),
+        };
+        draw_text(format!("Nodes: {}  Iterations: {}  {}", tree.nodes.len(), iterations, status), 10.0, 20.0, 20.0, DARKGRAY);
+        draw_text("Drag: look  WASD/QE: move  R: restart", 10.0, 40.0, 20.0, DARKGRAY);
+
+        next_frame().await
+    }
+}
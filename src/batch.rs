@@ -0,0 +1,312 @@
+// Aynı senaryoyu farklı tohumlarla çok kez çalıştırıp istatistik toplayan toplu deney modu.
+//
+// Süre ölçümü `std::time::Instant` ile yapılır; bu mod yalnızca yerel (ekransız) derlemede
+// kullanılmak üzere tasarlanmıştır.
+
+use std::io::{self, Write};
+use std::time::Instant;
+
+use crate::config::RRTConfig;
+use crate::grid::GridAstar;
+use crate::path::Path;
+use crate::rrt::{path_clearance, path_length, PlanResult, RRT};
+
+// Tek bir denemenin sonucu
+#[derive(Clone, Copy, Debug)]
+pub struct TrialStats {
+    pub seed: u64,
+    pub success: bool,
+    pub iterations: usize,
+    pub nodes: usize,
+    pub collision_checks: usize, // Yapılan kenar çarpışma kontrolleri
+    pub path_length: Option<f32>,
+    pub min_clearance: Option<f32>, // Yolun engellere en çok yaklaştığı mesafe
+    pub wall_time_ms: f64,
+    pub astar_ratio: Option<f32>, // RRT yol uzunluğunun ızgara A* yol uzunluğuna oranı
+    pub best_goal_distance: f32, // Ağacın hedefe en çok yaklaştığı mesafe
+    pub coverage: Option<f32>, // Deneme sonunda kaplanan serbest hücrelerin oranı; kapsama ızgarası kapalıysa `None`
+    pub max_depth: usize, // Deneme sonunda ağacın biçimi (`RRT::statistics`)
+    pub mean_depth: f32,
+    pub mean_branching: f32,
+    pub mean_edge_length: f32,
+}
+
+// Bir ölçümün ortalama, medyan ve standart sapması
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Aggregate {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+}
+
+impl Aggregate {
+    pub fn of(values: &[f64]) -> Aggregate {
+        if values.is_empty() {
+            return Aggregate::default();
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let mut sorted = values.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        };
+        Aggregate { mean, median, stddev: variance.sqrt() }
+    }
+}
+
+// Tüm denemelerin özeti; yol uzunluğu yalnızca başarılı denemeler üzerinden hesaplanır
+#[derive(Clone, Copy, Debug)]
+pub struct BatchSummary {
+    pub trials: usize,
+    pub success_rate: f64,
+    pub iterations: Aggregate,
+    pub nodes: Aggregate,
+    pub collision_checks: Aggregate,
+    pub path_length: Aggregate,
+    pub min_clearance: Aggregate,
+    pub wall_time_ms: Aggregate,
+    pub astar_ratio: Aggregate,
+    pub miss_distance: Aggregate, // Başarısız denemelerde hedefe en çok yaklaşılan mesafe
+    pub coverage: Aggregate, // Yüzde olarak
+    pub max_depth: Aggregate,
+    pub mean_depth: Aggregate,
+    pub mean_branching: Aggregate,
+    pub mean_edge_length: Aggregate,
+}
+
+fn aggregate_by<F: Fn(&TrialStats) -> Option<f64>>(trials: &[TrialStats], f: F) -> Aggregate {
+    let values: Vec<f64> = trials.iter().filter_map(f).collect();
+    Aggregate::of(&values)
+}
+
+impl BatchSummary {
+    pub fn from_trials(trials: &[TrialStats]) -> BatchSummary {
+        let successes = trials.iter().filter(|t| t.success).count();
+        BatchSummary {
+            trials: trials.len(),
+            success_rate: if trials.is_empty() { 0.0 } else { successes as f64 / trials.len() as f64 },
+            iterations: aggregate_by(trials, |t| Some(t.iterations as f64)),
+            nodes: aggregate_by(trials, |t| Some(t.nodes as f64)),
+            collision_checks: aggregate_by(trials, |t| Some(t.collision_checks as f64)),
+            path_length: aggregate_by(trials, |t| t.path_length.map(f64::from)),
+            // Engelsiz haritada mesafe sonsuz olduğundan ortalamaya katılmaz
+            min_clearance: aggregate_by(trials, |t| t.min_clearance.filter(|c| c.is_finite()).map(f64::from)),
+            wall_time_ms: aggregate_by(trials, |t| Some(t.wall_time_ms)),
+            astar_ratio: aggregate_by(trials, |t| t.astar_ratio.map(f64::from)),
+            miss_distance: aggregate_by(trials, |t| (!t.success).then_some(f64::from(t.best_goal_distance))),
+            coverage: aggregate_by(trials, |t| t.coverage.map(|c| 100.0 * f64::from(c))),
+            max_depth: aggregate_by(trials, |t| Some(t.max_depth as f64)),
+            mean_depth: aggregate_by(trials, |t| Some(f64::from(t.mean_depth))),
+            mean_branching: aggregate_by(trials, |t| Some(f64::from(t.mean_branching))),
+            mean_edge_length: aggregate_by(trials, |t| Some(f64::from(t.mean_edge_length))),
+        }
+    }
+
+    // İnsan tarafından okunacak özet tablosunu yazar
+    pub fn print<W: Write>(&self, mut w: W) -> io::Result<()> {
+        writeln!(w, "trials: {}  success rate: {:.1}%", self.trials, self.success_rate * 100.0)?;
+        writeln!(w, "{:<14}{:>12}{:>12}{:>12}", "", "mean", "median", "stddev")?;
+        for (name, a) in [
+            ("iterations", self.iterations),
+            ("nodes", self.nodes),
+            ("edge checks", self.collision_checks),
+            ("path length", self.path_length),
+            ("min clearance", self.min_clearance),
+            ("wall ms", self.wall_time_ms),
+            ("RRT/A* ratio", self.astar_ratio),
+            ("miss distance", self.miss_distance),
+            ("coverage %", self.coverage),
+            ("max depth", self.max_depth),
+            ("mean depth", self.mean_depth),
+            ("branching", self.mean_branching),
+            ("edge length", self.mean_edge_length),
+        ] {
+            writeln!(w, "{:<14}{:>12.2}{:>12.2}{:>12.2}", name, a.mean, a.median, a.stddev)?;
+        }
+        Ok(())
+    }
+}
+
+// Senaryoyu `0..n` tohumlarıyla çalıştırır. Ağaç denemeler arasında yeniden kullanılır,
+// böylece düğüm vektörünün kapasitesi her denemede baştan ayrılmaz.
+pub fn run_trials(config: &RRTConfig, n: u64) -> Vec<TrialStats> {
+    let mut rrt = RRT::from_config(config);
+    let astar_length = astar_reference(config, &rrt);
+    (0..n).map(|seed| run_trial(&mut rrt, config, seed, astar_length)).collect()
+}
+
+// Senaryoyu `n` kez, `index` numaralı denemeyi `trial_seed(base, index)` tohumuyla çalıştırır;
+// taban tohum yapılandırmadaki tohumdur (verilmemişse sıfır). `rayon` özelliğiyle denemeler
+// iş parçacıklarına dağıtılır, her deneme kendi ağacını kurar. Sonuçlar deneme sırasıyla
+// döner ve duvar saati süreleri dışında iş parçacığı sayısından ve zamanlamadan bağımsızdır.
+pub fn run_trials_parallel(config: &RRTConfig, n: u64) -> Vec<TrialStats> {
+    let base = config.seed.unwrap_or(0);
+    let astar_length = astar_reference(config, &RRT::from_config(config));
+    let trial = |index: u64| run_trial(&mut RRT::from_config(config), config, trial_seed(base, index), astar_length);
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        (0..n).into_par_iter().map(trial).collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        (0..n).map(trial).collect()
+    }
+}
+
+// Deneme tohumu: deneme indeksinin karması (splitmix64) taban tohumla XOR'lanır, böylece
+// komşu indeksler birbirinden bağımsız görünen tohumlar alır
+pub fn trial_seed(base: u64, index: u64) -> u64 {
+    let mut z = index.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    base ^ (z ^ (z >> 31))
+}
+
+// Senaryo denemeler arasında değişmediğinden referans yol bir kez hesaplanır
+fn astar_reference(config: &RRTConfig, rrt: &RRT) -> Option<f32> {
+    if !config.compare_astar {
+        return None;
+    }
+    let (start, goal) = (rrt.starts[0], rrt.goal());
+    GridAstar::from_rrt(rrt, config.astar_cell_size).search(&start, &goal).map(|path| path_length(&path))
+}
+
+// Ağacı verilen tohumla yapılandırmadaki uç noktalardan yeniden başlatıp bir deneme çalıştırır
+fn run_trial(rrt: &mut RRT, config: &RRTConfig, seed: u64, astar_length: Option<f32>) -> TrialStats {
+    let (start, goal) = (rrt.starts[0], rrt.goal());
+    rrt.reseed(seed);
+    rrt.reset(start, goal);
+    let started = Instant::now();
+    let result = rrt.plan(config.max_iters);
+    let wall_time_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let stats = *result.stats();
+    let length = result.path().map(Path::length);
+    let tree = rrt.statistics();
+    TrialStats {
+        seed,
+        success: matches!(result, PlanResult::Found { .. }),
+        iterations: stats.iterations,
+        nodes: stats.nodes,
+        collision_checks: rrt.lazy_stats.checks_performed,
+        path_length: length,
+        min_clearance: result.path().map(|path| path_clearance(path, &rrt.obstacles)),
+        wall_time_ms,
+        astar_ratio: length.zip(astar_length).map(|(rrt, astar)| rrt / astar),
+        best_goal_distance: stats.best_goal_distance,
+        coverage: rrt.coverage_fraction(),
+        max_depth: tree.shape.max_depth,
+        mean_depth: tree.shape.mean_depth,
+        mean_branching: tree.shape.mean_branching,
+        mean_edge_length: tree.mean_edge_length,
+    }
+}
+
+// Deneme başına bir satır içeren CSV yazar
+pub fn write_trials_csv<W: Write>(trials: &[TrialStats], mut w: W) -> io::Result<()> {
+    writeln!(w, "seed,success,iterations,nodes,collision_checks,path_length,wall_time_ms,astar_ratio,min_clearance,best_goal_distance,coverage,max_depth,mean_depth,mean_branching,mean_edge_length")?;
+    for t in trials {
+        let length = t.path_length.map(|l| format!("{:.6}", l)).unwrap_or_default();
+        let ratio = t.astar_ratio.map(|r| format!("{:.6}", r)).unwrap_or_default();
+        let clearance = t.min_clearance.map(|c| format!("{:.6}", c)).unwrap_or_default();
+        let coverage = t.coverage.map(|c| format!("{:.6}", c)).unwrap_or_default();
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{:.3},{},{},{:.6},{},{},{:.6},{:.6},{:.6}",
+            t.seed,
+            t.success,
+            t.iterations,
+            t.nodes,
+            t.collision_checks,
+            length,
+            t.wall_time_ms,
+            ratio,
+            clearance,
+            t.best_goal_distance,
+            coverage,
+            t.max_depth,
+            t.mean_depth,
+            t.mean_branching,
+            t.mean_edge_length
+        )?;
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{PotentialField, SamplerKind};
+    use crate::rrt::Point;
+
+    #[test]
+    fn ten_trials_on_an_empty_map_all_succeed() {
+        let config = RRTConfig {
+            start: Some(Point { x: 20.0, y: 20.0 }),
+            goal: Some(Point { x: 300.0, y: 250.0 }),
+            goal_bias: 0.1,
+            sampler: SamplerKind::GoalBiased,
+            max_iters: 10_000,
+            ..RRTConfig::default()
+        };
+        let trials = run_trials(&config, 10);
+        assert_eq!(trials.iter().map(|t| t.seed).collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+        assert!(trials.iter().all(|t| t.success && t.path_length.is_some()));
+        let summary = BatchSummary::from_trials(&trials);
+        assert_eq!((summary.trials, summary.success_rate), (10, 1.0));
+        // Düz çizgi yaklaşık 362 birimdir; hedef eşiği kadar kısa kalabilir
+        assert!(summary.path_length.mean >= 352.0);
+
+        let mut csv = Vec::new();
+        write_trials_csv(&trials, &mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap().lines().count(), 11);
+    }
+
+    #[test]
+    fn parallel_trials_are_reproducible_and_ordered_by_index() {
+        let mut config = RRTConfig::from_toml_file("scenarios/corridor.toml").expect("scenario loads");
+        config.max_iters = 5000;
+        let outcome = |trials: &[TrialStats]| -> Vec<(u64, usize, usize, Option<f32>)> {
+            trials.iter().map(|t| (t.seed, t.iterations, t.nodes, t.path_length)).collect()
+        };
+        let first = run_trials_parallel(&config, 8);
+        let second = run_trials_parallel(&config, 8);
+        assert_eq!(outcome(&first), outcome(&second));
+        let seeds: Vec<u64> = (0..8).map(|i| trial_seed(3, i)).collect();
+        assert_eq!(first.iter().map(|t| t.seed).collect::<Vec<_>>(), seeds);
+        // Tohumlar birbirinden farklıdır ve ağaçlar farklı büyür
+        assert!(seeds.iter().enumerate().all(|(i, s)| !seeds[..i].contains(s)));
+        assert!(first.windows(2).any(|w| w[0].iterations != w[1].iterations));
+        assert_eq!(trial_seed(0, 5) ^ trial_seed(9, 5), 9);
+    }
+
+    #[test]
+    fn trials_report_coverage_only_when_it_is_enabled() {
+        let config = RRTConfig { seed: Some(1), max_iters: 2000, coverage_resolution: 10, ..RRTConfig::default() };
+        let trials = run_trials(&config, 3);
+        assert!(trials.iter().all(|t| t.coverage.is_some_and(|c| c > 0.0 && c <= 1.0)));
+        assert!(BatchSummary::from_trials(&trials).coverage.mean > 0.0);
+        let disabled = RRTConfig { coverage_resolution: 0, ..config };
+        assert!(run_trials(&disabled, 1)[0].coverage.is_none());
+    }
+
+    #[test]
+    fn potential_field_reduces_mean_iterations_on_the_example_scenario() {
+        let mut config = RRTConfig::from_toml_file("scenarios/example.toml").expect("scenario loads");
+        let plain = BatchSummary::from_trials(&run_trials(&config, 40));
+        config.potential_field = Some(PotentialField::default());
+        let guided = BatchSummary::from_trials(&run_trials(&config, 40));
+        assert_eq!((plain.success_rate, guided.success_rate), (1.0, 1.0));
+        assert!(
+            guided.iterations.mean < plain.iterations.mean,
+            "guided {} vs plain {} mean iterations",
+            guided.iterations.mean,
+            plain.iterations.mean
+        );
+    }
+}
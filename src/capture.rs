@@ -0,0 +1,213 @@
+// Ekran görüntülerini numaralı PNG kareler ve/veya animasyonlu GIF halinde kaydeder.
+//
+// Kareler macroquad'ın `get_screen_data` fonksiyonuyla pencereden alındığı için yakalama
+// yalnızca pencereli modda çalışır; macroquad ekran dışı bir hedefin içeriğini geri okumayı
+// desteklemediğinden ekransız çalıştırmada bu özellik kullanılamaz.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use macroquad::prelude::get_screen_data;
+
+// Yazıcı iş parçacığına gönderilen ham kare
+struct Frame {
+    iteration: usize,
+    width: u16,
+    height: u16,
+    rgba: Vec<u8>,
+}
+
+impl Frame {
+    // OpenGL'den gelen görüntü alttan üste sıralıdır; dosyaya yazmadan önce dikey olarak çevrilir
+    fn flipped(mut self) -> Self {
+        let row = self.width as usize * 4;
+        let mut flipped = Vec::with_capacity(self.rgba.len());
+        for y in (0..self.height as usize).rev() {
+            flipped.extend_from_slice(&self.rgba[y * row..(y + 1) * row]);
+        }
+        self.rgba = flipped;
+        self
+    }
+}
+
+// GIF çıktısının ayarları; GIF desteği olmadan derlendiğinde yalnızca uyarı için kullanılır
+#[cfg_attr(not(feature = "gif"), allow(dead_code))]
+pub struct GifOptions {
+    pub path: PathBuf,
+    pub fps: f32, // Oynatma hızı; GIF gecikmeleri yüzde bir saniye cinsinden olduğundan en fazla 100
+    pub width: Option<u32>, // Verilirse kareler en-boy oranı korunarak bu genişliğe küçültülür
+}
+
+pub struct FrameCapture {
+    every: usize, // Kaç iterasyonda bir kare alınacağı
+    last_iteration: usize, // En son kare alınan iterasyon
+    sender: Option<Sender<Frame>>,
+    worker: Option<JoinHandle<io::Result<usize>>>,
+}
+
+impl FrameCapture {
+    // Kareleri `dir` klasörüne PNG olarak yazan bir yakalayıcı başlatır; `gif` verilirse kareler
+    // ayrıca tek bir animasyonlu GIF'e eklenir. `dir` verilmezse yalnızca GIF yazılır.
+    pub fn start(dir: Option<PathBuf>, every: usize, gif: Option<GifOptions>) -> io::Result<Self> {
+        // Web derlemesinde ne iş parçacığı ne de dosya sistemi bulunur
+        if cfg!(target_arch = "wasm32") {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, "not available in the web build"));
+        }
+        if let Some(dir) = &dir {
+            fs::create_dir_all(dir)?;
+        }
+        let (sender, receiver) = mpsc::channel::<Frame>();
+        let worker = thread::spawn(move || {
+            let mut gif = GifWriter::new(gif);
+            let mut written = 0;
+            for frame in receiver {
+                let frame = frame.flipped();
+                if let Some(dir) = &dir {
+                    // İterasyon numarası sıfırla doldurulur ki kareler dosya adına göre doğru sıralansın
+                    let file = dir.join(format!("frame_{:08}.png", frame.iteration));
+                    image::save_buffer(
+                        &file,
+                        &frame.rgba,
+                        frame.width as u32,
+                        frame.height as u32,
+                        image::ColorType::Rgba8,
+                    )
+                    .map_err(io::Error::other)?;
+                }
+                gif.push(frame)?;
+                written += 1;
+            }
+            gif.finish()?;
+            Ok(written)
+        });
+        Ok(FrameCapture {
+            every: every.max(1),
+            last_iteration: 0,
+            sender: Some(sender),
+            worker: Some(worker),
+        })
+    }
+
+    // Gerekliyse o anki kareyi yakalar; dosyaya yazma işi arka planda yapılır
+    // Kare başına birden çok iterasyon çalışabildiğinden son kareden bu yana `every` katlarından
+    // biri geçildiyse yakalanır
+    pub fn capture(&mut self, iteration: usize) {
+        if iteration / self.every <= self.last_iteration / self.every {
+            return;
+        }
+        self.capture_now(iteration);
+    }
+
+    // İterasyon aralığına bakmadan o anki kareyi yakalar
+    pub fn capture_now(&mut self, iteration: usize) {
+        self.last_iteration = iteration;
+        if let Some(sender) = &self.sender {
+            let image = get_screen_data();
+            let frame = Frame {
+                iteration,
+                width: image.width,
+                height: image.height,
+                rgba: image.bytes,
+            };
+            // Yazıcı bir hata nedeniyle durduysa hata `finish` içinde raporlanır
+            let _ = sender.send(frame);
+        }
+    }
+
+    // Kuyruktaki tüm kareler yazılana kadar bekler ve yazılan kare sayısını döndürür
+    pub fn finish(&mut self) -> io::Result<usize> {
+        self.sender.take();
+        match self.worker.take() {
+            Some(worker) => worker
+                .join()
+                .map_err(|_| io::Error::other("capture thread panicked"))?,
+            None => Ok(0),
+        }
+    }
+}
+
+// Kareyi en-boy oranını koruyarak `width` genişliğine küçültür; büyütme yapılmaz
+#[cfg(feature = "gif")]
+fn scaled(frame: Frame, width: u32) -> Frame {
+    let (w, h) = (frame.width as u32, frame.height as u32);
+    if width == 0 || width >= w {
+        return frame;
+    }
+    let height = ((h as u64 * width as u64) / w as u64).max(1) as u32;
+    let Some(image) = image::RgbaImage::from_raw(w, h, frame.rgba.clone()) else {
+        return frame;
+    };
+    let resized = image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle);
+    Frame { iteration: frame.iteration, width: width as u16, height: height as u16, rgba: resized.into_raw() }
+}
+
+#[cfg(feature = "gif")]
+struct GifWriter {
+    options: Option<GifOptions>,
+    encoder: Option<gif::Encoder<io::BufWriter<fs::File>>>,
+}
+
+#[cfg(feature = "gif")]
+impl GifWriter {
+    fn new(options: Option<GifOptions>) -> Self {
+        GifWriter { options, encoder: None }
+    }
+
+    // Kareyi GIF'e ekler; kodlayıcı ilk (küçültülmüş) karenin boyutlarıyla açılır
+    fn push(&mut self, frame: Frame) -> io::Result<()> {
+        let Some(options) = &self.options else {
+            return Ok(());
+        };
+        let mut frame = match options.width {
+            Some(width) => scaled(frame, width),
+            None => frame,
+        };
+        if self.encoder.is_none() {
+            let file = io::BufWriter::new(fs::File::create(&options.path)?);
+            let mut encoder =
+                gif::Encoder::new(file, frame.width, frame.height, &[]).map_err(io::Error::other)?;
+            encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+            self.encoder = Some(encoder);
+        }
+        let mut gif_frame = gif::Frame::from_rgba_speed(frame.width, frame.height, &mut frame.rgba, 10);
+        // Gecikme yüzde bir saniye cinsindendir; sıfır gecikmeyi görüntüleyiciler yavaş oynatır
+        gif_frame.delay = (100.0 / options.fps).round().clamp(1.0, u16::MAX as f32) as u16;
+        if let Some(encoder) = self.encoder.as_mut() {
+            encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    // GIF sonlandırıcısını yazar ve dosyayı boşaltır
+    fn finish(self) -> io::Result<()> {
+        if let Some(encoder) = self.encoder {
+            let mut file = encoder.into_inner().map_err(io::Error::other)?;
+            io::Write::flush(&mut file)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "gif"))]
+struct GifWriter;
+
+#[cfg(not(feature = "gif"))]
+impl GifWriter {
+    fn new(options: Option<GifOptions>) -> Self {
+        if options.is_some() {
+            eprintln!("GIF output requires building with `--features gif`; no GIF is written");
+        }
+        GifWriter
+    }
+
+    fn push(&mut self, _frame: Frame) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn finish(self) -> io::Result<()> {
+        Ok(())
+    }
+}
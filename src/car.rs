@@ -0,0 +1,245 @@
+// İleri ve geri gidebilen araba benzeri robot için RRT: durumlar konum ve yönden oluşan pozlardır,
+// düğümler en kısa Reeds-Shepp eğrisi boyunca en fazla `step_size` ilerlenerek eklenir ve en yakın
+// düğüm Reeds-Shepp uzunluğuna göre seçilir. Her düğüm ebeveyninden gelen eğriyi ileri ve geri
+// parçalarıyla saklar; robot gövdesi disktir.
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::config::RRTConfig;
+use crate::obstacle::{Bounds, Obstacle};
+use crate::path::Pose;
+use crate::reeds_shepp::{self, Gear, ReedsSheppPath};
+use crate::rrt::{seeded_rng, Point, RRT};
+use crate::scalar::Scalar;
+
+// Senaryo dosyasındaki `[car]` tablosu: verilirse birincil robot en küçük dönüş yarıçapı
+// `turning_radius` olan bir arabadır. Yönler x ekseninden saat yönünün tersine radyandır.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CarSpec {
+    pub turning_radius: f32,
+    pub start_heading: f32,
+    pub goal_heading: f32,
+    pub heading_tolerance: f32, // Hedefe varılmış sayılması için yön farkının üst sınırı
+}
+
+impl Default for CarSpec {
+    fn default() -> Self {
+        CarSpec { turning_radius: 20.0, start_heading: 0.0, goal_heading: 0.0, heading_tolerance: 0.2 }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CarNode<T = f32> {
+    pub pose: Pose<T>,
+    pub parent: Option<usize>,
+    pub cost: T, // Kökten bu düğüme eğri uzunluklarının toplamı
+    pub edge: ReedsSheppPath<T>, // Ebeveynden bu düğüme giden eğri; kökte boştur
+}
+
+pub struct CarTree<T = f32> {
+    pub nodes: Vec<CarNode<T>>,
+    pub goal: Pose<T>,
+    pub bounds: Bounds<T>,
+    pub obstacles: Vec<Obstacle<T>>,
+    pub robot_radius: T,
+    pub turning_radius: T,
+    pub step_size: T, // Bir genişletmede eğri boyunca gidilen en uzun yol
+    pub goal_threshold: T,
+    pub heading_tolerance: T,
+    pub goal_bias: T, // Hedef pozun doğrudan örneklenme olasılığı
+    pub collision_resolution: T, // Eğri boyunca kontrol edilen pozlar arasındaki en uzun yol
+    pub goal_node: Option<usize>, // Hedef bölgesine ilk ulaşan düğüm
+    rng: StdRng,
+}
+
+impl<T: Scalar> CarTree<T> {
+    pub fn new(start: Pose<T>, goal: Pose<T>, bounds: Bounds<T>, turning_radius: T, step_size: T, goal_threshold: T) -> Self {
+        CarTree {
+            nodes: vec![CarNode { pose: start, parent: None, cost: T::zero(), edge: ReedsSheppPath { segments: Vec::new(), turning_radius } }],
+            goal,
+            bounds,
+            obstacles: Vec::new(),
+            robot_radius: T::zero(),
+            turning_radius,
+            step_size,
+            goal_threshold,
+            heading_tolerance: T::cast(0.2),
+            goal_bias: T::cast(0.05),
+            collision_resolution: T::one(),
+            goal_node: None,
+            rng: seeded_rng(None),
+        }
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = seeded_rng(Some(seed));
+    }
+
+    // Ağacı tek köke indirir; üreteç kaldığı yerden devam eder
+    pub fn reset(&mut self) {
+        self.nodes.truncate(1);
+        self.goal_node = None;
+    }
+
+    // Hedef pozu ya da alan içinde düzgün bir konum ve yön
+    pub fn sample(&mut self) -> Pose<T> {
+        if T::unit(&mut self.rng) < self.goal_bias {
+            return self.goal;
+        }
+        let b = &self.bounds;
+        let point = Point { x: self.rng.gen_range(b.min_x..b.max_x), y: self.rng.gen_range(b.min_y..b.max_y) };
+        let pi = T::cast(std::f64::consts::PI);
+        Pose { point, heading: self.rng.gen_range(-pi..pi) }
+    }
+
+    // Reeds-Shepp uzunluğuna göre en yakın düğüm; eşit uzaklıkta indeksi küçük olan döner
+    pub fn find_nearest(&self, pose: &Pose<T>) -> usize {
+        let mut best = (0, T::infinity());
+        for (index, node) in self.nodes.iter().enumerate() {
+            let d = reeds_shepp::distance(&node.pose, pose, self.turning_radius);
+            if d < best.1 {
+                best = (index, d);
+            }
+        }
+        best.0
+    }
+
+    // `from` pozundan `to` pozuna giden en kısa eğrinin ilk `step_size` kadarı
+    pub fn steer(&self, from: &Pose<T>, to: &Pose<T>) -> ReedsSheppPath<T> {
+        reeds_shepp::shortest_path(from, to, self.turning_radius).truncated(self.step_size)
+    }
+
+    // Disk gövde eğri boyunca alan içinde kalıyor ve hiçbir engele değmiyor mu
+    pub fn is_edge_collision_free(&self, from: &Pose<T>, edge: &ReedsSheppPath<T>) -> bool {
+        edge.sample(from, self.collision_resolution).iter().all(|(pose, _)| {
+            self.bounds.contains(&pose.point)
+                && !self.obstacles.iter().any(|o| o.signed_distance(&pose.point) <= self.robot_radius)
+        })
+    }
+
+    // Konum hedefe `goal_threshold` kadar yakın ve yön farkı `heading_tolerance` altında
+    pub fn in_goal_region(&self, pose: &Pose<T>) -> bool {
+        let difference = pose.heading - self.goal.heading;
+        let turn = difference.sin().atan2(difference.cos());
+        pose.point.distance(&self.goal.point) < self.goal_threshold && turn.abs() < self.heading_tolerance
+    }
+
+    pub fn add_node(&mut self, edge: ReedsSheppPath<T>, parent: usize) -> usize {
+        let from = &self.nodes[parent];
+        let (pose, cost) = (edge.end_pose(&from.pose), from.cost + edge.length());
+        self.nodes.push(CarNode { pose, parent: Some(parent), cost, edge });
+        let index = self.nodes.len() - 1;
+        if self.goal_node.is_none() && self.in_goal_region(&pose) {
+            self.goal_node = Some(index);
+        }
+        index
+    }
+
+    // Tek bir genişletme iterasyonu; eğri çarpışmasızsa eklenen düğümün indeksi döner
+    pub fn extend(&mut self) -> Option<usize> {
+        let sample = self.sample();
+        let nearest = self.find_nearest(&sample);
+        let from = self.nodes[nearest].pose;
+        let edge = self.steer(&from, &sample);
+        if edge.segments.is_empty() || !self.is_edge_collision_free(&from, &edge) {
+            return None;
+        }
+        Some(self.add_node(edge, nearest))
+    }
+
+    // Kökten düğüme giden düğüm indeksleri
+    pub fn chain_to(&self, index: usize) -> Vec<usize> {
+        let mut chain = vec![index];
+        while let Some(parent) = self.nodes[chain[chain.len() - 1]].parent {
+            chain.push(parent);
+        }
+        chain.reverse();
+        chain
+    }
+
+    // Kökten düğüme giden eğriler boyunca en fazla `resolution` aralıklı pozlar ve vitesleri
+    pub fn trajectory(&self, index: usize, resolution: T) -> Vec<(Pose<T>, Gear)> {
+        let chain = self.chain_to(index);
+        let mut samples = vec![(self.nodes[chain[0]].pose, Gear::Forward)];
+        for pair in chain.windows(2) {
+            let (from, to) = (&self.nodes[pair[0]], &self.nodes[pair[1]]);
+            samples.extend(to.edge.sample(&from.pose, resolution).into_iter().skip(1));
+        }
+        samples
+    }
+
+    // Hedefe ulaşılana veya iterasyon sınırına kadar büyür; bulunan yolun pozları döner
+    pub fn plan(&mut self, max_iters: usize) -> Option<Vec<(Pose<T>, Gear)>> {
+        for _ in 0..max_iters {
+            if self.goal_node.is_some() {
+                break;
+            }
+            self.extend();
+        }
+        self.goal_node.map(|goal| self.trajectory(goal, self.collision_resolution))
+    }
+}
+
+// Senaryonun haritası, uç noktaları ve parametreleriyle araba planlayıcısı; `[car]` tablosu
+// yoksa varsayılan araba kullanılır
+pub fn car_from_config(config: &RRTConfig) -> CarTree {
+    let rrt = RRT::from_config(config);
+    let spec = config.car.unwrap_or_default();
+    let start = Pose { point: rrt.starts[0], heading: spec.start_heading };
+    let goal = Pose { point: rrt.goal(), heading: spec.goal_heading };
+    let mut tree = CarTree::new(start, goal, rrt.bounds, spec.turning_radius, config.step_size, config.goal_threshold);
+    tree.obstacles = rrt.obstacles;
+    tree.robot_radius = config.robot_radius;
+    tree.heading_tolerance = spec.heading_tolerance;
+    tree.goal_bias = config.goal_bias;
+    tree.collision_resolution = config.collision_resolution;
+    if let Some(seed) = config.seed {
+        tree.reseed(seed);
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn car_tree_grows_along_reeds_shepp_curves_and_parks_in_reverse() {
+        let pose = |x: f32, y: f32, heading: f32| Pose { point: Point { x, y }, heading };
+        let bounds = Bounds { min_x: 0.0, max_x: 200.0, min_y: 0.0, max_y: 200.0 };
+        let mut car: CarTree = CarTree::new(pose(100.0, 100.0, 0.0), pose(100.0, 108.0, 0.0), bounds, 10.0, 10.0, 2.0);
+        // Yanda duran düğüm öklid uzaklığıyla daha yakın olsa da arkada duran düğümden düz gitmek daha kısadır
+        car.nodes[0].pose = pose(0.0, 0.0, 0.0);
+        let path_to = |to: Pose| reeds_shepp::shortest_path(&pose(0.0, 0.0, 0.0), &to, 10.0);
+        let beside = car.add_node(path_to(pose(30.0, 8.0, 0.0)), 0);
+        let behind = car.add_node(path_to(pose(18.0, 0.0, 0.0)), 0);
+        assert_eq!(car.find_nearest(&pose(30.0, 0.0, 0.0)), behind);
+        assert_eq!(car.find_nearest(&pose(40.0, 8.0, 0.0)), beside);
+        assert_close(car.nodes[behind].cost, 18.0);
+
+        car.nodes.truncate(1);
+        car.nodes[0].pose = pose(100.0, 100.0, 0.0);
+        car.obstacles.push(Obstacle::Rect { min: Point { x: 60.0, y: 112.0 }, max: Point { x: 140.0, y: 130.0 } });
+        car.robot_radius = 2.0;
+        car.goal_bias = 0.2;
+        car.reseed(3);
+        let trajectory = car.plan(5_000).expect("car parks");
+        let goal = car.goal_node.unwrap();
+        assert!(car.in_goal_region(&car.nodes[goal].pose));
+        assert!(trajectory.iter().any(|&(_, gear)| gear == Gear::Reverse));
+        for i in car.chain_to(goal).into_iter().skip(1) {
+            let node = &car.nodes[i];
+            let parent = &car.nodes[node.parent.unwrap()];
+            assert!(node.edge.length() <= car.step_size + 1e-4);
+            assert!(car.is_edge_collision_free(&parent.pose, &node.edge));
+            assert!(node.edge.end_pose(&parent.pose).point.distance(&node.pose.point) < 1e-4);
+        }
+        assert!(trajectory.windows(2).all(|w| w[0].0.point.distance(&w[1].0.point) <= car.collision_resolution + 1e-4));
+    }
+}
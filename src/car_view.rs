@@ -0,0 +1,151 @@
+// Araba modu: senaryoda `[car]` tablosu varsa ağaç Reeds-Shepp eğrileriyle her karede
+// `iterations_per_frame` iterasyon büyür. Eğrilerin ileri parçaları kenar renginde, geri parçaları
+// turuncu çizilir. Yol bulununca araba yol boyunca `speed` hızıyla canlandırılır; geri giderken
+// gövdesi turuncudur. R yeni bir tohumla baştan planlar, boşluk canlandırmayı duraklatır.
+use macroquad::prelude::*;
+use rrt_visualization::{car_from_config, CarTree, Gear, Pose, RRTConfig};
+
+use crate::draw::{fit_camera, DrawStyle};
+
+// Geri sürülen eğri parçalarının ve geri giden gövdenin rengi
+const REVERSE_COLOR: Color = ORANGE;
+
+// Eğrilerin çizimde parçalara bölündüğü en uzun yay
+const CURVE_RESOLUTION: f32 = 2.0;
+
+// Bulunan yolun pozları ve başlangıçtan her poza olan yay uzunlukları
+struct Drive {
+    poses: Vec<(Pose, Gear)>,
+    cumulative: Vec<f32>,
+}
+
+impl Drive {
+    fn new(poses: Vec<(Pose, Gear)>) -> Self {
+        let mut total = 0.0;
+        let cumulative = poses
+            .iter()
+            .enumerate()
+            .map(|(i, (pose, _))| {
+                if i > 0 {
+                    total += poses[i - 1].0.point.distance(&pose.point);
+                }
+                total
+            })
+            .collect();
+        Drive { poses, cumulative }
+    }
+
+    fn length(&self) -> f32 {
+        self.cumulative.last().copied().unwrap_or(0.0)
+    }
+
+    // Yol boyunca `s` kadar gidildiğinde arabanın pozu ve vitesi
+    fn at(&self, s: f32) -> (Pose, Gear) {
+        let i = self.cumulative.partition_point(|&c| c <= s).clamp(1, self.poses.len().max(1)) - 1;
+        self.poses[i]
+    }
+}
+
+fn draw_curves(car: &CarTree, style: &DrawStyle) {
+    for node in car.nodes.iter().skip(1) {
+        let from = car.nodes[node.parent.expect("non-root node has a parent")].pose;
+        for pair in node.edge.sample(&from, CURVE_RESOLUTION).windows(2) {
+            let (a, b) = (pair[0].0.point, pair[1].0.point);
+            let color = if pair[1].1 == Gear::Reverse { REVERSE_COLOR } else { style.theme.edge };
+            draw_line(a.x, a.y, b.x, b.y, style.edge_width, color);
+        }
+    }
+}
+
+// Yönü gösteren dikdörtgen gövde; ön kenar kalın bir çizgiyle işaretlenir
+fn draw_car(pose: &Pose, half_length: f32, color: Color, style: &DrawStyle) {
+    let (sin, cos) = pose.heading.sin_cos();
+    let (forward, side) = (vec2(cos, sin) * half_length, vec2(-sin, cos) * half_length * 0.5);
+    let center = vec2(pose.point.x, pose.point.y);
+    let corners = [center + forward + side, center - forward + side, center - forward - side, center + forward - side];
+    draw_triangle(corners[0], corners[1], corners[2], color);
+    draw_triangle(corners[0], corners[2], corners[3], color);
+    draw_line(corners[3].x, corners[3].y, corners[0].x, corners[0].y, style.path_width, style.theme.text);
+}
+
+pub async fn run_car_window(config: RRTConfig) {
+    let style = DrawStyle::from_config(&config);
+    let camera = fit_camera(config.bounds, config.step_size * 4.0);
+    let mut car = car_from_config(&config);
+    let mut seed = config.seed.unwrap_or(0);
+    let half_length = config.robot_radius.max(style.node_radius) * 1.5;
+    let mut drive: Option<Drive> = None;
+    let mut iterations = 0;
+    let mut travelled = 0.0;
+    let mut paused = false;
+
+    loop {
+        if is_quit_requested() || is_key_pressed(KeyCode::Escape) {
+            break;
+        }
+        if is_key_pressed(KeyCode::R) {
+            seed += 1;
+            car.reseed(seed);
+            car.reset();
+            drive = None;
+            iterations = 0;
+        }
+        if is_key_pressed(KeyCode::Space) {
+            paused = !paused;
+        }
+        if drive.is_none() && iterations < config.max_iters {
+            for _ in 0..config.iterations_per_frame {
+                car.extend();
+                iterations += 1;
+            }
+            if let Some(goal) = car.goal_node {
+                drive = Some(Drive::new(car.trajectory(goal, CURVE_RESOLUTION)));
+                travelled = 0.0;
+            }
+        }
+        if let Some(drive) = &drive {
+            if !paused {
+                // Hedefe vardıktan kısa bir süre sonra canlandırma baştan başlar
+                travelled += config.speed * get_frame_time();
+                if travelled > drive.length() + config.speed {
+                    travelled = 0.0;
+                }
+            }
+        }
+
+        clear_background(style.theme.background);
+        set_camera(&camera);
+        for obstacle in &car.obstacles {
+            style.draw_obstacle(obstacle, config.robot_radius);
+        }
+        draw_curves(&car, &style);
+        draw_car(&car.goal, half_length, Color { a: 0.3, ..style.theme.goal }, &style);
+        let status = match &drive {
+            Some(drive) => {
+                let points: Vec<_> = drive.poses.iter().map(|(pose, _)| pose.point).collect();
+                style.draw_path(&points, style.theme.path);
+                let (pose, gear) = drive.at(travelled);
+                let color = if gear == Gear::Reverse { REVERSE_COLOR } else { style.theme.start };
+                draw_car(&pose, half_length, color, &style);
+                let changes = drive.poses.windows(2).filter(|w| w[0].1 != w[1].1).count();
+                format!("path {:.1} with {} gear changes, {}", drive.length(), changes, if gear == Gear::Reverse { "reversing" } else { "driving forward" })
+            }
+            None => {
+                draw_car(&car.nodes[0].pose, half_length, style.theme.start, &style);
+                if iterations < config.max_iters { "planning".to_string() } else { "no path".to_string() }
+            }
+        };
+
+        set_default_camera();
+        let lines = [
+            format!("Reeds-Shepp car, turning radius {:.1}  nodes: {}  iterations: {}", car.turning_radius, car.nodes.len(), iterations),
+            status,
+            "R: replan with a new seed  Space: pause".to_string(),
+        ];
+        for (k, line) in lines.iter().enumerate() {
+            draw_text(line, 10.0, 20.0 + 18.0 * k as f32, 18.0, style.theme.text);
+        }
+
+        next_frame().await;
+    }
+}
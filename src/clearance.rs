@@ -0,0 +1,68 @@
+// Robot açıklığının düzgün ızgarada örneklenmiş alanı: her hücrenin merkezinde en yakın engel
+// sınırına uzaklıktan robot yarıçapı düşülür, böylece sıfır robotun engele değdiği yerdir. Açıklık
+// maliyetini ve robot yarıçapını ayarlarken arka plan tonu olarak çizilir.
+use crate::grid::CellFrame;
+use crate::obstacle::CollisionChecker;
+use crate::scalar::Scalar;
+
+#[derive(Clone, Debug)]
+pub struct ClearanceField<T = f32> {
+    pub frame: CellFrame<T>,
+    values: Vec<T>, // Satır satır hücre merkezindeki robot açıklığı; engel yoksa sonsuz
+}
+
+impl<T: Scalar> ClearanceField<T> {
+    pub fn sample(frame: CellFrame<T>, checker: &CollisionChecker<'_, T>, robot_radius: T) -> Self {
+        let mut values = Vec::with_capacity(frame.len());
+        for row in 0..frame.rows {
+            for col in 0..frame.cols {
+                values.push(checker.clearance(&frame.cell_center(col, row)) - robot_radius);
+            }
+        }
+        ClearanceField { frame, values }
+    }
+
+    pub fn value(&self, col: usize, row: usize) -> T {
+        self.values[row * self.frame.cols + col]
+    }
+
+    // En açık hücrenin açıklığı; hücre yoksa veya engel yoksa sonsuz olabilir
+    pub fn max_value(&self) -> T {
+        self.values.iter().copied().fold(T::neg_infinity(), T::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::obstacle::{Bounds, Obstacle};
+    use crate::rrt::{Point, RRT};
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn clearance_field_samples_robot_clearance_at_cell_centers() {
+        let mut rrt: RRT = RRT::new(Point { x: 90.0, y: 90.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+        rrt.bounds = Bounds::new(0.0, 100.0, 0.0, 100.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 50.0, y: 50.0 }, radius: 20.0 });
+        rrt.set_robot_radius(5.0);
+        let field = ClearanceField::sample(CellFrame::fitted(rrt.bounds, 10, 10), &rrt.checker(), rrt.robot_radius());
+        for row in 0..10 {
+            for col in 0..10 {
+                let center = field.frame.cell_center(col, row);
+                assert_close(field.value(col, row), rrt.clearance(&center) - 5.0);
+            }
+        }
+        // Engelin ortasındaki hücre içeride, köşe hücresi en açık olandır
+        assert!(field.value(4, 4) < 0.0 && field.value(5, 5) < 0.0);
+        assert_close(field.max_value(), field.value(0, 0));
+        assert_close(field.value(0, 0), 45.0 * 2f32.sqrt() - 25.0);
+
+        // Engelsiz haritada açıklık her yerde sonsuzdur
+        rrt.obstacles.clear();
+        let open = ClearanceField::sample(CellFrame::fitted(rrt.bounds, 3, 2), &rrt.checker(), rrt.robot_radius());
+        assert!(open.max_value().is_infinite() && open.value(2, 1).is_infinite());
+    }
+}
@@ -0,0 +1,31 @@
+// Zaman bütçesi için tekdüze artan saat. Masaüstünde `Instant` kullanılır; `Instant` bulunmayan
+// WASM derlemesinde görselleştirme kendi saatini (macroquad'ın `get_time`) verir.
+use std::time::Instant;
+
+pub trait Clock {
+    // Keyfi bir başlangıçtan bu yana geçen saniye; hiçbir zaman geri gitmez
+    fn now(&self) -> f64;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl SystemClock {
+    pub fn new() -> Self {
+        SystemClock { origin: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        SystemClock::new()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> f64 {
+        self.origin.elapsed().as_secs_f64()
+    }
+}
@@ -0,0 +1,142 @@
+// Bölünmüş ekran karşılaştırma modu: pencerenin iki yarısında iki planlayıcı aynı senaryo ve
+// tohumla, kare başına aynı sayıda iterasyonla büyütülür. Sol yarı ana yapılandırmanın, sağ yarı
+// `--compare` ile verilen yapılandırmanın parametrelerini kullanır; harita, uç noktalar ve tohum
+// her iki taraf için soldakinden alınır.
+//
+// Sol tık iki taraf için de yeni hedef seçip ağaçları yeniden başlatır, sağ tık her iki haritaya
+// engel bırakır (ağaçların yalnızca engelin kestiği dalları budanır), R iki ağacı aynı tohumla
+// yeniden başlatır.
+use macroquad::prelude::*;
+use rrt_visualization::{Obstacle, Path, Point, RRTConfig, StepResult, RRT};
+
+use crate::draw::{fit_camera_in, DrawStyle, EdgeColoring};
+
+// Pencerenin bir yarısında çalışan bağımsız planlayıcı
+struct Pane {
+    label: String,
+    rrt: RRT,
+    path: Option<Path>,
+    found_at: Option<usize>, // Hedefe ulaşılan iterasyon
+}
+
+impl Pane {
+    fn step(&mut self, iterations: usize) {
+        for _ in 0..iterations {
+            if self.path.is_some() {
+                break;
+            }
+            let result = self.rrt.step();
+            if matches!(result, StepResult::Extended { .. } | StepResult::GoalReached { .. }) && self.rrt.goal_node().is_some() {
+                // Tembel modda aday yol doğrulanır; geçersizse büyüme sürer
+                self.rrt.validate_goal_path();
+                self.path = self.rrt.best_path();
+                self.found_at = self.path.as_ref().map(|_| self.rrt.iteration);
+            }
+        }
+    }
+
+    fn restart(&mut self, start: Point, goal: Point, seed: u64) {
+        self.rrt.reseed(seed);
+        self.rrt.reset(start, goal);
+        self.path = None;
+        self.found_at = None;
+    }
+
+    fn camera(&self, viewport: Rect) -> Camera2D {
+        fit_camera_in(self.rrt.bounds, self.rrt.step_size() * 4.0, Some(viewport))
+    }
+
+    fn draw(&self, style: &DrawStyle, viewport: Rect) {
+        set_camera(&self.camera(viewport));
+        for obstacle in &self.rrt.obstacles {
+            style.draw_obstacle(obstacle, self.rrt.robot_radius());
+        }
+        style.draw_tree(&self.rrt, EdgeColoring::Flat);
+        if let Some(path) = &self.path {
+            style.draw_path(path, style.theme.path);
+        }
+        style.draw_endpoints(&self.rrt.starts, &self.rrt.goals);
+
+        set_default_camera();
+        let status = match (&self.path, self.found_at) {
+            (Some(path), Some(at)) => format!("path: {:.1} (iteration {})", path.length(), at),
+            _ => format!("searching... best distance {:.1}", self.rrt.best_goal_distance),
+        };
+        let stats = format!("iterations: {}  nodes: {}", self.rrt.iteration, self.rrt.node_count());
+        for (i, line) in [self.label.as_str(), stats.as_str(), status.as_str()].iter().enumerate() {
+            draw_text(line, viewport.x + 10.0, 20.0 + 18.0 * i as f32, 18.0, style.theme.text);
+        }
+    }
+}
+
+// Pencerenin `index` numaralı yarısı (ekran koordinatlarında)
+fn half(index: usize) -> Rect {
+    let w = screen_width() / 2.0;
+    Rect::new(index as f32 * w, 0.0, w, screen_height())
+}
+
+pub async fn run_compare_window(left: (String, RRTConfig), right: (String, RRTConfig)) {
+    let (left_label, mut left_config) = left;
+    let (right_label, mut right_config) = right;
+    // Tohum verilmemişse iki tarafın aynı rastgele sayıları çekmesi için burada seçilir
+    let seed = left_config.seed.unwrap_or_else(|| (macroquad::miniquad::date::now() * 1000.0) as u64);
+    left_config.seed = Some(seed);
+    right_config.seed = Some(seed);
+    let left_rrt = RRT::from_config(&left_config);
+    let mut right_rrt = RRT::from_config(&right_config);
+    right_rrt.bounds = left_rrt.bounds;
+    right_rrt.obstacles = left_rrt.obstacles.clone();
+    right_rrt.dynamic_obstacles = left_rrt.dynamic_obstacles.clone();
+    right_rrt.set_goals(left_rrt.goals.clone());
+    right_rrt.set_starts(left_rrt.starts.clone());
+
+    let mut panes = [
+        Pane { label: left_label, rrt: left_rrt, path: None, found_at: None },
+        Pane { label: right_label, rrt: right_rrt, path: None, found_at: None },
+    ];
+    let style = DrawStyle::from_config(&left_config);
+    let iterations_per_frame = left_config.iterations_per_frame.max(1);
+
+    loop {
+        if is_quit_requested() || is_key_pressed(KeyCode::Escape) {
+            break;
+        }
+        // İmlecin altındaki yarının kamerasıyla dünya koordinatına çevrilen nokta iki tarafa da uygulanır
+        let (x, y) = mouse_position();
+        let hovered = usize::from(x >= screen_width() / 2.0);
+        let world = Point::from(panes[hovered].camera(half(hovered)).screen_to_world(vec2(x, y)));
+        let (start, goal) = (panes[0].rrt.starts[0], panes[0].rrt.goal());
+        if is_mouse_button_pressed(MouseButton::Left) {
+            for pane in &mut panes {
+                pane.restart(start, world, seed);
+            }
+        } else if is_key_pressed(KeyCode::R) {
+            for pane in &mut panes {
+                pane.restart(start, goal, seed);
+            }
+        }
+        if is_mouse_button_pressed(MouseButton::Right) {
+            for pane in &mut panes {
+                // Engelin kestiği dallar budanır; yol bozulduysa büyüme kaldığı yerden sürer
+                if pane.rrt.add_obstacle(Obstacle::Circle { center: world, radius: 20.0 }) > 0 {
+                    pane.rrt.validate_goal_path();
+                    pane.path = pane.rrt.best_path();
+                    pane.found_at = pane.found_at.filter(|_| pane.path.is_some());
+                }
+            }
+        }
+
+        for pane in &mut panes {
+            pane.step(iterations_per_frame);
+        }
+
+        clear_background(style.theme.background);
+        for (i, pane) in panes.iter().enumerate() {
+            pane.draw(&style, half(i));
+        }
+        let middle = screen_width() / 2.0;
+        draw_line(middle, 0.0, middle, screen_height(), 2.0, style.theme.text);
+
+        next_frame().await;
+    }
+}
@@ -0,0 +1,697 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::car::CarSpec;
+use crate::fleet::RobotSpec;
+use crate::mapgen::{ClutterSpec, MazeSpec};
+use crate::terrain::TerrainSpec;
+use crate::obstacle::{Bounds, DynamicObstacle, Obstacle, RobotShape};
+use crate::rrt::Point;
+
+// Örneklerin nasıl üretileceğini belirler
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SamplerKind {
+    // Alan içinde düzgün dağılımlı örnekleme
+    #[default]
+    Uniform,
+    // `goal_bias` olasılığıyla doğrudan hedefi örnekler
+    GoalBiased,
+    // `frontier_bias` olasılığıyla örneği, en yakın düğüm olarak az seçilmiş (sınırdaki)
+    // düğümlerin bölgelerine yönlendirir; hedef yanlılığı da uygulanır
+    Frontier,
+}
+
+// Hedef yanlılığının iterasyonlar boyunca nasıl değiştiği. Yanlılık yalnızca `goal_biased` ve
+// `frontier` örneklemede kullanılır. `iterations` verilmezse `max_iters` alınır; son iterasyonda `end`
+// değerine ulaşılır ve sonrasında değişmez.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BiasSchedule {
+    // Her iterasyonda `goal_bias`
+    #[default]
+    Constant,
+    // `start` değerinden `end` değerine doğrusal geçiş
+    Linear { start: f32, end: f32, #[serde(default)] iterations: Option<usize> },
+    // Her iterasyonda aynı oranla çarpılarak `start` değerinden `end` değerine geçiş
+    Exponential { start: f32, end: f32, #[serde(default)] iterations: Option<usize> },
+}
+
+impl BiasSchedule {
+    // Verilen iterasyondaki (sıfırdan sayılır) yanlılık; `Constant` için `goal_bias` geçerli
+    // olduğundan `None` döner
+    pub fn bias_at(&self, iteration: usize) -> Option<f64> {
+        let (start, end, iterations, exponential) = match *self {
+            BiasSchedule::Constant => return None,
+            BiasSchedule::Linear { start, end, iterations } => (start, end, iterations, false),
+            BiasSchedule::Exponential { start, end, iterations } => (start, end, iterations, true),
+        };
+        let (start, end) = (start as f64, end as f64);
+        let last = iterations.unwrap_or(1).saturating_sub(1).max(1);
+        let t = (iteration as f64 / last as f64).min(1.0);
+        // Sıfır veya negatif uçlarda üstel geçiş tanımsız olduğundan doğrusala düşülür
+        Some(if exponential && start > 0.0 && end > 0.0 {
+            start * (end / start).powf(t)
+        } else {
+            start + (end - start) * t
+        })
+    }
+
+    // Süre verilmemişse iterasyon bütçesini kullanır
+    pub fn with_default_iterations(self, max_iters: usize) -> Self {
+        match self {
+            BiasSchedule::Constant => self,
+            BiasSchedule::Linear { start, end, iterations } => {
+                BiasSchedule::Linear { start, end, iterations: iterations.or(Some(max_iters)) }
+            }
+            BiasSchedule::Exponential { start, end, iterations } => {
+                BiasSchedule::Exponential { start, end, iterations: iterations.or(Some(max_iters)) }
+            }
+        }
+    }
+}
+
+// Yönlendirmede atılan adımın uzunluğu. `ClearanceScaled` adımı kaynak noktanın en yakın engele
+// açıklığı (robot yarıçapı düşülerek) kadar tutar ve [min, max] aralığına kırpar: açık alanda
+// uzun, engel yakınında kısa adımlar atılır. Açıklık kadar bir adım engele giremeyeceğinden dar
+// geçitler atlanmaz.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepPolicy {
+    // Her adım `step_size` uzunluğunda
+    #[default]
+    Fixed,
+    ClearanceScaled { min: f32, max: f32 },
+}
+
+// Potansiyel alanla yönlendirme: adımın yönü örneğe, en yakın hedefe ve etki yarıçapı içindeki en
+// yakın engelden uzağa bakan birim vektörlerin kazançlarla ağırlıklı toplamıdır. İtme, engele
+// (robot yarıçapı düşülerek) uzaklık `influence_radius` değerinden küçükken başlar ve engele
+// yaklaştıkça doğrusal olarak `obstacle_gain` değerine çıkar. Toplam neredeyse sıfırsa alan yok
+// sayılıp düz yönlendirme yapılır.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PotentialField {
+    pub sample_gain: f32,
+    pub goal_gain: f32,
+    pub obstacle_gain: f32,
+    pub influence_radius: f32,
+}
+
+impl Default for PotentialField {
+    fn default() -> Self {
+        PotentialField { sample_gain: 1.0, goal_gain: 0.5, obstacle_gain: 1.0, influence_radius: 20.0 }
+    }
+}
+
+// Açıklık cezasının biçimi; her ikisi de `clearance_weight` ile ağırlıklandırılır
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClearanceCost {
+    // `clearance_distance` altında kalınan oranın karesi; uzaktaki kenarlar cezasızdır
+    #[default]
+    Shortfall,
+    // Kenar maliyeti `uzunluk · (1 + ağırlık / orta noktanın açıklığı)`; her kenar cezalanır, en
+    // ucuz rota iki duvara da uzak durur
+    Inverse,
+}
+
+// Birden çok kökten büyüyen ormanda genişletilecek düğümün seçimi
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForestGrowth {
+    // Örneğe tüm ormandaki en yakın düğüm genişletilir; örneğe yakın ağaçlar daha çok büyür
+    #[default]
+    Nearest,
+    // Her örnek sıradaki kökün ağacındaki en yakın düğümden genişletilir; ağaçlar eşit sayıda örnek alır
+    RoundRobin,
+}
+
+// En yakın düğüm aramasında kullanılan indeks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NeighborIndexKind {
+    // Tüm düğümler taranır
+    #[default]
+    Linear,
+    // `grid_cell_size` boyutunda hücrelere bölünmüş düzgün ızgara
+    Grid,
+    // Eklendikçe büyüyen k-d ağacı
+    KdTree,
+}
+
+// Düğüm sınırına ulaşıldığında yapılacak işlem
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeCapPolicy {
+    // Yeni düğüm eklenmez, ağaç doymuş olarak işaretlenir
+    #[default]
+    Saturate,
+    // Hedeften ve bulunan yoldan en uzak yapraklar silinerek yer açılır
+    Prune,
+}
+
+// Görselleştirmenin renk teması
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeKind {
+    #[default]
+    Light,
+    Dark,
+}
+
+// Kullanılacak planlama algoritması
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannerKind {
+    #[default]
+    Rrt,
+    Prm,
+}
+
+// Bir senaryonun tamamını tanımlayan planlayıcı yapılandırması
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RRTConfig {
+    pub start: Option<Point>, // Verilmezse alan içinde rastgele seçilir
+    pub goal: Option<Point>, // Verilmezse alan içinde rastgele seçilir
+    pub extra_goals: Vec<Point>, // Birincil hedefe ek olarak kabul edilen hedefler
+    pub extra_starts: Vec<Point>, // Birincil başlangıca ek kökler; ağaç her kökten büyüyen bir ormana dönüşür
+    pub forest_growth: ForestGrowth,
+    pub robots: Vec<RobotSpec>, // Birincil robottan sonra sırayla planlanan ek robotlar; öncekilerle zamanda çakışmazlar
+    pub car: Option<CarSpec>, // Verilmişse birincil robot ileri ve geri gidebilen bir arabadır ve Reeds-Shepp eğrileriyle planlanır
+    pub step_size: f32,
+    pub step_policy: StepPolicy,
+    pub max_edge_length: Option<f32>, // Verilmişse daha uzun kenarlar ara düğümlere bölünür
+    pub potential_field: Option<PotentialField>, // Verilmişse adımlar hedefe çekilip engellerden itilir
+    pub goal_threshold: f32,
+    pub goal_bias: f32,
+    pub goal_bias_schedule: BiasSchedule,
+    pub bounds: Bounds,
+    pub seed: Option<u64>,
+    pub sampler: SamplerKind,
+    pub frontier_bias: f32, // Sınır örneklemesinde örneğin sınır düğümlerine yönlendirilme olasılığı
+    // Yeniden planlamada örneğin önceki yolun ara noktalarından birinin çevresinden çekilme
+    // olasılığı ve bu çevrenin (normal dağılımın) standart sapması
+    pub waypoint_bias: f32,
+    pub waypoint_sigma: f32,
+    pub obstacles: Vec<Obstacle>,
+    pub dynamic_obstacles: Vec<DynamicObstacle>, // Zamanla hareket eden engeller; düğümler varış zamanı taşır
+    pub speed: f32, // Robotun hızı; kenarın süresi uzunluk / hız
+    pub clutter: Option<ClutterSpec>, // Verilmişse rastgele engeller üretilip `obstacles` listesine eklenir
+    pub maze: Option<MazeSpec>, // Verilmişse labirent duvarları üretilip `obstacles` listesine eklenir
+    pub terrain: Option<TerrainSpec>, // Verilmişse kenar maliyeti arazi çarpanıyla ağırlıklandırılır
+    pub terrain_bias: f32, // Arazi varken örneğin hücre maliyetiyle ters orantılı çekilme olasılığı; kalanı düzgündür
+    pub collision_resolution: f32,
+    pub adaptive_collision: bool,
+    pub robot_radius: f32, // Sıfırdan büyükse engeller bu yarıçap kadar şişirilir
+    pub robot_shape: RobotShape, // `{ type = "polygon", vertices = [...] }` verilirse disk yerine robotun kapladığı çokgen kullanılır
+    // Sıfırdan büyükse engellere yakın kenarların maliyeti artırılır, bu kenarlar olasılıkla
+    // reddedilir ve yeni düğümün ebeveyni yakın düğümler arasından en ucuz olan seçilir
+    pub clearance_weight: f32,
+    pub clearance_distance: f32, // Açıklık cezasının başladığı engel mesafesi
+    pub clearance_cost: ClearanceCost, // `"inverse"` verilirse ceza orta noktanın açıklığıyla ters orantılıdır
+    pub max_iters: usize, // Ekransız planlamada iterasyon sınırı
+    pub time_budget_ms: Option<u64>, // Verilmişse planlama bu kadar milisaniye sonra durur
+    pub batch_size: usize, // Ekransız planlamada bir toplu genişletmede denenen aday sayısı; `rayon` özelliğiyle paralel denetlenir
+    // Yol bulunduktan sonra maliyet alt sınırı en iyi yolu aşan düğümler budanır, bu tür
+    // genişlemeler çarpışma kontrolünden önce reddedilir
+    pub branch_and_bound: bool,
+    pub branch_and_bound_interval: usize, // Budama geçişleri arasındaki iterasyon sayısı
+    pub goal_connect_interval: usize, // Sıfırdan büyükse her bu kadar iterasyonda en yeni düğümden hedefe düz bağlantı denenir
+    // Verilmişse kökten maliyeti bu değeri aşacak genişlemeler reddedilir ve yalnızca bu bütçe
+    // içindeki yollar hedefe ulaşmış sayılır (ör. bataryanın izin verdiği en uzun yol)
+    pub max_path_cost: Option<f32>,
+    pub max_nodes: Option<usize>, // Verilmişse ağaç bu kadar düğümü aşmaz; sınırda `node_cap_policy` uygulanır
+    pub node_cap_policy: NodeCapPolicy,
+    pub node_capacity: Option<usize>, // Düğüm vektörü için önceden ayrılacak yer; verilmezse `max_iters + 1`
+    pub neighbor_index: NeighborIndexKind,
+    pub grid_cell_size: f32, // Izgara indeksinin hücre boyutu
+    pub kd_tree_rebalance: f32, // Son kurulumdan beri eklenen düğümler boyunun bu oranını aşınca k-d ağacı baştan kurulur; sıfırsa hiç kurulmaz
+    pub planner: PlannerKind,
+    pub prm_samples: usize, // PRM yol haritasındaki köşe sayısı
+    pub prm_neighbors: usize, // PRM köşelerinin bağlandığı en yakın komşu sayısı
+    pub astar_cell_size: f32, // Karşılaştırma için kullanılan ızgara A* hücre boyutu
+    pub compare_astar: bool, // Bulunan yolu ızgara A* yolu ile karşılaştırır
+    pub any_angle: bool, // Düğümleri görüş hattı olan en uzak atalarına bağlar
+    pub any_angle_depth: usize,
+    pub cross_edges: bool, // Yeni düğümleri yarıçap içindeki görünür düğümlere de bağlar; yol çizge üzerinde aranır
+    pub cross_edge_radius: f32,
+    pub lazy_collision: bool, // Kenar kontrollerini aday yol doğrulanana kadar erteler
+    pub dynamic_domain: bool, // Örnekleri ağacın ulaşabildiği bölgeyle sınırlar (DD-RRT)
+    pub dynamic_domain_radius: f32,
+    pub dynamic_domain_shrink: f32,
+    pub rejection_sampling: bool, // Düzgün örnekler engel dışına düşene kadar (bir sınıra kadar) yeniden çekilir
+    pub window_width: i32, // Pencere boyutu; varsayılanlar macroquad'ınkilerle aynıdır
+    pub window_height: i32,
+    pub high_dpi: bool,
+    pub node_radius: f32, // Başlangıç ve hedef işaretlerinin yarıçapı
+    pub edge_width: f32, // Ağaç kenarlarının çizgi kalınlığı
+    pub path_width: f32, // Bulunan yolun çizgi kalınlığı
+    pub heatmap_resolution: usize, // Örnek yoğunluğu haritasının eksen başına hücre sayısı
+    pub coverage_resolution: usize, // Kapsama ızgarasının eksen başına hücre sayısı; sıfırsa kapsama ölçülmez
+    pub clearance_field_resolution: usize, // L ile açılan açıklık alanının eksen başına hücre sayısı; alan her karede yeniden örneklenir
+    pub theme: ThemeKind,
+    pub iterations_per_frame: usize, // Görselleştirmede çizilen her karede çalışan planlama iterasyonu
+    pub export_frame_id: String, // Dışa aktarılan nav_msgs/Path iletisinin koordinat çerçevesi
+    pub export_scale: f32, // Dışa aktarılan yolda dünya birimini (piksel) metreye çeviren çarpan
+}
+
+impl Default for RRTConfig {
+    fn default() -> Self {
+        RRTConfig {
+            start: None,
+            goal: None,
+            extra_goals: Vec::new(),
+            extra_starts: Vec::new(),
+            forest_growth: ForestGrowth::Nearest,
+            robots: Vec::new(),
+            car: None,
+            step_size: 10.0,
+            step_policy: StepPolicy::Fixed,
+            max_edge_length: None,
+            potential_field: None,
+            goal_threshold: 10.0,
+            goal_bias: 0.0,
+            goal_bias_schedule: BiasSchedule::Constant,
+            bounds: Bounds::default(),
+            seed: None,
+            sampler: SamplerKind::Uniform,
+            frontier_bias: 0.8,
+            waypoint_bias: 0.0,
+            waypoint_sigma: 10.0,
+            obstacles: Vec::new(),
+            dynamic_obstacles: Vec::new(),
+            speed: 50.0,
+            clutter: None,
+            maze: None,
+            terrain: None,
+            terrain_bias: 0.0,
+            collision_resolution: 1.0,
+            adaptive_collision: false,
+            robot_radius: 0.0,
+            robot_shape: RobotShape::Disk,
+            clearance_weight: 0.0,
+            clearance_distance: 20.0,
+            clearance_cost: ClearanceCost::Shortfall,
+            max_iters: 10_000,
+            time_budget_ms: None,
+            batch_size: 1,
+            branch_and_bound: false,
+            branch_and_bound_interval: 100,
+            goal_connect_interval: 0,
+            max_path_cost: None,
+            max_nodes: None,
+            node_cap_policy: NodeCapPolicy::Saturate,
+            node_capacity: None,
+            neighbor_index: NeighborIndexKind::Linear,
+            grid_cell_size: 20.0,
+            kd_tree_rebalance: 0.3,
+            planner: PlannerKind::Rrt,
+            prm_samples: 300,
+            prm_neighbors: 10,
+            astar_cell_size: 5.0,
+            compare_astar: false,
+            any_angle: false,
+            any_angle_depth: 8,
+            cross_edges: false,
+            cross_edge_radius: 25.0,
+            lazy_collision: false,
+            dynamic_domain: false,
+            dynamic_domain_radius: 50.0,
+            dynamic_domain_shrink: 0.8,
+            rejection_sampling: false,
+            window_width: 800,
+            window_height: 600,
+            high_dpi: false,
+            node_radius: 5.0,
+            edge_width: 1.0,
+            path_width: 2.0,
+            heatmap_resolution: 40,
+            coverage_resolution: 20,
+            clearance_field_resolution: 60,
+            theme: ThemeKind::Light,
+            iterations_per_frame: 1,
+            export_frame_id: "map".to_string(),
+            export_scale: 1.0,
+        }
+    }
+}
+
+// Yapılandırma dosyası okunurken oluşabilecek hatalar
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    Invalid(String), // Okunan değerler kullanılamaz (ör. NaN veya sonsuz koordinat)
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "could not read config file: {}", e),
+            ConfigError::Parse(e) => write!(f, "invalid config file: {}", e),
+            ConfigError::Serialize(e) => write!(f, "could not serialize config: {}", e),
+            ConfigError::Invalid(reason) => write!(f, "invalid config: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Parse(e)
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(e: toml::ser::Error) -> Self {
+        ConfigError::Serialize(e)
+    }
+}
+
+impl RRTConfig {
+    // TOML metninden yapılandırma okur; eksik alanlar varsayılan değerleri alır
+    pub fn from_toml_str(s: &str) -> Result<RRTConfig, ConfigError> {
+        let mut config: RRTConfig = toml::from_str(s)?;
+        config.validate()?;
+        // Arazi görüntüsü yüklenirken okunur; planlayıcılar yalnızca çarpan ızgarası görür
+        if let Some(terrain) = config.terrain.take() {
+            let loaded = terrain.load().map_err(|e| ConfigError::Invalid(format!("could not load terrain image: {}", e)))?;
+            config.terrain = Some(loaded);
+        }
+        Ok(config)
+    }
+
+    // Uç noktaların, alanın ve engellerin tüm koordinatları sonlu olmalıdır. TOML `nan` ve `inf`
+    // değerlerini kabul ettiğinden bu değerler (ör. bozuk bir algılayıcıdan gelen) açık bir hatayla
+    // reddedilir; aksi halde uzaklıklar ve yönlendirme açıları sessizce NaN olurdu.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let check = |name: &str, p: &Point| {
+            if p.is_finite() {
+                Ok(())
+            } else {
+                Err(ConfigError::Invalid(format!("{} has a non-finite coordinate ({}, {})", name, p.x, p.y)))
+            }
+        };
+        if let Some(start) = &self.start {
+            check("start", start)?;
+        }
+        if let Some(goal) = &self.goal {
+            check("goal", goal)?;
+        }
+        for p in &self.extra_starts {
+            check("extra start", p)?;
+        }
+        for p in &self.extra_goals {
+            check("extra goal", p)?;
+        }
+        for robot in &self.robots {
+            check("robot start", &robot.start)?;
+            check("robot goal", &robot.goal)?;
+            if let Some(radius) = robot.radius {
+                if !(radius.is_finite() && radius >= 0.0) {
+                    return Err(ConfigError::Invalid(format!("robot radius must be non-negative, got {}", radius)));
+                }
+            }
+        }
+        if let Some(car) = &self.car {
+            if !(car.turning_radius.is_finite() && car.turning_radius > 0.0) {
+                return Err(ConfigError::Invalid(format!("car turning_radius must be positive, got {}", car.turning_radius)));
+            }
+            if car.heading_tolerance.is_nan() || car.heading_tolerance < 0.0 {
+                return Err(ConfigError::Invalid(format!("car heading_tolerance must be non-negative, got {}", car.heading_tolerance)));
+            }
+        }
+        let b = &self.bounds;
+        check("bounds minimum", &Point { x: b.min_x, y: b.min_y })?;
+        check("bounds maximum", &Point { x: b.max_x, y: b.max_y })?;
+        // Boş aralıktan örnek çekilemez
+        if !(b.min_x < b.max_x && b.min_y < b.max_y) {
+            return Err(ConfigError::Invalid(format!("bounds must satisfy min < max on both axes, got x {}..{} and y {}..{}", b.min_x, b.max_x, b.min_y, b.max_y)));
+        }
+        if !(self.step_size.is_finite() && self.step_size > 0.0) {
+            return Err(ConfigError::Invalid(format!("step_size must be positive, got {}", self.step_size)));
+        }
+        if !(self.goal_threshold.is_finite() && self.goal_threshold > 0.0) {
+            return Err(ConfigError::Invalid(format!("goal_threshold must be positive, got {}", self.goal_threshold)));
+        }
+        for obstacle in self.obstacles.iter().chain(self.dynamic_obstacles.iter().map(|o| &o.shape)) {
+            match obstacle {
+                Obstacle::Circle { center, radius } => {
+                    check("obstacle center", center)?;
+                    check("obstacle radius", &Point { x: *radius, y: 0.0 })?;
+                }
+                Obstacle::Rect { min, max } => {
+                    check("obstacle corner", min)?;
+                    check("obstacle corner", max)?;
+                }
+            }
+        }
+        if let Some(TerrainSpec::Grid { costs }) = &self.terrain {
+            let cols = costs.first().map_or(0, Vec::len);
+            if cols == 0 || costs.iter().any(|row| row.len() != cols) {
+                return Err(ConfigError::Invalid("terrain costs must be a non-empty grid with rows of equal length".to_string()));
+            }
+            // Sonsuz çarpan geçilmez ve örneklenmez hücredir
+            if let Some(&cost) = costs.iter().flatten().find(|&&c| c.is_nan() || c <= 0.0) {
+                return Err(ConfigError::Invalid(format!("terrain costs must be positive, got {}", cost)));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.terrain_bias) {
+            return Err(ConfigError::Invalid(format!("terrain_bias must be between 0 and 1, got {}", self.terrain_bias)));
+        }
+        if let Some(TerrainSpec::Image { min_cost, max_cost, .. }) = &self.terrain {
+            if !(min_cost.is_finite() && max_cost.is_finite() && *min_cost > 0.0 && max_cost >= min_cost) {
+                return Err(ConfigError::Invalid(format!("terrain image costs must satisfy 0 < min_cost <= max_cost, got {} and {}", min_cost, max_cost)));
+            }
+        }
+        if !(self.kd_tree_rebalance.is_finite() && self.kd_tree_rebalance >= 0.0) {
+            return Err(ConfigError::Invalid(format!("kd_tree_rebalance must be non-negative, got {}", self.kd_tree_rebalance)));
+        }
+        if let Some(length) = self.max_edge_length {
+            if !(length.is_finite() && length > 0.0) {
+                return Err(ConfigError::Invalid(format!("max_edge_length must be positive, got {}", length)));
+            }
+        }
+        if !(self.export_scale.is_finite() && self.export_scale > 0.0) {
+            return Err(ConfigError::Invalid(format!("export_scale must be positive, got {}", self.export_scale)));
+        }
+        if let Some(cost) = self.max_path_cost {
+            if !(cost.is_finite() && cost > 0.0) {
+                return Err(ConfigError::Invalid(format!("max_path_cost must be positive, got {}", cost)));
+            }
+        }
+        if let Some(field) = &self.potential_field {
+            let gains = [field.sample_gain, field.goal_gain, field.obstacle_gain];
+            if gains.iter().any(|g| !(g.is_finite() && *g >= 0.0)) || !(field.influence_radius.is_finite() && field.influence_radius > 0.0) {
+                return Err(ConfigError::Invalid(format!("potential field gains must be non-negative and influence_radius positive, got {:?}", field)));
+            }
+        }
+        if let RobotShape::Polygon(vertices) = &self.robot_shape {
+            if vertices.is_empty() {
+                return Err(ConfigError::Invalid("robot polygon has no vertices".to_string()));
+            }
+            for v in vertices {
+                check("robot polygon vertex", v)?;
+            }
+        }
+        Ok(())
+    }
+
+    // TOML dosyasından yapılandırma okur
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<RRTConfig, ConfigError> {
+        let text = fs::read_to_string(path)?;
+        RRTConfig::from_toml_str(&text)
+    }
+}
+
+// Etkileşimli olarak çizilen haritanın kaydedilen kısmı: alan, uç noktalar ve engeller.
+// Alan adları senaryo dosyasıyla aynı olduğundan kaydedilen dosya `--config` ile de açılabilir.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MapLayout {
+    pub start: Point,
+    pub goal: Point,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_goals: Vec<Point>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_starts: Vec<Point>,
+    pub bounds: Bounds,
+    pub obstacles: Vec<Obstacle>,
+}
+
+impl MapLayout {
+    // f32 değerleri en kısa kayıpsız gösterimleriyle yazılır; okunup yeniden yazılan dosya aynı kalır
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string(self)?)
+    }
+
+    pub fn from_toml_str(s: &str) -> Result<MapLayout, ConfigError> {
+        let layout: MapLayout = toml::from_str(s)?;
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    // Senaryo dosyasıyla aynı kurallar: sonlu koordinatlar ve boş olmayan alan
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        RRTConfig {
+            start: Some(self.start),
+            goal: Some(self.goal),
+            extra_goals: self.extra_goals.clone(),
+            extra_starts: self.extra_starts.clone(),
+            bounds: self.bounds,
+            obstacles: self.obstacles.clone(),
+            ..RRTConfig::default()
+        }
+        .validate()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ConfigError> {
+        fs::write(path, self.to_toml_string()?)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<MapLayout, ConfigError> {
+        MapLayout::from_toml_str(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation_rejects_empty_bounds_and_non_positive_step_or_threshold() {
+        let bounds = |min_x: f32, max_x: f32, min_y: f32, max_y: f32| {
+            format!("[bounds]\nmin_x = {:?}\nmax_x = {:?}\nmin_y = {:?}\nmax_y = {:?}\n", min_x, max_x, min_y, max_y)
+        };
+        for toml in [
+            bounds(100.0, 100.0, 0.0, 100.0),
+            bounds(0.0, 100.0, 50.0, 10.0),
+            "step_size = 0.0".to_string(),
+            "step_size = -5.0".to_string(),
+            "goal_threshold = 0.0".to_string(),
+            "goal_threshold = nan".to_string(),
+        ] {
+            assert!(matches!(RRTConfig::from_toml_str(&toml), Err(ConfigError::Invalid(_))), "{}", toml);
+        }
+        assert!(RRTConfig::from_toml_str(&bounds(0.0, 100.0, 0.0, 1.0)).is_ok());
+    }
+
+    #[test]
+    fn layouts_with_non_finite_coordinates_or_radii_are_rejected() {
+        let layout = |obstacle: &str| {
+            format!(
+                "start = {{ x = 10.0, y = 10.0 }}\ngoal = {{ x = 90.0, y = 90.0 }}\n\n[bounds]\nmin_x = 0.0\nmax_x = 100.0\nmin_y = 0.0\nmax_y = 100.0\n\n[[obstacles]]\n{}\n",
+                obstacle
+            )
+        };
+        assert!(MapLayout::from_toml_str(&layout("type = \"circle\"\ncenter = { x = 50.0, y = 50.0 }\nradius = 5.0")).is_ok());
+        for obstacle in [
+            "type = \"circle\"\ncenter = { x = nan, y = 50.0 }\nradius = 5.0",
+            "type = \"circle\"\ncenter = { x = 50.0, y = 50.0 }\nradius = inf",
+            "type = \"rect\"\nmin = { x = 40.0, y = -inf }\nmax = { x = 60.0, y = 60.0 }",
+        ] {
+            assert!(matches!(MapLayout::from_toml_str(&layout(obstacle)), Err(ConfigError::Invalid(_))), "{}", obstacle);
+        }
+    }
+
+    #[test]
+    fn scenario_toml_populates_every_field() {
+        let config = RRTConfig::from_toml_str(
+            r#"
+            start = { x = 20.0, y = 30.0 }
+            goal = { x = 380.0, y = 370.0 }
+            step_size = 12.5
+            goal_threshold = 8.0
+            goal_bias = 0.1
+            seed = 42
+            sampler = "goal_biased"
+
+            [bounds]
+            min_x = -10.0
+            max_x = 400.0
+            min_y = 0.0
+            max_y = 450.0
+
+            [[obstacles]]
+            type = "circle"
+            center = { x = 200.0, y = 200.0 }
+            radius = 60.0
+
+            [[obstacles]]
+            type = "rect"
+            min = { x = 80.0, y = 250.0 }
+            max = { x = 160.0, y = 270.0 }
+            "#,
+        )
+        .expect("config parses");
+        assert_eq!((config.start, config.goal), (Some(Point { x: 20.0, y: 30.0 }), Some(Point { x: 380.0, y: 370.0 })));
+        assert_eq!((config.step_size, config.goal_threshold, config.goal_bias), (12.5, 8.0, 0.1));
+        assert_eq!((config.seed, config.sampler), (Some(42), SamplerKind::GoalBiased));
+        let b = config.bounds;
+        assert_eq!((b.min_x, b.max_x, b.min_y, b.max_y), (-10.0, 400.0, 0.0, 450.0));
+        assert_eq!(config.obstacles.len(), 2);
+        assert!(matches!(config.obstacles[0], Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 }));
+        assert!(matches!(config.obstacles[1], Obstacle::Rect { min: Point { x: 80.0, y: 250.0 }, max: Point { x: 160.0, y: 270.0 } }));
+
+        // Verilmeyen alanlar varsayılan değerleri alır
+        let defaults = RRTConfig::from_toml_str("").expect("empty config parses");
+        assert_eq!((defaults.step_size, defaults.sampler), (RRTConfig::default().step_size, SamplerKind::Uniform));
+        assert!(defaults.obstacles.is_empty() && defaults.start.is_none());
+    }
+
+    #[test]
+    fn map_layout_save_load_save_is_byte_identical() {
+        let third = 1.0f32 / 3.0;
+        let layout = MapLayout {
+            start: Point { x: 0.1, y: 20.0 },
+            goal: Point { x: 380.25, y: 123.456 },
+            extra_goals: vec![Point { x: third, y: 7.0e-3 }],
+            extra_starts: Vec::new(),
+            bounds: Bounds::new(0.0, 400.0, 0.0, 400.0),
+            obstacles: vec![
+                Obstacle::Circle { center: Point { x: 100.7, y: third * 300.0 }, radius: 12.3 },
+                Obstacle::Rect { min: Point { x: 200.0, y: 0.0 }, max: Point { x: 210.9, y: 299.99 } },
+            ],
+        };
+        let path = std::env::temp_dir().join(format!("rrt_layout_round_trip_{}.toml", std::process::id()));
+        layout.save(&path).expect("layout saves");
+        let first = fs::read_to_string(&path).expect("saved layout is readable");
+        let loaded = MapLayout::load(&path).expect("layout loads");
+        loaded.save(&path).expect("layout saves again");
+        let second = fs::read_to_string(&path).expect("saved layout is readable");
+        // Kaydedilen harita senaryo olarak da açılır
+        let config = RRTConfig::from_toml_file(&path).expect("layout opens as a scenario");
+        fs::remove_file(&path).expect("layout file is removed");
+
+        assert_eq!(first, second);
+        assert_eq!(loaded.goal, layout.goal);
+        assert_eq!(loaded.extra_goals, layout.extra_goals);
+        assert_eq!(format!("{:?}", loaded.obstacles), format!("{:?}", layout.obstacles));
+        assert_eq!(config.start, Some(layout.start));
+        assert_eq!(config.obstacles.len(), layout.obstacles.len());
+    }
+
+    #[test]
+    fn config_with_non_finite_coordinates_is_rejected() {
+        let error = RRTConfig::from_toml_str("goal = { x = nan, y = 10.0 }").unwrap_err();
+        assert!(matches!(error, ConfigError::Invalid(_)), "{}", error);
+        assert!(error.to_string().contains("goal"), "{}", error);
+        let error = RRTConfig::from_toml_str("[[obstacles]]\ntype = \"circle\"\ncenter = { x = 5.0, y = 5.0 }\nradius = inf\n").unwrap_err();
+        assert!(matches!(error, ConfigError::Invalid(_)), "{}", error);
+        assert!(RRTConfig::from_toml_str("goal = { x = 50.0, y = 10.0 }").is_ok());
+    }
+}
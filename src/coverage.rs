@@ -0,0 +1,80 @@
+// Ağacın serbest alanı ne kadar kapladığını ölçen düzgün ızgara: bir hücreye düğüm düştüğünde
+// hücre kaplanmış sayılır ve sayaçlar düğüm eklendikçe güncellenir. Merkezi (robot yarıçapı kadar
+// şişirilmiş) bir engelin içinde kalan hücreler serbest sayılmaz, böylece tam büyümüş bir ağaçta
+// kapsama %100'e yaklaşır. Serbest olmayan hücrelere düşen düğümler kapsamaya katılmaz.
+use crate::grid::CellFrame;
+use crate::obstacle::CollisionChecker;
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+#[derive(Clone, Debug)]
+pub struct CoverageGrid<T = f32> {
+    pub frame: CellFrame<T>,
+    free: Vec<bool>, // Satır satır hücrenin serbest alanda olup olmadığı
+    covered: Vec<bool>, // Satır satır hücreye en az bir düğüm düşüp düşmediği
+    free_cells: usize,
+    covered_free_cells: usize,
+}
+
+impl<T: Scalar> CoverageGrid<T> {
+    // Bütün hücreleri serbest ve boş kabul eden ızgara
+    pub fn new(frame: CellFrame<T>) -> Self {
+        let cells = frame.len();
+        CoverageGrid { frame, free: vec![true; cells], covered: vec![false; cells], free_cells: cells, covered_free_cells: 0 }
+    }
+
+    // Hücreleri merkezlerinin serbest olup olmadığına göre yeniden işaretler ve kaplananları siler
+    pub fn mark_free(&mut self, checker: &CollisionChecker<'_, T>) {
+        for row in 0..self.frame.rows {
+            for col in 0..self.frame.cols {
+                self.free[row * self.frame.cols + col] = checker.is_point_free(&self.frame.cell_center(col, row));
+            }
+        }
+        self.free_cells = self.free.iter().filter(|&&free| free).count();
+        self.clear();
+    }
+
+    // Noktanın düştüğü hücreyi kaplanmış işaretler; alan dışındaki noktalar yok sayılır
+    pub fn record(&mut self, p: &Point<T>) {
+        let Some((col, row)) = self.frame.cell_of(p) else {
+            return;
+        };
+        let cell = row * self.frame.cols + col;
+        if !self.covered[cell] {
+            self.covered[cell] = true;
+            if self.free[cell] {
+                self.covered_free_cells += 1;
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.covered.iter_mut().for_each(|c| *c = false);
+        self.covered_free_cells = 0;
+    }
+
+    pub fn is_free(&self, col: usize, row: usize) -> bool {
+        self.free[row * self.frame.cols + col]
+    }
+
+    pub fn is_covered(&self, col: usize, row: usize) -> bool {
+        self.covered[row * self.frame.cols + col]
+    }
+
+    pub fn free_cells(&self) -> usize {
+        self.free_cells
+    }
+
+    pub fn covered_free_cells(&self) -> usize {
+        self.covered_free_cells
+    }
+
+    // Kaplanan serbest hücrelerin serbest hücrelere oranı [0, 1]; serbest hücre yoksa sıfır
+    pub fn coverage(&self) -> T {
+        if self.free_cells == 0 {
+            T::zero()
+        } else {
+            T::cast(self.covered_free_cells) / T::cast(self.free_cells)
+        }
+    }
+}
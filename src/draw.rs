@@ -0,0 +1,380 @@
+// Pencere modlarının ortak çizim yardımcıları
+use macroquad::prelude::*;
+use rrt_visualization::{Bounds, ClearanceField, CostMap, CoverageGrid, Node, Obstacle, Point, RRTConfig, RobotShape, SampleHeatmap, ThemeKind, RRT};
+
+// Tüm çizim renkleri; `Theme::default()` bugüne kadarki açık renkli görünümdür
+#[derive(Clone, Copy)]
+pub struct Theme {
+    pub background: Color,
+    pub edge: Color,
+    pub edge_far: Color, // Derinlik/maliyet renklendirmesinde köke en uzak kenarların rengi
+    pub path: Color,
+    pub path_label: Color,
+    pub reference_path: Color, // Izgara A* karşılaştırma yolu
+    pub spline: Color, // Yumuşatılmış (Catmull-Rom) yol
+    pub start: Color,
+    pub goal: Color,
+    pub obstacle: Color,
+    pub obstacle_margin: Color, // Robot yarıçapı kadar şişirilmiş engel sınırı
+    pub roadmap: Color,
+    pub node: Color,
+    pub sample: Color,
+    pub accepted: Color,
+    pub rejected: Color,
+    pub heatmap: Color,
+    pub terrain: Color, // Arazi maliyet haritasının en pahalı hücrelerinin tonu
+    pub coverage: Color, // Kapsama ızgarasında kaplanan serbest hücrelerin tonu
+    pub clearance_near: Color, // Açıklık alanında robotun engele değdiği hücrelerin tonu
+    pub clearance_far: Color, // Açıklık alanında yeterince açık hücrelerin tonu
+    pub highlight: Color, // İmlecin altındaki düğüm ve köke giden zinciri
+    pub text: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            background: WHITE,
+            edge: BLUE,
+            edge_far: RED,
+            path: GREEN,
+            path_label: DARKGREEN,
+            reference_path: ORANGE,
+            spline: PURPLE,
+            start: GREEN,
+            goal: RED,
+            obstacle: GRAY,
+            obstacle_margin: LIGHTGRAY,
+            roadmap: LIGHTGRAY,
+            node: DARKBLUE,
+            sample: MAGENTA,
+            accepted: DARKGREEN,
+            rejected: RED,
+            heatmap: Color::new(1.0, 0.0, 0.0, 1.0),
+            terrain: BROWN,
+            coverage: SKYBLUE,
+            clearance_near: RED,
+            clearance_far: GREEN,
+            highlight: GOLD,
+            text: DARKGRAY,
+        }
+    }
+}
+
+impl Theme {
+    // Projektörlerde ve karanlık ortamlarda okunaklı koyu tema
+    pub fn dark() -> Self {
+        Theme {
+            background: Color::from_rgba(24, 24, 28, 255),
+            edge: Color::from_rgba(90, 160, 255, 255),
+            edge_far: Color::from_rgba(255, 120, 90, 255),
+            path: Color::from_rgba(80, 230, 120, 255),
+            path_label: Color::from_rgba(80, 230, 120, 255),
+            reference_path: ORANGE,
+            spline: Color::from_rgba(200, 130, 255, 255),
+            start: Color::from_rgba(80, 230, 120, 255),
+            goal: Color::from_rgba(255, 90, 90, 255),
+            obstacle: Color::from_rgba(95, 95, 105, 255),
+            obstacle_margin: Color::from_rgba(65, 65, 75, 255),
+            roadmap: Color::from_rgba(65, 65, 75, 255),
+            node: Color::from_rgba(160, 200, 255, 255),
+            sample: Color::from_rgba(255, 100, 255, 255),
+            accepted: Color::from_rgba(80, 230, 120, 255),
+            rejected: Color::from_rgba(255, 80, 80, 255),
+            heatmap: Color::from_rgba(255, 160, 0, 255),
+            terrain: Color::from_rgba(170, 120, 60, 255),
+            coverage: Color::from_rgba(90, 160, 255, 255),
+            clearance_near: Color::from_rgba(255, 80, 80, 255),
+            clearance_far: Color::from_rgba(80, 230, 120, 255),
+            highlight: GOLD,
+            text: LIGHTGRAY,
+        }
+    }
+
+    pub fn of(kind: ThemeKind) -> Self {
+        match kind {
+            ThemeKind::Light => Theme::default(),
+            ThemeKind::Dark => Theme::dark(),
+        }
+    }
+}
+
+// Çizim boyutları ve tema; varsayılanlar bugüne kadarki sabit değerlerle aynıdır
+#[derive(Clone, Copy)]
+pub struct DrawStyle {
+    pub node_radius: f32, // Başlangıç ve hedef işaretlerinin yarıçapı
+    pub vertex_radius: f32, // Yol haritası köşelerinin ve düğüm noktalarının yarıçapı
+    pub edge_width: f32,
+    pub path_width: f32,
+    pub theme: Theme,
+}
+
+impl Default for DrawStyle {
+    fn default() -> Self {
+        DrawStyle { node_radius: 5.0, vertex_radius: 1.5, edge_width: 1.0, path_width: 2.0, theme: Theme::default() }
+    }
+}
+
+impl DrawStyle {
+    // Boyutları ve temayı yapılandırmadan alır
+    pub fn from_config(config: &RRTConfig) -> Self {
+        DrawStyle {
+            node_radius: config.node_radius,
+            edge_width: config.edge_width,
+            path_width: config.path_width,
+            theme: Theme::of(config.theme),
+            ..DrawStyle::default()
+        }
+    }
+
+    // Yolu ardışık parçalar halinde çizer
+    pub fn draw_path(&self, path: &[Point], color: Color) {
+        for w in path.windows(2) {
+            draw_line(w[0].x, w[0].y, w[1].x, w[1].y, self.path_width, color);
+        }
+    }
+
+    // Ağacın her düğümünü ebeveynine bağlayan kenarları çizer
+    pub fn draw_tree(&self, rrt: &RRT, coloring: EdgeColoring) {
+        for index in 0..rrt.node_count() {
+            let node = rrt.node(index);
+            if let Some(parent_index) = node.parent {
+                let parent_node = rrt.node(parent_index);
+                draw_line(
+                    node.point.x,
+                    node.point.y,
+                    parent_node.point.x,
+                    parent_node.point.y,
+                    self.edge_width,
+                    coloring.color(self, rrt, node),
+                );
+            }
+        }
+    }
+
+    // Tüm başlangıç ve hedef noktalarını işaretler
+    pub fn draw_endpoints(&self, starts: &[Point], goals: &[Point]) {
+        for start in starts {
+            draw_circle(start.x, start.y, self.node_radius, self.theme.start);
+        }
+        for goal in goals {
+            draw_circle(goal.x, goal.y, self.node_radius, self.theme.goal);
+        }
+    }
+
+    // Robotu `at` noktasında çizer: çokgen robotun kapladığı alanın sınırı, disk robotu dolu daire
+    pub fn draw_robot(&self, shape: &RobotShape, robot_radius: f32, at: Point, color: Color) {
+        match shape.footprint(&at) {
+            Some(footprint) => {
+                for (u, v) in footprint.iter().zip(footprint.iter().cycle().skip(1)) {
+                    draw_line(u.x, u.y, v.x, v.y, self.path_width, color);
+                }
+            }
+            None => draw_circle(at.x, at.y, self.node_radius.max(robot_radius), color),
+        }
+    }
+
+    // Engeli çizer; robot yarıçapı verilmişse şişirilmiş sınırı da ince bir çizgiyle gösterir
+    pub fn draw_obstacle(&self, obstacle: &Obstacle, robot_radius: f32) {
+        match obstacle {
+            Obstacle::Circle { center, radius } => {
+                draw_circle(center.x, center.y, *radius, self.theme.obstacle);
+                if robot_radius > 0.0 {
+                    draw_circle_lines(center.x, center.y, radius + robot_radius, 1.0, self.theme.obstacle_margin);
+                }
+            }
+            Obstacle::Rect { min, max } => {
+                draw_rectangle(min.x, min.y, max.x - min.x, max.y - min.y, self.theme.obstacle);
+                if robot_radius > 0.0 {
+                    // Yalnızca kenarlar çizilir; köşelerdeki çeyrek daire yaylar gösterilmez
+                    let r = robot_radius;
+                    draw_line(min.x, min.y - r, max.x, min.y - r, 1.0, self.theme.obstacle_margin);
+                    draw_line(min.x, max.y + r, max.x, max.y + r, 1.0, self.theme.obstacle_margin);
+                    draw_line(min.x - r, min.y, min.x - r, max.y, 1.0, self.theme.obstacle_margin);
+                    draw_line(max.x + r, min.y, max.x + r, max.y, 1.0, self.theme.obstacle_margin);
+                }
+            }
+        }
+    }
+}
+
+// Düğümün ayrıntılarını imlecin yanında küçük bir kutuda gösterir (ekran koordinatlarında)
+pub fn draw_node_tooltip(rrt: &RRT, index: usize, cursor: Vec2, theme: &Theme) {
+    let node = rrt.node(index);
+    let parent = node.parent.map_or("-".to_string(), |p| p.to_string());
+    let lines = [
+        format!("node {}", index),
+        format!("({:.1}, {:.1})", node.point.x, node.point.y),
+        format!("parent {}", parent),
+        format!("depth {}", node.depth),
+        format!("cost {:.1}", node.cost),
+    ];
+    let (font_size, line_height) = (16.0, 16.0);
+    let width = lines
+        .iter()
+        .map(|line| measure_text(line, None, font_size as u16, 1.0).width)
+        .fold(0.0, f32::max)
+        + 12.0;
+    let height = line_height * lines.len() as f32 + 8.0;
+    // Kutu pencereden taşacaksa imlecin diğer tarafına alınır
+    let mut x = cursor.x + 14.0;
+    let mut y = cursor.y + 14.0;
+    if x + width > screen_width() {
+        x = cursor.x - 14.0 - width;
+    }
+    if y + height > screen_height() {
+        y = cursor.y - 14.0 - height;
+    }
+    draw_rectangle(x, y, width, height, Color { a: 0.9, ..theme.background });
+    draw_rectangle_lines(x, y, width, height, 1.0, theme.text);
+    for (i, line) in lines.iter().enumerate() {
+        draw_text(line, x + 6.0, y + 4.0 + line_height * (i as f32 + 0.8), font_size, theme.text);
+    }
+}
+
+// Örnek yoğunluğunu yarı saydam hücreler olarak çizer; opaklık en kalabalık hücreye göre ölçeklenir
+pub fn draw_heatmap(heatmap: &SampleHeatmap, theme: &Theme) {
+    if heatmap.max_count == 0 {
+        return;
+    }
+    let (w, h) = (heatmap.cell_width(), heatmap.cell_height());
+    for row in 0..heatmap.rows {
+        for col in 0..heatmap.cols {
+            let count = heatmap.counts[row * heatmap.cols + col];
+            if count == 0 {
+                continue;
+            }
+            let t = count as f32 / heatmap.max_count as f32;
+            let x = heatmap.bounds.min_x + col as f32 * w;
+            let y = heatmap.bounds.min_y + row as f32 * h;
+            draw_rectangle(x, y, w, h, Color { a: 0.6 * t, ..theme.heatmap });
+        }
+    }
+}
+
+// Kapsama ızgarasının hücre sınırlarını soluk çizgilerle, kaplanan serbest hücreleri yarı saydam
+// tonla çizer
+pub fn draw_coverage(coverage: &CoverageGrid, theme: &Theme) {
+    let frame = &coverage.frame;
+    let (w, h) = (frame.cell_width, frame.cell_height);
+    for row in 0..frame.rows {
+        for col in 0..frame.cols {
+            let x = frame.bounds.min_x + col as f32 * w;
+            let y = frame.bounds.min_y + row as f32 * h;
+            if coverage.is_free(col, row) && coverage.is_covered(col, row) {
+                draw_rectangle(x, y, w, h, Color { a: 0.25, ..theme.coverage });
+            }
+            draw_rectangle_lines(x, y, w, h, 1.0, Color { a: 0.2, ..theme.text });
+        }
+    }
+}
+
+// Arazi maliyetini arka plan tonu olarak çizer; en ucuz hücreler boş kalır, opaklık en pahalı
+// hücreye göre ölçeklenir
+pub fn draw_cost_map(map: &CostMap, theme: &Theme) {
+    let (min, max) = (map.min_cost(), map.max_cost());
+    if max <= min {
+        return;
+    }
+    let frame = &map.frame;
+    for row in 0..frame.rows {
+        for col in 0..frame.cols {
+            let t = (map.cell_cost(col, row) - min) / (max - min);
+            if t <= 0.0 {
+                continue;
+            }
+            let x = frame.bounds.min_x + col as f32 * frame.cell_width;
+            let y = frame.bounds.min_y + row as f32 * frame.cell_height;
+            draw_rectangle(x, y, frame.cell_width, frame.cell_height, Color { a: 0.5 * t, ..theme.terrain });
+        }
+    }
+}
+
+// Açıklık alanını arka plan tonu olarak çizer: açıklığı sıfır olan (robotun engele değdiği)
+// hücrelerden `saturation` açıklığındaki hücrelere doğru yakın tondan uzak tona geçilir
+pub fn draw_clearance_field(field: &ClearanceField, saturation: f32, theme: &Theme) {
+    let frame = &field.frame;
+    let (near, far) = (theme.clearance_near, theme.clearance_far);
+    for row in 0..frame.rows {
+        for col in 0..frame.cols {
+            let t = (field.value(col, row) / saturation.max(f32::EPSILON)).clamp(0.0, 1.0);
+            let lerp = |a: f32, b: f32| a + (b - a) * t;
+            let color = Color::new(lerp(near.r, far.r), lerp(near.g, far.g), lerp(near.b, far.b), 0.35);
+            let x = frame.bounds.min_x + col as f32 * frame.cell_width;
+            let y = frame.bounds.min_y + row as f32 * frame.cell_height;
+            draw_rectangle(x, y, frame.cell_width, frame.cell_height, color);
+        }
+    }
+}
+
+// Ormanda her kökün alt ağacının rengi; daha çok kök varsa renkler baştan tekrarlanır
+pub const ROOT_COLORS: [Color; 6] = [SKYBLUE, ORANGE, LIME, VIOLET, GOLD, PINK];
+
+// Ağaç kenarlarının renklendirme biçimi; E tuşu sırayla değiştirir
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EdgeColoring {
+    Flat,
+    Depth,
+    Cost,
+    Root, // Kenar, çocuk düğümün büyüdüğü kökün rengindedir
+}
+
+impl EdgeColoring {
+    pub fn next(self) -> Self {
+        match self {
+            EdgeColoring::Flat => EdgeColoring::Depth,
+            EdgeColoring::Depth => EdgeColoring::Cost,
+            EdgeColoring::Cost => EdgeColoring::Root,
+            EdgeColoring::Root => EdgeColoring::Flat,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            EdgeColoring::Flat => "flat",
+            EdgeColoring::Depth => "depth",
+            EdgeColoring::Cost => "cost",
+            EdgeColoring::Root => "root",
+        }
+    }
+
+    // Çocuk düğümün derinliğine veya maliyetine göre kenar rengi; değerler ağacın o anki en
+    // büyük değerine göre normalleştirilir
+    pub fn color(self, style: &DrawStyle, rrt: &RRT, node: &Node) -> Color {
+        let t = match self {
+            EdgeColoring::Flat => return style.theme.edge,
+            EdgeColoring::Root => return ROOT_COLORS[node.root % ROOT_COLORS.len()],
+            EdgeColoring::Depth => node.depth as f32 / rrt.max_depth.max(1) as f32,
+            EdgeColoring::Cost => node.cost / rrt.max_cost.max(f32::EPSILON),
+        };
+        let t = t.clamp(0.0, 1.0);
+        // Kökten uzaklaştıkça kenar renginden uzak kenar rengine
+        let (from, to) = (style.theme.edge, style.theme.edge_far);
+        let lerp = |a: f32, b: f32| a + (b - a) * t;
+        Color::new(lerp(from.r, to.r), lerp(from.g, to.g), lerp(from.b, to.b), 1.0)
+    }
+}
+
+// Kutuyu pencerenin en-boy oranını koruyarak kenar payıyla birlikte ekrana sığdıran kamera.
+// Tek düğümlü ağaçta sonsuz yakınlaşmamak için kutu en az `min_extent` genişliğinde tutulur.
+pub fn fit_camera(bounds: Bounds, min_extent: f32) -> Camera2D {
+    fit_camera_in(bounds, min_extent, None)
+}
+
+// `fit_camera` ile aynıdır, ancak verilmişse kutu pencerenin yalnızca `viewport` dikdörtgenine
+// (ekran koordinatlarında) sığdırılır ve çizim o dikdörtgenle sınırlanır
+pub fn fit_camera_in(bounds: Bounds, min_extent: f32, viewport: Option<Rect>) -> Camera2D {
+    let area = viewport.unwrap_or(Rect::new(0.0, 0.0, screen_width(), screen_height()));
+    let center = vec2((bounds.min_x + bounds.max_x) / 2.0, (bounds.min_y + bounds.max_y) / 2.0);
+    let aspect = area.w / area.h;
+    let mut w = (bounds.max_x - bounds.min_x).max(min_extent) * 1.1;
+    let mut h = (bounds.max_y - bounds.min_y).max(min_extent) * 1.1;
+    if w / h > aspect {
+        h = w / aspect;
+    } else {
+        w = h * aspect;
+    }
+    let mut camera = Camera2D::from_display_rect(Rect::new(center.x - w / 2.0, center.y - h / 2.0, w, h));
+    // macroquad'ın görüntü alanı sol alt köşeden ölçülür
+    camera.viewport = viewport.map(|v| (v.x as i32, (screen_height() - v.y - v.h) as i32, v.w as i32, v.h as i32));
+    camera
+}
@@ -0,0 +1,301 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::observer::json_number;
+use crate::path::Path;
+use crate::rrt::TreeStatistics;
+use crate::scalar::Scalar;
+
+// Yolu `x,y` başlıklı CSV olarak yazar; her satır bir ara noktadır
+pub fn write_path_csv<W: Write>(path: &Path, mut w: W) -> io::Result<()> {
+    writeln!(w, "x,y")?;
+    for p in path.iter() {
+        writeln!(w, "{:.6},{:.6}", p.x, p.y)?;
+    }
+    w.flush()
+}
+
+// Yolu `x,y,s,heading` başlıklı CSV olarak yazar; her satır bir ara noktadır. `s` başlangıçtan yay
+// uzunluğu, `heading` ara noktadan çıkan parçanın yönüdür (radyan); son nokta gelen parçanın yönünü
+// taşır, tek noktalı yolda sıfırdır.
+pub fn write_path_profile_csv<W: Write>(path: &Path, mut w: W) -> io::Result<()> {
+    writeln!(w, "x,y,s,heading")?;
+    let headings = path.headings();
+    for (i, (p, s)) in path.iter().zip(path.cumulative_lengths()).enumerate() {
+        let heading = headings.get(i).or(headings.last()).copied().unwrap_or(0.0);
+        writeln!(w, "{:.6},{:.6},{:.6},{:.6}", p.x, p.y, s, heading)?;
+    }
+    w.flush()
+}
+
+// Ağaç istatistiklerini `statistic,bin,value` başlıklı uzun biçimli CSV olarak yazar: tek değerli
+// ölçümlerin `bin` sütunu boştur, `depth` satırlarında derinlik, `edge_length` satırlarında kutunun
+// alt sınırıdır.
+pub fn write_tree_statistics_csv<T: Scalar, W: Write>(stats: &TreeStatistics<T>, mut w: W) -> io::Result<()> {
+    writeln!(w, "statistic,bin,value")?;
+    let shape = &stats.shape;
+    writeln!(w, "nodes,,{}", stats.nodes)?;
+    writeln!(w, "leaves,,{}", shape.leaves)?;
+    writeln!(w, "max_depth,,{}", shape.max_depth)?;
+    writeln!(w, "mean_depth,,{:.6}", shape.mean_depth)?;
+    writeln!(w, "max_branching,,{}", shape.max_branching)?;
+    writeln!(w, "mean_branching,,{:.6}", shape.mean_branching)?;
+    writeln!(w, "min_edge_length,,{:.6}", stats.min_edge_length)?;
+    writeln!(w, "mean_edge_length,,{:.6}", stats.mean_edge_length)?;
+    writeln!(w, "max_edge_length,,{:.6}", stats.max_edge_length)?;
+    for (depth, count) in stats.depth_histogram.iter().enumerate() {
+        writeln!(w, "depth,{},{}", depth, count)?;
+    }
+    let width = stats.max_edge_length / T::cast(stats.edge_length_histogram.len().max(1));
+    for (bin, count) in stats.edge_length_histogram.iter().enumerate() {
+        writeln!(w, "edge_length,{:.6},{}", width * T::cast(bin), count)?;
+    }
+    w.flush()
+}
+
+// Ağaç istatistiklerini tek bir JSON nesnesi olarak yazar; histogramlar sayı dizileridir
+pub fn write_tree_statistics_json<T: Scalar, W: Write>(stats: &TreeStatistics<T>, mut w: W) -> io::Result<()> {
+    let list = |counts: &[usize]| counts.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+    let shape = &stats.shape;
+    writeln!(
+        w,
+        r#"{{"nodes":{},"leaves":{},"max_depth":{},"mean_depth":{},"max_branching":{},"mean_branching":{},"min_edge_length":{},"mean_edge_length":{},"max_edge_length":{},"depth_histogram":[{}],"edge_length_histogram":[{}]}}"#,
+        stats.nodes,
+        shape.leaves,
+        shape.max_depth,
+        json_number(shape.mean_depth),
+        shape.max_branching,
+        json_number(shape.mean_branching),
+        json_number(stats.min_edge_length),
+        json_number(stats.mean_edge_length),
+        json_number(stats.max_edge_length),
+        list(&stats.depth_histogram),
+        list(&stats.edge_length_histogram)
+    )?;
+    w.flush()
+}
+
+// nav_msgs/Path biçimli dışa aktarımın başlığı ve ölçeği
+#[derive(Clone, Debug, PartialEq)]
+pub struct RosPathOptions {
+    pub frame_id: String,
+    pub stamp: Duration, // Unix başlangıcından beri geçen süre; başlıkta `sec` ve `nanosec` olarak yazılır
+    pub scale: f32, // Dünya birimini (piksel) metreye çeviren çarpan
+}
+
+// geometry_msgs/Pose: konum `[x, y, z]`, yönelim `[x, y, z, w]` birim dördeyi
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RosPose {
+    pub position: [f32; 3],
+    pub orientation: [f32; 4],
+}
+
+// Yolun ara noktalarını ölçeklenmiş, düzlemdeki (z = 0) pozlara çevirir; yönelim, CSV'deki gibi
+// ara noktadan çıkan parçanın yönünün z ekseni etrafındaki dönüşüdür
+pub fn ros_poses(path: &Path, scale: f32) -> Vec<RosPose> {
+    let headings = path.headings();
+    path.iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let yaw = headings.get(i).or(headings.last()).copied().unwrap_or(0.0);
+            let (sin, cos) = (yaw / 2.0).sin_cos();
+            RosPose { position: [p.x * scale, p.y * scale, 0.0], orientation: [0.0, 0.0, sin, cos] }
+        })
+        .collect()
+}
+
+// JSON ve YAML çift tırnaklı metinleri için kaçışlı çerçeve adı
+fn quoted(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// Yolu nav_msgs/Path biçiminde tek bir JSON nesnesi olarak yazar; her poz yolun başlığını taşıyan
+// bir geometry_msgs/PoseStamped iletisidir
+pub fn write_path_ros_json<W: Write>(path: &Path, options: &RosPathOptions, mut w: W) -> io::Result<()> {
+    let header = format!(
+        r#"{{"stamp":{{"sec":{},"nanosec":{}}},"frame_id":{}}}"#,
+        options.stamp.as_secs(),
+        options.stamp.subsec_nanos(),
+        quoted(&options.frame_id)
+    );
+    let poses: Vec<String> = ros_poses(path, options.scale)
+        .iter()
+        .map(|pose| {
+            let ([x, y, z], [qx, qy, qz, qw]) = (pose.position, pose.orientation);
+            format!(
+                r#"{{"header":{},"pose":{{"position":{{"x":{},"y":{},"z":{}}},"orientation":{{"x":{},"y":{},"z":{},"w":{}}}}}}}"#,
+                header,
+                json_number(x),
+                json_number(y),
+                json_number(z),
+                json_number(qx),
+                json_number(qy),
+                json_number(qz),
+                json_number(qw)
+            )
+        })
+        .collect();
+    writeln!(w, r#"{{"header":{},"poses":[{}]}}"#, header, poses.join(","))?;
+    w.flush()
+}
+
+// std_msgs/Header bloğu; `first` ilk satırın, `indent` diğer satırların girintisidir
+fn write_yaml_header<W: Write>(w: &mut W, first: &str, indent: &str, options: &RosPathOptions) -> io::Result<()> {
+    writeln!(w, "{}header:", first)?;
+    writeln!(w, "{}  stamp:", indent)?;
+    writeln!(w, "{}    sec: {}", indent, options.stamp.as_secs())?;
+    writeln!(w, "{}    nanosec: {}", indent, options.stamp.subsec_nanos())?;
+    writeln!(w, "{}  frame_id: {}", indent, quoted(&options.frame_id))
+}
+
+// Yolu `ros2 topic echo` çıktısıyla aynı biçimde nav_msgs/Path YAML'ı olarak yazar
+pub fn write_path_ros_yaml<W: Write>(path: &Path, options: &RosPathOptions, mut w: W) -> io::Result<()> {
+    write_yaml_header(&mut w, "", "", options)?;
+    let poses = ros_poses(path, options.scale);
+    writeln!(w, "poses:{}", if poses.is_empty() { " []" } else { "" })?;
+    for pose in poses {
+        let ([x, y, z], [qx, qy, qz, qw]) = (pose.position, pose.orientation);
+        write_yaml_header(&mut w, "- ", "  ", options)?;
+        writeln!(w, "  pose:")?;
+        writeln!(w, "    position:")?;
+        writeln!(w, "      x: {:?}\n      y: {:?}\n      z: {:?}", x, y, z)?;
+        writeln!(w, "    orientation:")?;
+        writeln!(w, "      x: {:?}\n      y: {:?}\n      z: {:?}\n      w: {:?}", qx, qy, qz, qw)?;
+    }
+    w.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::obstacle::Obstacle;
+    use crate::rrt::{PlanResult, Point, EDGE_LENGTH_BINS, RRT};
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn path_csv_keeps_two_columns_and_the_profile_adds_arc_length_and_heading() {
+        let path = Path::new(vec![Point { x: 0.0, y: 0.0 }, Point { x: 3.0, y: 4.0 }, Point { x: 3.0, y: 10.0 }], None);
+        let mut csv = Vec::new();
+        write_path_csv(&path, &mut csv).unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "x,y\n0.000000,0.000000\n3.000000,4.000000\n3.000000,10.000000\n");
+
+        let mut profile = Vec::new();
+        write_path_profile_csv(&path, &mut profile).unwrap();
+        let profile = String::from_utf8(profile).unwrap();
+        let lines: Vec<&str> = profile.lines().collect();
+        assert_eq!(lines[0], "x,y,s,heading");
+        assert_eq!(lines[2], format!("3.000000,4.000000,5.000000,{:.6}", std::f32::consts::FRAC_PI_2));
+        // Son nokta gelen parçanın yönünü taşır
+        assert_eq!(lines[3], format!("3.000000,10.000000,11.000000,{:.6}", std::f32::consts::FRAC_PI_2));
+    }
+
+    #[test]
+    fn ros_path_export_orients_poses_along_segments_and_scales_positions() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 300.0 }, 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 160.0 }, radius: 60.0 });
+        rrt.reseed(2);
+        let PlanResult::Found { path, .. } = rrt.plan(20_000) else { panic!("no path") };
+        let scale = 0.05;
+        let poses = ros_poses(&path, scale);
+        assert_eq!(poses.len(), path.len());
+        let headings = path.headings();
+        for (i, pose) in poses.iter().enumerate() {
+            let [qx, qy, qz, qw] = pose.orientation;
+            assert_close(qx * qx + qy * qy + qz * qz + qw * qw, 1.0);
+            let yaw = (2.0 * (qw * qz + qx * qy)).atan2(1.0 - 2.0 * (qy * qy + qz * qz));
+            let heading = headings[i.min(headings.len() - 1)];
+            assert_close((yaw - heading).sin(), 0.0);
+            assert_close((yaw - heading).cos(), 1.0);
+            assert_eq!(pose.position, [path[i].x * scale, path[i].y * scale, 0.0]);
+        }
+        for pair in poses.windows(2) {
+            let (a, b) = (pair[0].position, pair[1].position);
+            assert!(((b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2)).sqrt() <= rrt.step_size() * scale + 1e-4);
+        }
+
+        let options = RosPathOptions { frame_id: "odom".to_string(), stamp: Duration::new(12, 500), scale };
+        let mut yaml = Vec::new();
+        write_path_ros_yaml(&path, &options, &mut yaml).unwrap();
+        let yaml = String::from_utf8(yaml).unwrap();
+        assert!(yaml.starts_with("header:\n  stamp:\n    sec: 12\n    nanosec: 500\n  frame_id: \"odom\"\nposes:\n- header:\n"));
+        // YAML'daki konumlar geri okununca pozlarla birebir aynıdır
+        let values = |key: &str| -> Vec<f32> { yaml.lines().filter_map(|l| l.strip_prefix(key)).map(|v| v.parse().unwrap()).collect() };
+        let (xs, ys) = (values("      x: "), values("      y: "));
+        assert_eq!(xs.len(), 2 * poses.len());
+        for (k, pose) in poses.iter().enumerate() {
+            assert_eq!([xs[2 * k], ys[2 * k]], [pose.position[0], pose.position[1]]);
+            assert_eq!([xs[2 * k + 1], ys[2 * k + 1]], [pose.orientation[0], pose.orientation[1]]);
+        }
+
+        let mut json = Vec::new();
+        write_path_ros_json(&path, &RosPathOptions { frame_id: "a\"b".to_string(), ..options }, &mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert!(json.starts_with(r#"{"header":{"stamp":{"sec":12,"nanosec":500},"frame_id":"a\"b"},"poses":[{"header":"#));
+        assert_eq!(json.matches(r#""orientation":{"x":0,"y":0,"z":"#).count(), poses.len());
+        assert!(json.ends_with("]}\n"));
+    }
+
+    #[test]
+    fn path_csv_parses_back_to_the_original_points() {
+        let points = vec![Point { x: 20.0, y: 20.0 }, Point { x: 31.41, y: -2.5 }, Point { x: 379.99997, y: 0.000123 }, Point { x: 123.456_79, y: 98.765_43 }];
+        let mut csv = Vec::new();
+        write_path_csv(&Path::new(points.clone(), None), &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("x,y"));
+        let parsed: Vec<Point> = lines
+            .map(|line| {
+                let (x, y) = line.split_once(',').expect("two columns");
+                Point { x: x.parse().unwrap(), y: y.parse().unwrap() }
+            })
+            .collect();
+        assert_eq!(parsed.len(), points.len());
+        for (a, b) in parsed.iter().zip(&points) {
+            assert!(a.distance(b) < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn tree_statistics_are_written_as_json_and_csv() {
+        // 0 ─ 1 ─ 3 ─ 4
+        //  └─ 2
+        // Kenar uzunlukları 10, 4, 5 ve 3
+        let p = |x: f32, y: f32| Point { x, y };
+        let mut rrt = RRT::new(p(200.0, 200.0), p(1e6, 1e6), 10.0, 1.0);
+        for (point, parent) in [(p(210.0, 200.0), 0), (p(200.0, 204.0), 0), (p(210.0, 205.0), 1), (p(210.0, 208.0), 3)] {
+            rrt.add_node(point, parent);
+        }
+        let stats = rrt.statistics();
+        let mut json = Vec::new();
+        write_tree_statistics_json(&stats, &mut json).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            concat!(
+                r#"{"nodes":5,"leaves":2,"max_depth":3,"mean_depth":1.4,"max_branching":2,"mean_branching":1.3333334,"#,
+                r#""min_edge_length":3,"mean_edge_length":5.5,"max_edge_length":10,"depth_histogram":[1,2,1,1],"#,
+                r#""edge_length_histogram":[0,0,0,1,1,1,0,0,0,1]}"#,
+                "\n"
+            )
+        );
+        let mut csv = Vec::new();
+        write_tree_statistics_csv(&stats, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 1 + 9 + 4 + EDGE_LENGTH_BINS);
+        assert_eq!(&lines[..3], ["statistic,bin,value", "nodes,,5", "leaves,,2"]);
+        assert!(lines.contains(&"depth,1,2") && lines.contains(&"edge_length,3.000000,1"));
+    }
+}
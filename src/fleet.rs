@@ -0,0 +1,209 @@
+// Aynı haritadaki birden çok robot için öncelikli planlama: robotlar sırayla planlanır, her robot
+// kendisinden önce planlananların zamanlanmış yollarını hareketli engel olarak görür. Robotlar yol
+// boyunca sabit hızla ilerler, hedefe varınca orada bekler; yol bulunamayan robot başlangıcında
+// bekler.
+use serde::{Deserialize, Serialize};
+
+use crate::config::RRTConfig;
+use crate::obstacle::point_segment_distance;
+use crate::rrt::{PlanResult, Point, RRT};
+use crate::scalar::Scalar;
+
+// Senaryo dosyasındaki `[[robots]]` tablosu: birincil robottan sonra planlanan ek robot
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct RobotSpec {
+    pub start: Point,
+    pub goal: Point,
+    pub radius: Option<f32>, // Verilmezse senaryonun `robot_radius` değeri kullanılır
+}
+
+// Zamanlanmış yol: `times[i]` robotun `points[i]` noktasına vardığı an; zamanlar azalmaz
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trajectory<T = f32> {
+    pub points: Vec<Point<T>>,
+    pub times: Vec<T>,
+}
+
+impl<T: Scalar> Trajectory<T> {
+    // Hiç kıpırdamadan `at` noktasında bekleyen robot
+    pub fn stationary(at: Point<T>) -> Self {
+        Trajectory { points: vec![at], times: vec![T::zero()] }
+    }
+
+    // Ara noktalardan `t = 0` anında yola çıkıp sabit `speed` hızıyla geçen robot
+    pub fn from_points(points: &[Point<T>], speed: T) -> Self {
+        let mut times = Vec::with_capacity(points.len());
+        let mut time = T::zero();
+        for (i, p) in points.iter().enumerate() {
+            if i > 0 {
+                time += points[i - 1].distance(p) / speed;
+            }
+            times.push(time);
+        }
+        Trajectory { points: points.to_vec(), times }
+    }
+
+    // Ağaçta kökten `node` düğümüne giden zincir, düğümlerin varış zamanlarıyla; planlayıcının
+    // hareketli engellere karşı kontrol ettiği zamanlama tam olarak budur
+    pub fn from_tree(rrt: &RRT<T>, node: usize) -> Self {
+        let mut chain = vec![node];
+        let mut current = node;
+        while let Some(parent) = rrt.node(current).parent {
+            chain.push(parent);
+            current = parent;
+        }
+        chain.reverse();
+        Trajectory {
+            points: chain.iter().map(|&i| rrt.node(i).point).collect(),
+            times: chain.iter().map(|&i| rrt.node(i).time).collect(),
+        }
+    }
+
+    // Hedefe varış anı
+    pub fn duration(&self) -> T {
+        self.times.last().copied().unwrap_or_else(T::zero)
+    }
+
+    // Robotun `t` anındaki konumu; yola çıkmadan önce ilk, varıştan sonra son noktadadır
+    pub fn position_at(&self, t: T) -> Point<T> {
+        let i = self.times.partition_point(|&time| time <= t);
+        if i == 0 {
+            return self.points[0];
+        }
+        if i == self.points.len() {
+            return self.points[i - 1];
+        }
+        let (t0, t1) = (self.times[i - 1], self.times[i]);
+        Point::lerp(self.points[i - 1], self.points[i], (t - t0) / (t1 - t0))
+    }
+
+    // `t` anından itibaren robotun geçtiği noktaların (varıştan sonra beklediği nokta dahil) `p`
+    // noktasına en kısa uzaklığı
+    pub fn distance_after(&self, p: &Point<T>, t: T) -> T {
+        let i = self.times.partition_point(|&time| time <= t);
+        let mut from = self.position_at(t);
+        let mut best = from.distance(p);
+        for next in &self.points[i.min(self.points.len())..] {
+            best = best.min(point_segment_distance(p, &from, next));
+            from = *next;
+        }
+        best
+    }
+}
+
+// Daha önce planlanmış, zamanlanmış yolu boyunca ilerleyen disk biçimli robot
+#[derive(Clone, Debug, PartialEq)]
+pub struct MovingRobot<T = f32> {
+    pub trajectory: Trajectory<T>,
+    pub radius: T,
+}
+
+// İki robotun gövdelerinin ilk değdiği an: `0` ile iki varış anının büyüğü arasında `dt`
+// aralıklarla örneklenen anlarda merkezler arası uzaklık yarıçapların toplamından küçükse çakışma
+// vardır. Varıştan sonra robotlar hedeflerinde beklediğinden son örnek anı yeterlidir.
+pub fn first_conflict<T: Scalar>(a: &MovingRobot<T>, b: &MovingRobot<T>, dt: T) -> Option<T> {
+    let end = a.trajectory.duration().max(b.trajectory.duration());
+    let steps = (end / dt).ceil().max(T::one()).to_usize().unwrap_or(1);
+    (0..=steps).map(|i| (T::cast(i) * dt).min(end)).find(|&t| {
+        a.trajectory.position_at(t).distance(&b.trajectory.position_at(t)) < a.radius + b.radius
+    })
+}
+
+// Her robot çifti için ilk çakışma anı: `(i, j, t)` ile `i < j`
+pub fn fleet_conflicts<T: Scalar>(robots: &[MovingRobot<T>], dt: T) -> Vec<(usize, usize, T)> {
+    let mut conflicts = Vec::new();
+    for i in 0..robots.len() {
+        for j in i + 1..robots.len() {
+            if let Some(t) = first_conflict(&robots[i], &robots[j], dt) {
+                conflicts.push((i, j, t));
+            }
+        }
+    }
+    conflicts
+}
+
+// Planlayıcıları sırayla planlar; her planlayıcının `moving_robots` listesi kendisinden önceki
+// robotlarla değiştirilir. Yol bulunamayan robot `None` döner ve sonrakiler için başlangıcında
+// bekleyen bir engel olur.
+pub fn plan_prioritized<T: Scalar>(planners: &mut [RRT<T>], max_iters: usize) -> Vec<Option<Trajectory<T>>> {
+    let mut planned: Vec<MovingRobot<T>> = Vec::with_capacity(planners.len());
+    let mut trajectories = Vec::with_capacity(planners.len());
+    for rrt in planners.iter_mut() {
+        rrt.moving_robots = planned.clone();
+        let trajectory = match rrt.plan(max_iters) {
+            PlanResult::Found { path, .. } => path.goal_node().map(|node| Trajectory::from_tree(rrt, node)),
+            PlanResult::NotFound { .. } => None,
+        };
+        let motion = trajectory.clone().unwrap_or_else(|| Trajectory::stationary(rrt.starts[0]));
+        planned.push(MovingRobot { trajectory: motion, radius: rrt.robot_radius() });
+        trajectories.push(trajectory);
+    }
+    trajectories
+}
+
+// Birincil robot ve `robots` listesindeki ek robotlar için planlayıcılar; ek robotlar senaryonun
+// haritasını ve parametrelerini kendi uç noktaları ve yarıçaplarıyla kullanır
+pub fn fleet_from_config(config: &RRTConfig) -> Vec<RRT> {
+    let primary = RRT::from_config(config);
+    let mut planners = Vec::with_capacity(config.robots.len() + 1);
+    for spec in &config.robots {
+        let mut rrt = RRT::from_config(config);
+        rrt.bounds = primary.bounds;
+        rrt.obstacles = primary.obstacles.clone();
+        rrt.set_robot_radius(spec.radius.unwrap_or(config.robot_radius));
+        rrt.set_goals(vec![spec.goal]);
+        rrt.set_starts(vec![spec.start]);
+        planners.push(rrt);
+    }
+    planners.insert(0, primary);
+    planners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn trajectories_wait_at_both_ends_and_interpolate_between_waypoints() {
+        let points = [Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 0.0 }, Point { x: 100.0, y: 50.0 }];
+        let trajectory = Trajectory::from_points(&points, 50.0);
+        assert_close(trajectory.duration(), 3.0);
+        assert_eq!(trajectory.position_at(-1.0), points[0]);
+        assert_close(trajectory.position_at(1.0).x, 50.0);
+        assert_close(trajectory.position_at(2.5).y, 25.0);
+        assert_eq!(trajectory.position_at(10.0), points[2]);
+        // Hedefte beklerken geçilen yol artık uzaklığa katılmaz
+        assert_close(trajectory.distance_after(&Point { x: 0.0, y: 0.0 }, 2.0), 100.0);
+    }
+
+    #[test]
+    fn prioritized_robots_never_touch_at_the_same_time() {
+        let config = RRTConfig::from_toml_file("scenarios/crossing.toml").expect("scenario loads");
+        let robots = |planners: &[RRT], trajectories: Vec<Option<Trajectory>>| -> Vec<MovingRobot> {
+            planners
+                .iter()
+                .zip(trajectories)
+                .map(|(rrt, trajectory)| MovingRobot { trajectory: trajectory.expect("robot finds a path"), radius: rrt.robot_radius() })
+                .collect()
+        };
+
+        // Birbirini görmeyen robotlar ortada aynı anda bulunur
+        let mut independent = fleet_from_config(&config);
+        let alone: Vec<_> = independent.iter_mut().flat_map(|rrt| plan_prioritized(std::slice::from_mut(rrt), config.max_iters)).collect();
+        assert!(!fleet_conflicts(&robots(&independent, alone), 0.01).is_empty());
+
+        let mut planners = fleet_from_config(&config);
+        let trajectories = plan_prioritized(&mut planners, config.max_iters);
+        assert!(planners[1].moving_robots.len() == 1 && planners[0].moving_robots.is_empty());
+        let fleet = robots(&planners, trajectories);
+        assert_eq!(fleet_conflicts(&fleet, 0.01), Vec::new());
+        for (rrt, robot) in planners.iter().zip(&fleet) {
+            assert_eq!(robot.trajectory.points[0], rrt.starts[0]);
+            assert!(robot.trajectory.points.last().unwrap().distance(&rrt.goal()) < rrt.goal_threshold());
+        }
+    }
+}
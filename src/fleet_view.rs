@@ -0,0 +1,122 @@
+// Çok robotlu mod: senaryonun `[[robots]]` tablosundaki robotlar birincil robottan sonra öncelik
+// sırasıyla planlanır ve hepsi aynı saatle birlikte canlandırılır. Sekme tuşu robotların birbirini
+// görmeden bağımsız planlandığı sürüme geçer; iki robotun gövdeleri değdiği an ikisi de kırmızı
+// çizilir ve çakışmalar ekranda listelenir. Boşluk canlandırmayı duraklatır, R baştan oynatır.
+use macroquad::prelude::*;
+use rrt_visualization::{fleet_conflicts, fleet_from_config, plan_prioritized, MovingRobot, RRTConfig, Trajectory};
+
+use crate::draw::{fit_camera, DrawStyle};
+
+// Çakışma aramasında ardışık örnek anları arasındaki süre (saniye)
+const CONFLICT_DT: f32 = 0.01;
+
+// Robotların yol ve gövde renkleri; daha çok robot varsa renkler baştan tekrarlanır
+const ROBOT_COLORS: [Color; 6] = [SKYBLUE, ORANGE, LIME, VIOLET, GOLD, PINK];
+
+// Bir planlama sonucunun canlandırılan robotları ve çakışmaları
+struct Plan {
+    robots: Vec<MovingRobot>,
+    found: Vec<bool>, // Yol bulunamayan robot başlangıcında bekler
+    conflicts: Vec<(usize, usize, f32)>,
+}
+
+impl Plan {
+    fn new(config: &RRTConfig, prioritized: bool) -> Self {
+        let mut planners = fleet_from_config(config);
+        let trajectories = if prioritized {
+            plan_prioritized(&mut planners, config.max_iters)
+        } else {
+            // Her robot diğerlerini görmeden kendi başına planlanır
+            planners.iter_mut().flat_map(|rrt| plan_prioritized(std::slice::from_mut(rrt), config.max_iters)).collect()
+        };
+        let robots: Vec<MovingRobot> = planners
+            .iter()
+            .zip(&trajectories)
+            .map(|(rrt, trajectory)| MovingRobot {
+                trajectory: trajectory.clone().unwrap_or_else(|| Trajectory::stationary(rrt.starts[0])),
+                radius: rrt.robot_radius(),
+            })
+            .collect();
+        let conflicts = fleet_conflicts(&robots, CONFLICT_DT);
+        Plan { found: trajectories.iter().map(Option::is_some).collect(), robots, conflicts }
+    }
+
+    fn duration(&self) -> f32 {
+        self.robots.iter().map(|r| r.trajectory.duration()).fold(0.0, f32::max)
+    }
+}
+
+pub async fn run_fleet_window(config: RRTConfig) {
+    let plans = [Plan::new(&config, true), Plan::new(&config, false)];
+    let obstacles = rrt_visualization::RRT::from_config(&config).obstacles;
+    let style = DrawStyle::from_config(&config);
+    let camera = fit_camera(config.bounds, config.step_size * 4.0);
+    let mut shown = 0;
+    let mut time = 0.0;
+    let mut paused = false;
+
+    loop {
+        if is_quit_requested() || is_key_pressed(KeyCode::Escape) {
+            break;
+        }
+        if is_key_pressed(KeyCode::Tab) {
+            shown = 1 - shown;
+            time = 0.0;
+        }
+        if is_key_pressed(KeyCode::R) {
+            time = 0.0;
+        }
+        if is_key_pressed(KeyCode::Space) {
+            paused = !paused;
+        }
+        let plan = &plans[shown];
+        if !paused {
+            // Son robot vardıktan kısa bir süre sonra canlandırma baştan başlar
+            time += get_frame_time();
+            if time > plan.duration() + 1.0 {
+                time = 0.0;
+            }
+        }
+
+        clear_background(style.theme.background);
+        set_camera(&camera);
+        for obstacle in &obstacles {
+            style.draw_obstacle(obstacle, config.robot_radius);
+        }
+        let positions: Vec<_> = plan.robots.iter().map(|r| r.trajectory.position_at(time)).collect();
+        for (i, robot) in plan.robots.iter().enumerate() {
+            let color = ROBOT_COLORS[i % ROBOT_COLORS.len()];
+            style.draw_path(&robot.trajectory.points, color);
+            let goal = robot.trajectory.points[robot.trajectory.points.len() - 1];
+            draw_circle_lines(goal.x, goal.y, style.node_radius, 1.0, color);
+        }
+        for (i, robot) in plan.robots.iter().enumerate() {
+            let touching = plan.robots.iter().enumerate().any(|(j, other)| {
+                j != i && positions[i].distance(&positions[j]) < robot.radius + other.radius
+            });
+            let color = if touching { RED } else { ROBOT_COLORS[i % ROBOT_COLORS.len()] };
+            draw_circle(positions[i].x, positions[i].y, robot.radius.max(style.node_radius), color);
+        }
+
+        set_default_camera();
+        let mode = if shown == 0 { "prioritized (Tab: independent)" } else { "independent (Tab: prioritized)" };
+        let mut lines = vec![format!("{}  t = {:.2} s", mode, time)];
+        for (i, found) in plan.found.iter().enumerate() {
+            if !found {
+                lines.push(format!("robot {}: no path, waiting at start", i));
+            }
+        }
+        if plan.conflicts.is_empty() {
+            lines.push("no conflicts".to_string());
+        }
+        for &(i, j, t) in &plan.conflicts {
+            lines.push(format!("conflict: robots {} and {} at t = {:.2} s", i, j, t));
+        }
+        for (k, line) in lines.iter().enumerate() {
+            let color = if line.starts_with("conflict") { RED } else { style.theme.text };
+            draw_text(line, 10.0, 20.0 + 18.0 * k as f32, 18.0, color);
+        }
+
+        next_frame().await;
+    }
+}
@@ -0,0 +1,50 @@
+// Hedef bölgeleri: ağacın ulaşması gereken alanın biçimi
+use rand::{Rng, RngCore};
+
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+// Bir noktanın hedefe ulaşmış sayılacağı bölge
+pub trait GoalRegion<T: Scalar = f32>: Send + Sync {
+    fn contains(&self, p: &Point<T>) -> bool;
+
+    // Hedef yanlı örneklemede bölgeden çekilen nokta
+    fn sample(&mut self, rng: &mut dyn RngCore) -> Point<T>;
+}
+
+// Merkezine `radius` uzaklıktan yakın noktaları kabul eden dairesel bölge; `goal_threshold`
+// eşiğinin davranışıyla aynıdır
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CircleGoal<T = f32> {
+    pub center: Point<T>,
+    pub radius: T,
+}
+
+impl<T: Scalar> GoalRegion<T> for CircleGoal<T> {
+    fn contains(&self, p: &Point<T>) -> bool {
+        p.distance(&self.center) < self.radius
+    }
+
+    // Hedef yanlılığı her zaman merkezi örnekler; rastgele sayı çekilmez
+    fn sample(&mut self, _rng: &mut dyn RngCore) -> Point<T> {
+        self.center
+    }
+}
+
+// Kenarları eksenlere paralel dikdörtgen bölge (ör. bir yanaşma alanı); kenarlar dahildir
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RectGoal<T = f32> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Scalar> GoalRegion<T> for RectGoal<T> {
+    fn contains(&self, p: &Point<T>) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x && p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    // Dikdörtgen içinde düzgün dağılımlı nokta
+    fn sample(&mut self, rng: &mut dyn RngCore) -> Point<T> {
+        Point { x: rng.gen_range(self.min.x..=self.max.x), y: rng.gen_range(self.min.y..=self.max.y) }
+    }
+}
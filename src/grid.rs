@@ -0,0 +1,187 @@
+use crate::obstacle::{Bounds, CollisionChecker};
+use crate::path::Path;
+use crate::planner::Planner;
+use crate::rrt::{PlanResult, PlanStats, Point, RRT};
+use crate::scalar::Scalar;
+use crate::search::astar;
+
+// Alan ile satır satır numaralanan hücreler arasındaki dönüşüm. Izgara A*'ın doluluk ızgarası ve
+// arazi maliyet haritası aynı dönüşümü kullanır; böylece bir noktanın çarpışma ve maliyet
+// hücreleri aynı kuralla bulunur. Hücreler alanın küçük köşesinden başlar; son satır ve sütun
+// alanın dışına taşabilir.
+#[derive(Clone, Copy, Debug)]
+pub struct CellFrame<T = f32> {
+    pub bounds: Bounds<T>,
+    pub cols: usize,
+    pub rows: usize,
+    pub cell_width: T,
+    pub cell_height: T,
+}
+
+impl<T: Scalar> CellFrame<T> {
+    // Alanı `cell_size` kenarlı kare hücrelerle örter
+    pub fn square(bounds: Bounds<T>, cell_size: T) -> Self {
+        let count = |extent: T| (extent / cell_size).ceil().max(T::one()).to_usize().unwrap_or(1);
+        let (cols, rows) = (count(bounds.max_x - bounds.min_x), count(bounds.max_y - bounds.min_y));
+        CellFrame { bounds, cols, rows, cell_width: cell_size, cell_height: cell_size }
+    }
+
+    // Alanı tam olarak `cols` x `rows` hücreye böler
+    pub fn fitted(bounds: Bounds<T>, cols: usize, rows: usize) -> Self {
+        let (cols, rows) = (cols.max(1), rows.max(1));
+        CellFrame {
+            bounds,
+            cols,
+            rows,
+            cell_width: (bounds.max_x - bounds.min_x) / T::cast(cols),
+            cell_height: (bounds.max_y - bounds.min_y) / T::cast(rows),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cols * self.rows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Hücrenin merkezi; alanın dışına taşan hücrelerde alan sınırına kırpılır
+    pub fn cell_center(&self, col: usize, row: usize) -> Point<T> {
+        let half = T::cast(0.5);
+        Point {
+            x: (self.bounds.min_x + (T::cast(col) + half) * self.cell_width).min(self.bounds.max_x),
+            y: (self.bounds.min_y + (T::cast(row) + half) * self.cell_height).min(self.bounds.max_y),
+        }
+    }
+
+    // Noktanın bulunduğu hücre; alan dışındaysa `None`
+    pub fn cell_of(&self, p: &Point<T>) -> Option<(usize, usize)> {
+        if !self.bounds.contains(p) {
+            return None;
+        }
+        let col = ((p.x - self.bounds.min_x) / self.cell_width).to_usize().unwrap_or(0).min(self.cols - 1);
+        let row = ((p.y - self.bounds.min_y) / self.cell_height).to_usize().unwrap_or(0).min(self.rows - 1);
+        Some((col, row))
+    }
+}
+
+// Engellerin ızgaraya dökülmüş hali üzerinde A* ile çalışan referans planlayıcı. Hücreler
+// merkezleri planlayıcının çarpışma denetleyicisine göre dolu ise kapalı sayılır, böylece
+// karşılaştırma RRT ile aynı çarpışma anlamını kullanır.
+pub struct GridAstar {
+    pub frame: CellFrame,
+    pub blocked: Vec<bool>, // Satır satır hücre doluluğu
+    pub start: Point,
+    pub goal: Point,
+    pub path: Option<Vec<Point>>, // Son planlamada bulunan yol
+}
+
+impl GridAstar {
+    pub fn new(checker: CollisionChecker<'_>, cell_size: f32, start: Point, goal: Point) -> Self {
+        let frame = CellFrame::square(*checker.bounds, cell_size);
+        let (cols, rows) = (frame.cols, frame.rows);
+        let mut grid = GridAstar {
+            frame,
+            blocked: vec![false; cols * rows],
+            start,
+            goal,
+            path: None,
+        };
+        for row in 0..rows {
+            for col in 0..cols {
+                let center = grid.frame.cell_center(col, row);
+                grid.blocked[row * cols + col] = !checker.is_point_free(&center);
+            }
+        }
+        grid
+    }
+
+    // Bir RRT'nin haritası, başlangıcı ve hedefi için ızgara oluşturur
+    pub fn from_rrt(rrt: &RRT, cell_size: f32) -> Self {
+        GridAstar::new(rrt.checker(), cell_size, rrt.starts[0], rrt.goal())
+    }
+
+    fn is_blocked(&self, col: i64, row: i64) -> bool {
+        let CellFrame { cols, rows, .. } = self.frame;
+        col < 0 || row < 0 || col >= cols as i64 || row >= rows as i64 || self.blocked[row as usize * cols + col as usize]
+    }
+
+    // 8 komşulu A*; çapraz hareket yalnızca iki yandaki hücre de boşsa izinlidir (köşe kesilmez)
+    pub fn search(&self, start: &Point, goal: &Point) -> Option<Vec<Point>> {
+        let frame = &self.frame;
+        let (sc, sr) = frame.cell_of(start)?;
+        let (gc, gr) = frame.cell_of(goal)?;
+        let index = |c: usize, r: usize| r * frame.cols + c;
+        if self.blocked[index(sc, sr)] || self.blocked[index(gc, gr)] {
+            return None;
+        }
+        let cells = astar(
+            frame.len(),
+            index(sc, sr),
+            index(gc, gr),
+            |i, out| {
+                let (c, r) = ((i % frame.cols) as i64, (i / frame.cols) as i64);
+                for dr in -1..=1i64 {
+                    for dc in -1..=1i64 {
+                        if (dc == 0 && dr == 0) || self.is_blocked(c + dc, r + dr) {
+                            continue;
+                        }
+                        if dc != 0 && dr != 0 && (self.is_blocked(c + dc, r) || self.is_blocked(c, r + dr)) {
+                            continue;
+                        }
+                        let step = if dc != 0 && dr != 0 { std::f32::consts::SQRT_2 } else { 1.0 };
+                        out.push(((r + dr) as usize * frame.cols + (c + dc) as usize, step * frame.cell_width));
+                    }
+                }
+            },
+            |i| frame.cell_center(i % frame.cols, i / frame.cols).distance(goal),
+        )?;
+
+        // Yol gerçek başlangıç ve hedef noktalarıyla uçlanır
+        let mut path = vec![*start];
+        let inner = cells.len().saturating_sub(1);
+        path.extend(
+            cells
+                .iter()
+                .take(inner)
+                .skip(1)
+                .map(|&i| frame.cell_center(i % frame.cols, i / frame.cols)),
+        );
+        path.push(*goal);
+        Some(path)
+    }
+}
+
+impl Planner for GridAstar {
+    // Izgara araması tek seferde tamamlanır; `max_iters` kullanılmaz
+    fn plan(&mut self, _max_iters: usize) -> PlanResult {
+        self.path = self.search(&self.start, &self.goal);
+        let stats = PlanStats {
+            iterations: 1,
+            nodes: self.path.as_ref().map_or(0, Vec::len),
+            budget_exhausted: false,
+            best_goal_distance: if self.path.is_some() { 0.0 } else { f32::INFINITY },
+            best_goal_iteration: 1,
+            samples_total: 0,
+            samples_rejected: 0,
+        };
+        match &self.path {
+            Some(path) => PlanResult::Found { path: Path::from(path.clone()), stats },
+            None => PlanResult::NotFound { stats },
+        }
+    }
+
+    // Izgara bir ağaç kurmadığından çizilecek kenar yoktur; yol `path` üzerinden çizilir
+    fn edges(&self) -> Vec<(Point, Point)> {
+        Vec::new()
+    }
+
+    fn start(&self) -> Point {
+        self.start
+    }
+
+    fn goal(&self) -> Point {
+        self.goal
+    }
+}
@@ -0,0 +1,49 @@
+use crate::obstacle::Bounds;
+use crate::rrt::Point;
+
+// Örneklerin çalışma alanı üzerindeki dağılımını kaba bir ızgarada sayan yoğunluk haritası.
+// Örnekleyicinin çabasını nereye harcadığını göstermek için kullanılır.
+#[derive(Clone, Debug)]
+pub struct SampleHeatmap {
+    pub bounds: Bounds,
+    pub cols: usize,
+    pub rows: usize,
+    pub counts: Vec<u32>, // Satır satır hücre başına örnek sayısı
+    pub max_count: u32, // En kalabalık hücrenin sayısı; renk ölçeği buna göre ayarlanır
+}
+
+impl SampleHeatmap {
+    pub fn new(bounds: Bounds, cols: usize, rows: usize) -> Self {
+        let (cols, rows) = (cols.max(1), rows.max(1));
+        SampleHeatmap { bounds, cols, rows, counts: vec![0; cols * rows], max_count: 0 }
+    }
+
+    pub fn cell_width(&self) -> f32 {
+        (self.bounds.max_x - self.bounds.min_x) / self.cols as f32
+    }
+
+    pub fn cell_height(&self) -> f32 {
+        (self.bounds.max_y - self.bounds.min_y) / self.rows as f32
+    }
+
+    // Örneği bulunduğu hücreye sayar; alan dışındaki örnekler yok sayılır
+    pub fn record(&mut self, p: &Point) {
+        if !self.bounds.contains(p) {
+            return;
+        }
+        let col = (((p.x - self.bounds.min_x) / self.cell_width()) as usize).min(self.cols - 1);
+        let row = (((p.y - self.bounds.min_y) / self.cell_height()) as usize).min(self.rows - 1);
+        let count = &mut self.counts[row * self.cols + col];
+        *count += 1;
+        self.max_count = self.max_count.max(*count);
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.iter().map(|&c| u64::from(c)).sum()
+    }
+
+    pub fn clear(&mut self) {
+        self.counts.iter_mut().for_each(|c| *c = 0);
+        self.max_count = 0;
+    }
+}
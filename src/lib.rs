@@ -0,0 +1,51 @@
+pub mod batch;
+pub mod car;
+pub mod clearance;
+pub mod clock;
+pub mod config;
+pub mod coverage;
+pub mod export;
+pub mod fleet;
+pub mod goal;
+pub mod grid;
+pub mod heatmap;
+pub mod mapgen;
+pub mod obstacle;
+pub mod observer;
+pub mod path;
+pub mod planner;
+pub mod prm;
+pub mod recorder;
+pub mod reeds_shepp;
+pub mod region;
+pub mod rrt;
+pub mod scalar;
+pub mod search;
+pub mod space3d;
+pub mod spatial;
+pub mod state;
+pub mod terrain;
+
+pub use car::{car_from_config, CarNode, CarSpec, CarTree};
+pub use clearance::ClearanceField;
+pub use clock::{Clock, SystemClock};
+pub use config::{BiasSchedule, ClearanceCost, ConfigError, ForestGrowth, MapLayout, NeighborIndexKind, NodeCapPolicy, PlannerKind, PotentialField, RRTConfig, SamplerKind, StepPolicy, ThemeKind};
+pub use coverage::CoverageGrid;
+pub use export::{ros_poses, write_path_csv, write_path_profile_csv, write_path_ros_json, write_path_ros_yaml, write_tree_statistics_csv, write_tree_statistics_json, RosPathOptions, RosPose};
+pub use fleet::{first_conflict, fleet_conflicts, fleet_from_config, plan_prioritized, MovingRobot, RobotSpec, Trajectory};
+pub use goal::{CircleGoal, GoalRegion, RectGoal};
+pub use grid::{CellFrame, GridAstar};
+pub use heatmap::SampleHeatmap;
+pub use obstacle::{Bounds, CollisionChecker, DynamicObstacle, Obstacle, RobotShape};
+pub use observer::{JsonLinesObserver, PlannerObserver, RejectReason};
+pub use path::{Path, PathError, Pose};
+pub use planner::Planner;
+pub use prm::Prm;
+pub use reeds_shepp::{Gear, ReedsSheppPath, Segment, Steering};
+pub use region::{CircleRegion, RectRegion, SamplingRegion};
+pub use scalar::Scalar;
+pub use space3d::{Bounds3, CollisionChecker3, Obstacle3, Point3, Scenario3};
+pub use spatial::{KdTree, SpatialGrid};
+pub use state::{State, StateNode, StateTree};
+pub use terrain::{CostMap, TerrainSpec};
+pub use rrt::{path_clearance, path_length, segment_clearances, Extension, FreeSpaceError, LastStepInfo, LazyStats, Node, PlanError, PlanResult, PlanStats, Point, RootReport, Run, StepResult, TreeStatistics, TreeStats, EDGE_LENGTH_BINS, RRT};
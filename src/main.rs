@@ -1,178 +1,1227 @@
+mod capture;
+mod car_view;
+mod compare;
+mod draw;
+mod fleet_view;
+mod panel;
+
+use capture::{FrameCapture, GifOptions};
+use compare::run_compare_window;
+use draw::{draw_clearance_field, draw_cost_map, draw_coverage, draw_heatmap, draw_node_tooltip, fit_camera, DrawStyle, EdgeColoring, ROOT_COLORS};
+use car_view::run_car_window;
+use fleet_view::run_fleet_window;
 use macroquad::prelude::*;
-use ::rand::Rng;
-use ::rand::rngs::ThreadRng;
+use panel::{ControlPanel, PanelAction};
+use rrt_visualization::batch::{run_trials, run_trials_parallel, write_trials_csv, BatchSummary};
+use rrt_visualization::mapgen::{maze, random_clutter};
+use rrt_visualization::recorder::{Event, RecordedSampler, Recorder, Replayer};
+use rrt_visualization::{
+    path_clearance, path_length, write_path_csv, write_path_ros_yaml, write_tree_statistics_json, CellFrame, ClearanceField, Clock, GridAstar, JsonLinesObserver, MapLayout, NeighborIndexKind, Obstacle, Path, Planner, PlannerKind,
+    Point, Prm, RRTConfig, RobotShape, RosPathOptions, SampleHeatmap, StepResult, ThemeKind, RRT,
+};
+use std::fs::File;
+use std::path::PathBuf;
+use std::process;
 
-// İki boyutlu bir noktayı temsil eden yapı
-#[derive(Clone, Copy)]
-struct Point {
-    x: f32,
-    y: f32,
+// Komut satırı argümanları; verilen her bayrak yapılandırma dosyasındaki değeri ezer
+#[derive(Default)]
+struct CliArgs {
+    config: Option<String>,
+    seed: Option<u64>,
+    step_size: Option<f32>,
+    goal_threshold: Option<f32>,
+    goal_bias: Option<f32>,
+    robot_radius: Option<f32>,
+    clearance_weight: Option<f32>,
+    record: Option<String>,
+    replay: Option<String>,
+    replay_speed: Option<f32>,
+    event_log: Option<String>,
+    compare: Option<String>,
+    capture: Option<String>,
+    capture_every: Option<usize>,
+    capture_gif: Option<String>,
+    record_gif: Option<String>,
+    gif_every: Option<usize>,
+    gif_fps: Option<f32>,
+    gif_width: Option<u32>,
+    max_iters: Option<usize>,
+    max_nodes: Option<usize>,
+    max_path_cost: Option<f32>,
+    time_budget_ms: Option<u64>,
+    batch_size: Option<usize>,
+    iterations_per_frame: Option<usize>,
+    trials: Option<u64>,
+    trials_csv: Option<String>,
+    parallel_trials: bool,
+    layout: Option<String>,
+    planner: Option<PlannerKind>,
+    theme: Option<ThemeKind>,
+    neighbor_index: Option<NeighborIndexKind>,
+    compare_astar: bool,
+    any_angle: bool,
+    cross_edges: bool,
+    lazy_collision: bool,
+    dynamic_domain: bool,
+    rejection_sampling: bool,
 }
 
-impl Point {
-    // İki nokta arasındaki öklid mesafesini hesaplayan fonksiyon
-    fn distance(&self, other: &Point) -> f32 {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+impl CliArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<CliArgs, String> {
+        let mut cli = CliArgs::default();
+        while let Some(flag) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("missing value for {}", flag));
+            match flag.as_str() {
+                "--config" => cli.config = Some(value()?),
+                "--seed" => cli.seed = Some(parse_value(&flag, &value()?)?),
+                "--step-size" => cli.step_size = Some(parse_value(&flag, &value()?)?),
+                "--goal-threshold" => cli.goal_threshold = Some(parse_value(&flag, &value()?)?),
+                "--goal-bias" => cli.goal_bias = Some(parse_value(&flag, &value()?)?),
+                "--robot-radius" => cli.robot_radius = Some(parse_value(&flag, &value()?)?),
+                "--clearance-weight" => cli.clearance_weight = Some(parse_value(&flag, &value()?)?),
+                "--record" => cli.record = Some(value()?),
+                "--replay" => cli.replay = Some(value()?),
+                "--replay-speed" => cli.replay_speed = Some(parse_value(&flag, &value()?)?),
+                "--event-log" => cli.event_log = Some(value()?),
+                "--compare" => cli.compare = Some(value()?),
+                "--capture" => cli.capture = Some(value()?),
+                "--capture-every" => cli.capture_every = Some(parse_value(&flag, &value()?)?),
+                "--capture-gif" => cli.capture_gif = Some(value()?),
+                "--record-gif" => cli.record_gif = Some(value()?),
+                "--gif-every" => cli.gif_every = Some(parse_value(&flag, &value()?)?),
+                "--gif-fps" => cli.gif_fps = Some(parse_value(&flag, &value()?)?),
+                "--gif-width" => cli.gif_width = Some(parse_value(&flag, &value()?)?),
+                "--max-iters" => cli.max_iters = Some(parse_value(&flag, &value()?)?),
+                "--max-nodes" => cli.max_nodes = Some(parse_value(&flag, &value()?)?),
+                "--max-path-cost" => cli.max_path_cost = Some(parse_value(&flag, &value()?)?),
+                "--time-budget-ms" => cli.time_budget_ms = Some(parse_value(&flag, &value()?)?),
+                "--batch-size" => cli.batch_size = Some(parse_value(&flag, &value()?)?),
+                "--iterations-per-frame" => cli.iterations_per_frame = Some(parse_value(&flag, &value()?)?),
+                "--trials" => cli.trials = Some(parse_value(&flag, &value()?)?),
+                "--trials-csv" => cli.trials_csv = Some(value()?),
+                "--parallel-trials" => cli.parallel_trials = true,
+                "--layout" => cli.layout = Some(value()?),
+                "--compare-astar" => cli.compare_astar = true,
+                "--any-angle" => cli.any_angle = true,
+                "--cross-edges" => cli.cross_edges = true,
+                "--lazy" => cli.lazy_collision = true,
+                "--dynamic-domain" => cli.dynamic_domain = true,
+                "--rejection-sampling" => cli.rejection_sampling = true,
+                "--theme" => {
+                    cli.theme = Some(match value()?.as_str() {
+                        "light" => ThemeKind::Light,
+                        "dark" => ThemeKind::Dark,
+                        other => return Err(format!("unknown theme: {}", other)),
+                    })
+                }
+                "--neighbor-index" => {
+                    cli.neighbor_index = Some(match value()?.as_str() {
+                        "linear" => NeighborIndexKind::Linear,
+                        "grid" => NeighborIndexKind::Grid,
+                        "kd_tree" => NeighborIndexKind::KdTree,
+                        other => return Err(format!("unknown neighbor index: {}", other)),
+                    })
+                }
+                "--planner" => {
+                    cli.planner = Some(match value()?.as_str() {
+                        "rrt" => PlannerKind::Rrt,
+                        "prm" => PlannerKind::Prm,
+                        other => return Err(format!("unknown planner: {}", other)),
+                    })
+                }
+                _ => return Err(format!("unknown argument: {}", flag)),
+            }
+        }
+        Ok(cli)
+    }
+
+    // Dosyadan (veya varsayılanlardan) gelen yapılandırmanın üzerine bayrakları uygular
+    fn apply(&self, config: &mut RRTConfig) {
+        if let Some(seed) = self.seed {
+            config.seed = Some(seed);
+        }
+        if let Some(step_size) = self.step_size {
+            config.step_size = step_size;
+        }
+        if let Some(goal_threshold) = self.goal_threshold {
+            config.goal_threshold = goal_threshold;
+        }
+        if let Some(goal_bias) = self.goal_bias {
+            config.goal_bias = goal_bias;
+        }
+        if let Some(iterations_per_frame) = self.iterations_per_frame {
+            config.iterations_per_frame = iterations_per_frame;
+        }
+        if let Some(robot_radius) = self.robot_radius {
+            config.robot_radius = robot_radius;
+        }
+        if let Some(clearance_weight) = self.clearance_weight {
+            config.clearance_weight = clearance_weight;
+        }
+        if let Some(max_iters) = self.max_iters {
+            config.max_iters = max_iters;
+        }
+        if let Some(max_nodes) = self.max_nodes {
+            config.max_nodes = Some(max_nodes);
+        }
+        if let Some(max_path_cost) = self.max_path_cost {
+            config.max_path_cost = Some(max_path_cost);
+        }
+        if let Some(time_budget_ms) = self.time_budget_ms {
+            config.time_budget_ms = Some(time_budget_ms);
+        }
+        if let Some(batch_size) = self.batch_size {
+            config.batch_size = batch_size;
+        }
+        if let Some(planner) = self.planner {
+            config.planner = planner;
+        }
+        if let Some(theme) = self.theme {
+            config.theme = theme;
+        }
+        if let Some(neighbor_index) = self.neighbor_index {
+            config.neighbor_index = neighbor_index;
+        }
+        if self.compare_astar {
+            config.compare_astar = true;
+        }
+        if self.any_angle {
+            config.any_angle = true;
+        }
+        if self.cross_edges {
+            config.cross_edges = true;
+        }
+        if self.lazy_collision {
+            config.lazy_collision = true;
+        }
+        if self.dynamic_domain {
+            config.dynamic_domain = true;
+        }
+        if self.rejection_sampling {
+            config.rejection_sampling = true;
+        }
     }
 }
 
-// Düğüm yapısı, bir nokta ve ebeveyn indeksini içerir
-struct Node {
-    point: Point,
-    parent: Option<usize>,
+fn parse_value<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
+
+// Argümanları ve varsa yapılandırma dosyasını okuyarak son yapılandırmayı oluşturur
+fn load_config() -> Result<(CliArgs, RRTConfig), String> {
+    let cli = CliArgs::parse(std::env::args().skip(1))?;
+    let mut config = match &cli.config {
+        Some(path) => RRTConfig::from_toml_file(path).map_err(|e| format!("{}: {}", path, e))?,
+        None => RRTConfig::default(),
+    };
+    cli.apply(&mut config);
+    Ok((cli, config))
 }
 
-impl Node {
-    // Yeni bir düğüm oluşturur, noktayı ve ebeveynini alır
-    fn new(point: Point, parent: Option<usize>) -> Self {
-        Node { point, parent }
+// Ekran konumunu dünya koordinatına çevirir; Z ile kamera ağaca sığdırılmışsa onun dönüşümü kullanılır
+fn screen_to_world(rrt: &RRT, auto_fit: bool, p: Vec2) -> Vec2 {
+    if auto_fit {
+        fit_camera(rrt.tree_bounds(), rrt.step_size() * 4.0).screen_to_world(p)
+    } else {
+        p
     }
 }
 
-// RRT ağacını tanımlayan yapı
-struct RRT {
-    nodes: Vec<Node>, // Ağacın düğümleri
-    goal: Point, // Hedef nokta
-    step_size: f32, // Adım boyutu
-    goal_threshold: f32, // Hedef eşiği
-    rng: ThreadRng, // Rastgele sayı üreteci
+// Harita tümden değiştiğinde (üretme veya yükleme) yeni engelleri ve sıfırlamayı kaydeder
+fn record_map_change(recorder: &mut Option<Recorder>, rrt: &RRT) {
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.record(Event::ObstaclesCleared);
+        for obstacle in &rrt.obstacles {
+            recorder.record(Event::ObstacleAdded(*obstacle));
+        }
+        recorder.record(Event::Reset { start: rrt.starts[0], goal: rrt.goal() });
+    }
+}
+
+fn save_recording(recorder: &Option<Recorder>, path: &Option<String>) {
+    if let (Some(recorder), Some(path)) = (recorder, path) {
+        match recorder.save(path) {
+            Ok(()) => println!("Recording saved to {}", path),
+            Err(e) => eprintln!("could not save recording to {}: {}", path, e),
+        }
+    }
 }
 
-impl RRT {
-    // Başlangıç ve hedef noktalar, adım boyutu ve hedef eşiği ile yeni bir RRT ağacı oluşturur
-    fn new(start: Point, goal: Point, step_size: f32, goal_threshold: f32) -> Self {
-        let root = Node::new(start, None); // Başlangıç düğümünü kök olarak ekler
-        RRT {
-            nodes: vec![root],
-            goal,
-            step_size,
-            goal_threshold,
-            rng: ::rand::thread_rng(),
+fn finish_event_log(rrt: &mut RRT, path: &Option<String>) {
+    if let (Some(mut observer), Some(path)) = (rrt.take_observer(), path) {
+        match observer.flush() {
+            Ok(()) => println!("Event log written to {}", path),
+            Err(e) => eprintln!("could not write event log to {}: {}", path, e),
         }
     }
+}
+
+// Bulunan yolu çalışma dizinindeki path.csv dosyasına yazar
+#[cfg(not(target_arch = "wasm32"))]
+fn export_path_csv(path: &Path) {
+    let result = File::create("path.csv").and_then(|file| write_path_csv(path, file));
+    match result {
+        Ok(()) => println!("Path written to path.csv ({} waypoints)", path.len()),
+        Err(e) => eprintln!("could not write path.csv: {}", e),
+    }
+}
+
+// Tarayıcıda dosya sistemi olmadığından CSV konsola yazdırılır
+#[cfg(target_arch = "wasm32")]
+fn export_path_csv(path: &Path) {
+    if let Err(e) = write_path_csv(path, std::io::stdout()) {
+        eprintln!("could not print path: {}", e);
+    }
+}
+
+// Bulunan yolu nav_msgs/Path biçiminde path.yaml dosyasına yazar; çerçeve ve ölçek yapılandırmadan gelir
+#[cfg(not(target_arch = "wasm32"))]
+fn export_path_ros(path: &Path, config: &RRTConfig) {
+    let stamp = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+    let options = RosPathOptions { frame_id: config.export_frame_id.clone(), stamp, scale: config.export_scale };
+    match File::create("path.yaml").and_then(|file| write_path_ros_yaml(path, &options, file)) {
+        Ok(()) => println!("Path written to path.yaml (frame {})", options.frame_id),
+        Err(e) => eprintln!("could not write path.yaml: {}", e),
+    }
+}
 
-    // Rastgele bir nokta seçer
-    fn random_point(&mut self, min_x: f32, max_x: f32, min_y: f32, max_y: f32) -> Point {
-        let x = self.rng.gen_range(min_x..max_x);
-        let y = self.rng.gen_range(min_y..max_y);
-        Point { x, y }
+// Tarayıcıda duvar saati olmadığından zaman damgası sıfırdır
+#[cfg(target_arch = "wasm32")]
+fn export_path_ros(path: &Path, config: &RRTConfig) {
+    let options = RosPathOptions { frame_id: config.export_frame_id.clone(), stamp: std::time::Duration::ZERO, scale: config.export_scale };
+    if let Err(e) = write_path_ros_yaml(path, &options, std::io::stdout()) {
+        eprintln!("could not print path: {}", e);
     }
+}
 
-    // En yakın düğümü bulur
-    fn find_nearest(&self, point: &Point) -> usize {
-        self.nodes
-            .iter()
-            .enumerate()
-            .min_by(|(_, a), (_, b)| {
-                a.point
-                    .distance(point)
-                    .partial_cmp(&b.point.distance(point))
-                    .unwrap()
-            })
-            .map(|(index, _)| index)
-            .unwrap()
+// Ağaç istatistiklerini konsola tek satırlık JSON olarak yazdırır; satırlar ağacın büyümesini izlemek
+// için bir dosyada toplanabilir
+fn print_tree_statistics(rrt: &RRT) {
+    if let Err(e) = write_tree_statistics_json(&rrt.statistics(), std::io::stdout()) {
+        eprintln!("could not print tree statistics: {}", e);
     }
+}
+
+// `--gif-fps` ve `--gif-width` hem `--capture-gif` hem `--record-gif` çıktısına uygulanır
+fn gif_options(cli: &CliArgs, path: &str) -> GifOptions {
+    let fps = cli.gif_fps.filter(|&fps| fps > 0.0).unwrap_or(25.0);
+    GifOptions { path: PathBuf::from(path), fps, width: cli.gif_width }
+}
 
-    // Verilen noktadan hedefe doğru adım boyutunda ilerler
-    fn steer(&self, from: &Point, to: &Point) -> Point {
-        let angle = (to.y - from.y).atan2(to.x - from.x);
-        Point {
-            x: from.x + self.step_size * angle.cos(),
-            y: from.y + self.step_size * angle.sin(),
+fn start_capture(cli: &CliArgs) -> Option<FrameCapture> {
+    let dir = PathBuf::from(cli.capture.as_deref().unwrap_or("frames"));
+    let every = cli.capture_every.unwrap_or(10);
+    let gif = cli.capture_gif.as_deref().map(|path| gif_options(cli, path));
+    match FrameCapture::start(Some(dir.clone()), every, gif) {
+        Ok(capture) => {
+            println!("Capturing every {} iterations to {}", every, dir.display());
+            Some(capture)
+        }
+        Err(e) => {
+            eprintln!("could not start capture in {}: {}", dir.display(), e);
+            None
         }
     }
+}
 
-    // Çarpışma kontrolü, burada basit olarak her zaman çarpışmasız kabul edilir
-    fn is_collision_free(&self, _point: &Point) -> bool {
-        true
+// PNG kareler yazmadan, her `--gif-every` karede bir pencereyi GIF'e ekleyen kayıt
+fn start_gif_recording(cli: &CliArgs) -> Option<FrameCapture> {
+    let path = cli.record_gif.as_deref()?;
+    if !cfg!(feature = "gif") {
+        eprintln!("--record-gif requires building with `--features gif`");
+        return None;
     }
+    let every = cli.gif_every.unwrap_or(2);
+    match FrameCapture::start(None, every, Some(gif_options(cli, path))) {
+        Ok(capture) => {
+            println!("Recording every {} frames to {}", every, path);
+            Some(capture)
+        }
+        Err(e) => {
+            eprintln!("could not record GIF to {}: {}", path, e);
+            None
+        }
+    }
+}
 
-    // Yeni düğüm ekler
-    fn add_node(&mut self, point: Point, parent_index: usize) {
-        let new_node = Node::new(point, Some(parent_index));
-        self.nodes.push(new_node);
+fn finish_capture(capture: &mut Option<FrameCapture>) {
+    if let Some(mut capture) = capture.take() {
+        match capture.finish() {
+            Ok(frames) => println!("Capture finished ({} frames)", frames),
+            Err(e) => eprintln!("capture failed: {}", e),
+        }
     }
+}
+
+// Tuşlarla ayarlanabilen kare başına iterasyon üst sınırı
+const MAX_ITERATIONS_PER_FRAME: usize = 1 << 14;
+
+// İmlecin bir düğümü seçmiş sayılması için düğüme en fazla bu kadar piksel yakın olması gerekir
+const HOVER_PICK_RADIUS_PX: f32 = 8.0;
+
+// Hedef işaretçisinin sürüklenmeye başlaması için imlecin ona en fazla bu kadar piksel yakın olması gerekir
+const GOAL_GRAB_RADIUS_PX: f32 = 12.0;
+
+// Çarpışma nedeniyle reddedilen noktaların ekranda kalıp solduğu süre (saniye)
+const REJECTED_FADE_SECS: f64 = 1.5;
+
+// WASM derlemesinde `Instant` bulunmadığından zaman bütçesi macroquad'ın saatiyle ölçülür
+struct FrameClock;
+
+impl Clock for FrameClock {
+    fn now(&self) -> f64 {
+        get_time()
+    }
+}
+
+// Kısayollarla değiştirilen parametrenin HUD'da gösterildiği süre (saniye)
+const PARAMETER_FLASH_SECS: f64 = 2.0;
+
+// Yumuşatılmış yolda iki ara nokta arasındaki eğri parçasından alınan örnek sayısı
+const SPLINE_SAMPLES_PER_SEGMENT: usize = 8;
+
+// [ ] adım boyutunu, - = hedef yanlılığını, , . hedef eşiğini birer adım azaltıp artırır.
+// Değerler planlayıcının ayarlayıcılarıyla geçerli aralığa kırpılır; değişen değerin HUD'da
+// gösterilecek metni döner.
+fn adjust_parameters(rrt: &mut RRT) -> Option<String> {
+    let delta = |down: KeyCode, up: KeyCode| (is_key_pressed(up) as i32 - is_key_pressed(down) as i32) as f32;
+    let step = delta(KeyCode::LeftBracket, KeyCode::RightBracket);
+    if step != 0.0 {
+        let value = rrt.set_step_size(rrt.step_size() + step);
+        return Some(format!("step size: {:.1}", value));
+    }
+    let bias = delta(KeyCode::Minus, KeyCode::Equal);
+    if bias != 0.0 {
+        let value = rrt.set_goal_bias(rrt.goal_bias() + bias * 0.05);
+        return Some(format!("goal bias: {:.2}", value));
+    }
+    let threshold = delta(KeyCode::Comma, KeyCode::Period);
+    if threshold != 0.0 {
+        let value = rrt.set_goal_threshold(rrt.goal_threshold() + threshold);
+        return Some(format!("goal threshold: {:.1}", value));
+    }
+    None
+}
 
-    // Geriye doğru giderek yolu çıkarır
-    fn trace_path(&self) -> Vec<Point> {
-        let mut path = Vec::new();
-        let mut current_node_index = self.nodes.len() - 1;
+// 1-5 tuşlarıyla açılıp kapatılan hata ayıklama katmanları
+struct DebugLayers {
+    sample: bool, // Son örnek ve en yakın düğüme çizgisi
+    rejected: bool, // Çarpışma nedeniyle reddedilen noktalar
+    goal_region: bool, // Hedef eşiği çemberi
+    node_dots: bool, // Düğüm noktaları
+    spline: bool, // Bulunan yolun Catmull-Rom eğrisiyle yumuşatılmış hali
+    coverage: bool, // Kapsama ızgarasının hücre sınırları ve kaplanan serbest hücreler
+}
 
-        while let Some(parent_index) = self.nodes[current_node_index].parent {
-            path.push(self.nodes[current_node_index].point);
-            current_node_index = parent_index;
+impl DebugLayers {
+    fn toggle_pressed(&mut self) {
+        for (key, layer) in [
+            (KeyCode::Key1, &mut self.sample),
+            (KeyCode::Key2, &mut self.rejected),
+            (KeyCode::Key3, &mut self.goal_region),
+            (KeyCode::Key4, &mut self.node_dots),
+            (KeyCode::Key5, &mut self.spline),
+            (KeyCode::Key6, &mut self.coverage),
+        ] {
+            if is_key_pressed(key) {
+                *layer = !*layer;
+            }
         }
-        path.push(self.nodes[current_node_index].point);
-        path.reverse();
-        path
+    }
+
+    fn hud_text(&self) -> String {
+        let state = |on: bool| if on { "[x]" } else { "[ ]" };
+        format!(
+            "1{} sample  2{} rejected  3{} goal  4{} nodes  5{} spline  6{} coverage",
+            state(self.sample),
+            state(self.rejected),
+            state(self.goal_region),
+            state(self.node_dots),
+            state(self.spline),
+            state(self.coverage)
+        )
     }
 }
 
-#[macroquad::main("RRT Visualization")]
-async fn main() {
-    let mut rng = ::rand::thread_rng();
-    
-    let start = Point {
-        x: rng.gen_range(0.0..400.0),
-        y: rng.gen_range(0.0..400.0),
-    };
-    let goal = Point {
-        x: rng.gen_range(0.0..400.0),
-        y: rng.gen_range(0.0..400.0),
+// Ekransız toplu deney modu: özet tabloyu yazdırır ve deneme başına CSV üretir
+// `--parallel-trials` ile deneme tohumları yapılandırmadaki tohumdan türetilir ve denemeler
+// `rayon` özelliğiyle iş parçacıklarına dağıtılır; sonuçlar her çalıştırmada aynıdır
+fn run_batch(cli: &CliArgs, config: &RRTConfig, trials: u64) {
+    // Engel içindeki uç noktayla denemelerin hepsi boşuna tüm iterasyonları tüketirdi
+    if let Err(e) = RRT::from_config(config).check_endpoints() {
+        eprintln!("error: {}", e);
+        process::exit(2);
+    }
+    if cli.parallel_trials && !cfg!(feature = "rayon") {
+        eprintln!("--parallel-trials runs sequentially unless built with `--features rayon`");
+    }
+    let results = if cli.parallel_trials { run_trials_parallel(config, trials) } else { run_trials(config, trials) };
+    let summary = BatchSummary::from_trials(&results);
+    if let Err(e) = summary.print(std::io::stdout()) {
+        eprintln!("could not print summary: {}", e);
+    }
+    let csv_path = cli.trials_csv.as_deref().unwrap_or("trials.csv");
+    match File::create(csv_path).and_then(|file| write_trials_csv(&results, file)) {
+        Ok(()) => println!("Per-trial results written to {}", csv_path),
+        Err(e) => eprintln!("could not write {}: {}", csv_path, e),
+    }
+}
+
+fn main() {
+    let (cli, config) = match load_config() {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(2);
+        }
     };
-    
-    let mut rrt = RRT::new(start, goal, 10.0, 10.0);
+
+    // Deney modu pencere açmadan çalışır
+    if let Some(trials) = cli.trials {
+        run_batch(&cli, &config, trials);
+        return;
+    }
+
+    // Karşılaştırma modunda komut satırı bayrakları yalnızca sol taraftaki yapılandırmaya uygulanır
+    if let Some(path) = &cli.compare {
+        let other = match RRTConfig::from_toml_file(path) {
+            Ok(other) => other,
+            Err(e) => {
+                eprintln!("error: {}: {}", path, e);
+                process::exit(2);
+            }
+        };
+        let label = cli.config.clone().unwrap_or_else(|| "default".to_string());
+        let window = window_conf("RRT Comparison", &config);
+        macroquad::Window::from_config(window, run_compare_window((label, config), (path.clone(), other)));
+        return;
+    }
+
+    // `[car]` tablosu verilmişse birincil robot Reeds-Shepp eğrileriyle planlanan bir arabadır
+    if config.car.is_some() && config.planner == PlannerKind::Rrt {
+        macroquad::Window::from_config(window_conf("RRT Car", &config), run_car_window(config));
+        return;
+    }
+
+    // Ek robotlar verilmişse tüm robotlar ekransız planlanıp birlikte canlandırılır
+    if !config.robots.is_empty() && config.planner == PlannerKind::Rrt {
+        macroquad::Window::from_config(window_conf("RRT Fleet", &config), run_fleet_window(config));
+        return;
+    }
+
+    match config.planner {
+        PlannerKind::Rrt => {
+            macroquad::Window::from_config(window_conf("RRT Visualization", &config), run_window(cli, config))
+        }
+        PlannerKind::Prm => {
+            macroquad::Window::from_config(window_conf("PRM Visualization", &config), run_prm_window(config))
+        }
+    }
+}
+
+// Pencere başlığı ve yapılandırmadaki boyutlarla macroquad ayarları
+fn window_conf(title: &str, config: &RRTConfig) -> macroquad::conf::Conf {
+    macroquad::conf::Conf {
+        miniquad_conf: macroquad::miniquad::conf::Conf {
+            window_title: title.to_string(),
+            window_width: config.window_width,
+            window_height: config.window_height,
+            high_dpi: config.high_dpi,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+// PRM modu: yol haritası bir kez kurulur, sol tık yeni bir hedef için sorgu yapar
+async fn run_prm_window(config: RRTConfig) {
+    let mut prm = Prm::from_config(&config);
+    let mut path = prm.plan(config.max_iters).path().map(|path| path.to_vec());
+    let roadmap = prm.edges();
+    let style = DrawStyle::from_config(&config);
+    println!(
+        "Roadmap built: {} vertices, {} edges",
+        prm.vertices.len(),
+        roadmap.len()
+    );
+
+    loop {
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let (x, y) = mouse_position();
+            prm.goal = Point { x, y };
+            path = prm.query(&prm.start, &prm.goal);
+            if path.is_none() {
+                println!("No path to the selected goal");
+            }
+        }
+        if is_quit_requested() || is_key_pressed(KeyCode::Escape) {
+            break;
+        }
+
+        clear_background(style.theme.background);
+        for obstacle in &prm.obstacles {
+            style.draw_obstacle(obstacle, prm.robot_radius);
+        }
+        for (a, b) in &roadmap {
+            draw_line(a.x, a.y, b.x, b.y, style.edge_width, style.theme.roadmap);
+        }
+        for v in &prm.vertices {
+            draw_circle(v.x, v.y, style.vertex_radius, style.theme.edge);
+        }
+        if let Some(path) = &path {
+            style.draw_path(path, style.theme.path);
+        }
+        style.draw_endpoints(std::slice::from_ref(&prm.start), std::slice::from_ref(&prm.goal));
+
+        next_frame().await;
+    }
+}
+
+async fn run_window(cli: CliArgs, #[allow(unused_mut)] mut config: RRTConfig) {
+    // Web hedefinde entropi kaynağı olmadığından tohum macroquad saatinden türetilir
+    #[cfg(target_arch = "wasm32")]
+    if config.seed.is_none() {
+        config.seed = Some((macroquad::miniquad::date::now() * 1000.0) as u64);
+    }
+
+    let mut rrt = RRT::from_config(&config);
+    let resolution = config.heatmap_resolution;
+    rrt.heatmap = Some(SampleHeatmap::new(rrt.bounds, resolution, resolution));
     let mut goal_reached = false;
-    let mut optimal_path: Vec<Point> = Vec::new();
+    let mut optimal_path = Path::default();
+
+    // Yeniden oynatmanın biçimi kaydın içeriğinden seçilir. İlk örnekten sonra harita
+    // değişmiyorsa kayıttaki başlangıç haritası kurulur ve ağaç planlayıcının kendisiyle kayıttaki
+    // örneklerden yeniden büyütülür; böylece planlayıcıdaki bir hata aynı örneklerle tekrar üretilir.
+    // Çalışma sırasında harita değiştiyse ağaç yalnızca kayıttaki olaylardan kurulur.
+    let mut replayer = match &cli.replay {
+        Some(path) => match Replayer::load(path) {
+            Ok(recording) => {
+                rrt.obstacles.clear();
+                if recording.regrows_from_samples() {
+                    for event in recording.events().iter().take_while(|e| !matches!(e, Event::Sample(_))) {
+                        event.apply(&mut rrt);
+                    }
+                    let sampler = RecordedSampler::from_events(recording.events());
+                    println!("Replaying {} recorded samples", sampler.len());
+                    rrt.sample_source = Some(Box::new(sampler));
+                    None
+                } else {
+                    println!("Replaying {} recorded events; the map changed during the run", recording.events().len());
+                    Some(recording)
+                }
+            }
+            Err(e) => {
+                eprintln!("error: could not load recording {}: {}", path, e);
+                process::exit(2);
+            }
+        },
+        None => None,
+    };
+    let replay_speed = cli.replay_speed.unwrap_or(1.0).max(0.0);
+    let mut replay_budget = 0.0;
+
+    let mut recorder = cli.record.as_ref().map(|_| Recorder::starting_from(&rrt));
+    if let Some(path) = &cli.event_log {
+        match JsonLinesObserver::create(path) {
+            Ok(observer) => rrt.set_observer(Some(Box::new(observer))),
+            Err(e) => {
+                eprintln!("error: could not create event log {}: {}", path, e);
+                process::exit(2);
+            }
+        }
+    }
+    let mut capture = if cli.capture.is_some() { start_capture(&cli) } else { None };
+    let mut gif_recording = start_gif_recording(&cli);
+    let mut frame = 0; // GIF kaydı iterasyonlara değil çizilen karelere göre aralıklanır
+    let mut iteration = 0;
+
+    // Izgara A* karşılaştırması; harita veya ağaç değiştiğinde yeniden hesaplanır
+    let mut show_astar = config.compare_astar;
+    let mut astar_path: Option<Option<Vec<Point>>> = None;
+    // Z, ağacın pencereyi doldurması için kamerayı ağacın sınırlarına otomatik sığdırır
+    let mut auto_fit = false;
+    let mut layers = DebugLayers { sample: false, rejected: false, goal_region: false, node_dots: false, spline: false, coverage: false };
+    let mut rejected: Vec<(Point, f64)> = Vec::new(); // Reddedilen nokta ve reddedildiği an
+    // Ormanda her kökün ağacı ayrı renkte başlar
+    let mut edge_coloring = if rrt.starts.len() > 1 { EdgeColoring::Root } else { EdgeColoring::Flat };
+    let style = DrawStyle::from_config(&config);
+    // H örnek yoğunluğu haritasını gösterir, X birikmiş sayıları sıfırlar
+    let mut show_heatmap = false;
+    // L açıklık alanını gösterir; alan her karede örneklendiğinden varsayılan olarak kapalıdır
+    let mut show_clearance = false;
+    // G ve M ile üretilen her yeni harita bir sonraki tohumu kullanır
+    let mut map_seed = config.seed.unwrap_or(0);
+    // PageUp ve PageDown kare başına planlama iterasyonunu ikiye katlar veya yarıya indirir
+    let mut iterations_per_frame = config.iterations_per_frame.max(1);
+    // Zaman bütçesi yalnızca planlama iterasyonlarında geçen süreyi sayar, çizim süresini saymaz
+    let mut planning_time = 0.0;
+    let mut budget_exhausted = false;
+    // P ayar panelini açıp kapatır
+    let mut panel = ControlPanel::new(&rrt);
+    let mut parameter_flash: Option<(String, f64)> = None; // Son değişen parametre ve değiştiği an
+    // Engel içindeki uç nokta ağacın hiç ilerlemeyeceği anlamına gelir; pencere yine açılır ki
+    // kullanıcı hedefi sürükleyerek düzeltebilsin
+    if let Err(e) = rrt.check_endpoints() {
+        eprintln!("warning: {}", e);
+        parameter_flash = Some((e.to_string(), get_time()));
+    }
+    let mut dragging_goal = false; // Birincil hedef sol tuşla sürükleniyor
+    prevent_quit();
 
     loop {
-        if !goal_reached {
-            // Rastgele nokta oluştur ve ağaçta en yakın düğümü bul
-            let rand_point = rrt.random_point(0.0, 400.0, 0.0, 400.0);
-            let nearest_index = rrt.find_nearest(&rand_point);
-            let nearest_node = &rrt.nodes[nearest_index];
-            let new_point = rrt.steer(&nearest_node.point, &rand_point);
+        let mut planned = false;
+        let mut just_reached = false;
+        if is_key_pressed(KeyCode::P) {
+            panel.open = !panel.open;
+        }
+        let goal_before = rrt.goal_node();
+        let panel_action = panel.show(&mut rrt, &mut layers, &mut iterations_per_frame);
+        if let Some(replayer) = replayer.as_mut() {
+            // Hız, kare başına yeniden oynatılan iterasyon sayısıdır (kesirli olabilir)
+            replay_budget += replay_speed;
+            while replay_budget >= 1.0 && !replayer.is_finished() {
+                replay_budget -= 1.0;
+                for event in replayer.step_iteration(&mut rrt) {
+                    match event {
+                        Event::GoalReached { node } => {
+                            goal_reached = true;
+                            optimal_path = rrt.trace_path_from(node).unwrap_or_default();
+                        }
+                        Event::Reset { .. } => {
+                            goal_reached = false;
+                            optimal_path = Path::default();
+                        }
+                        // Engel değişiklikleri yolu kesmiş, taşınan hedef başka bir düğümü seçmiş olabilir
+                        Event::GoalMoved(_) | Event::ObstacleAdded(_) | Event::ObstacleMoved { .. } => {
+                            goal_reached = rrt.goal_node().is_some();
+                            optimal_path = rrt.best_path().unwrap_or_default();
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        } else {
+            if let Some(message) = adjust_parameters(&mut rrt) {
+                parameter_flash = Some((message, get_time()));
+            }
+            // Hedef işaretçisi sol tuşla sürüklenir; bırakıldığında ağaç korunarak hedef taşınır ve
+            // büyüme kaldığı yerden sürer. Yeni eşiğin içinde kalan düğüm aşağıda hedef düğümü olarak işlenir.
+            let mouse = Vec2::from(mouse_position());
+            let mouse_world = Point::from(screen_to_world(&rrt, auto_fit, mouse));
+            if is_mouse_button_pressed(MouseButton::Left) && !panel.is_mouse_over() {
+                let grab = Point::from(screen_to_world(&rrt, auto_fit, mouse + vec2(GOAL_GRAB_RADIUS_PX, 0.0)));
+                dragging_goal = mouse_world.distance(&rrt.goal()) <= grab.distance(&mouse_world);
+            }
+            if dragging_goal && !is_mouse_button_down(MouseButton::Left) {
+                dragging_goal = false;
+                rrt.move_goal(mouse_world);
+                astar_path = None;
+                if let Err(e) = rrt.check_endpoints() {
+                    parameter_flash = Some((e.to_string(), get_time()));
+                }
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.record(Event::GoalMoved(mouse_world));
+                }
+            }
+            // Sağ tık imlecin olduğu yere bir engel bırakır; ağaç sıfırlanmaz, yalnızca engelin kestiği
+            // dallar budanır
+            let mut pruned = 0;
+            if is_mouse_button_pressed(MouseButton::Right) && !panel.is_mouse_over() {
+                let obstacle = Obstacle::Circle { center: mouse_world, radius: 20.0 };
+                pruned = rrt.add_obstacle(obstacle);
+                astar_path = None;
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.record(Event::ObstacleAdded(obstacle));
+                }
+            }
+            // U son eklenen düğümü geri alır; çocuğu olan düğüm çıkarılmaz
+            if is_key_pressed(KeyCode::U) {
+                let root = rrt.node_count().saturating_sub(1);
+                match rrt.pop_node() {
+                    Some(_) => {
+                        if let Some(recorder) = recorder.as_mut() {
+                            recorder.record(Event::SubtreePruned { root });
+                        }
+                    }
+                    None => println!("Cannot undo: the last node is a root or has children"),
+                }
+            }
+            // Panelde veya kısayolla değiştirilen hedef eşiği, taşınan hedef ya da budanan dallar
+            // hedef düğümünü veya yolunu değiştirmiş olabilir; hedef düğümü kalmadıysa büyüme sürer
+            if rrt.goal_node() != goal_before || pruned > 0 {
+                for root in rrt.validate_goal_path() {
+                    if let Some(recorder) = recorder.as_mut() {
+                        recorder.record(Event::SubtreePruned { root });
+                    }
+                }
+                goal_reached = rrt.goal_node().is_some();
+                optimal_path = rrt.best_path().unwrap_or_default();
+                if let (Some(node), Some(recorder)) = (rrt.goal_node(), recorder.as_mut()) {
+                    recorder.record(Event::GoalReached { node });
+                }
+            }
+
+            // R ağacı sıfırlar, Shift+R haritayı koruyup rastgele çarpışmasız uç noktalar seçer
+            let restart = matches!(panel_action, Some(PanelAction::Reset | PanelAction::Restart));
+            let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+            let mut reset = restart || (is_key_pressed(KeyCode::R) && !shift);
+            if is_key_pressed(KeyCode::R) && shift {
+                let rolled = rrt.random_free_point().and_then(|start| Ok((start, rrt.random_free_point()?)));
+                match rolled.and_then(|(start, goal)| rrt.reset_with(start, goal)) {
+                    Ok(_) => reset = true,
+                    Err(e) => println!("Could not re-roll start and goal: {}", e),
+                }
+            }
+            if reset {
+                let (start, goal) = (rrt.starts[0], rrt.goal());
+                // Ara nokta yanlılığı açıksa yeni ağaç bulunan son yolun çevresinde yoğunlaşır
+                if rrt.waypoint_bias > 0.0 && goal_reached {
+                    rrt.seed_from_path(&optimal_path);
+                }
+                rrt.reset(start, goal);
+                goal_reached = false;
+                optimal_path = Path::default();
+                astar_path = None;
+                rejected.clear();
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.record(Event::Reset { start, goal });
+                }
+            }
+            // G rastgele engeller, M labirent üretir; harita değişince ağaç sıfırlanır
+            let (start, goal) = (rrt.starts[0], rrt.goal());
+            let generated = if is_key_pressed(KeyCode::G) {
+                Some(random_clutter(&rrt.bounds, &start, &goal, 15, 15.0, 45.0, map_seed))
+            } else if is_key_pressed(KeyCode::M) {
+                let walls = maze(&rrt.bounds, 10, 10, 4.0, map_seed);
+                Some(walls.into_iter().filter(|w| !w.contains(&start) && !w.contains(&goal)).collect())
+            } else if panel_action == Some(PanelAction::ClearObstacles) {
+                Some(Vec::new())
+            } else {
+                None
+            };
+            // Ctrl+S haritayı kaydeder, Ctrl+O kaydedilmiş haritayı yükleyip ağacı sıfırlar
+            let ctrl = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+            let layout_path = cli.layout.as_deref().unwrap_or("layout.toml");
+            if ctrl && is_key_pressed(KeyCode::S) {
+                match rrt.layout().save(layout_path) {
+                    Ok(()) => println!("Layout saved to {}", layout_path),
+                    Err(e) => eprintln!("could not save layout to {}: {}", layout_path, e),
+                }
+            }
+            if ctrl && is_key_pressed(KeyCode::O) {
+                match MapLayout::load(layout_path) {
+                    Ok(layout) => {
+                        rrt.load_layout(&layout);
+                        rrt.heatmap = Some(SampleHeatmap::new(rrt.bounds, resolution, resolution));
+                        goal_reached = false;
+                        optimal_path = Path::default();
+                        astar_path = None;
+                        rejected.clear();
+                        record_map_change(&mut recorder, &rrt);
+                        println!("Layout loaded from {}", layout_path);
+                    }
+                    Err(e) => eprintln!("could not load layout from {}: {}", layout_path, e),
+                }
+            }
+            if let Some(obstacles) = generated {
+                map_seed += 1;
+                rrt.obstacles = obstacles;
+                rrt.reset(start, goal);
+                goal_reached = false;
+                optimal_path = Path::default();
+                astar_path = None;
+                rejected.clear();
+                record_map_change(&mut recorder, &rrt);
+            }
+
+            // Ağaç herhangi bir nedenle sıfırlandıysa bütçe baştan başlar
+            if rrt.iteration == 0 {
+                planning_time = 0.0;
+                budget_exhausted = false;
+            }
+            let frame_start = FrameClock.now();
+            // Kare başına birden çok iterasyon çalışabilir; hedefe ulaşılınca kalanlar atlanır
+            for _ in 0..iterations_per_frame {
+                if goal_reached || budget_exhausted {
+                    break;
+                }
+                let elapsed = planning_time + FrameClock.now() - frame_start;
+                if rrt.time_budget.is_some_and(|budget| elapsed >= budget.as_secs_f64()) {
+                    budget_exhausted = true;
+                    println!("Time budget exhausted after {} iterations", rrt.iteration);
+                    break;
+                }
+                iteration += 1;
+                planned = true;
+                // Rastgele nokta oluştur, en yakın düğümden yönlendir ve çarpışma yoksa ekle
+                let result = rrt.step();
+                let step = rrt.last_step.expect("step records its intermediate values");
+                if let Some(recorder) = recorder.as_mut() {
+                    recorder.record(Event::Sample(step.sample));
+                }
+                match result {
+                    StepResult::Rejected | StepResult::Exhausted => {
+                        rejected.push((step.steered, get_time()));
+                        if let Some(recorder) = recorder.as_mut() {
+                            recorder.record(Event::Rejected(step.steered));
+                        }
+                    }
+                    StepResult::Extended { .. } | StepResult::GoalReached { .. } => {
+                        // Bölünmüş kenarın ara düğümleri de sırasıyla kaydedilir; son düğüm yönlendirilen noktadır
+                        if let Some(recorder) = recorder.as_mut() {
+                            for index in rrt.node_count() - step.nodes_added..rrt.node_count() {
+                                let node = rrt.node(index);
+                                let parent = node.parent.expect("added nodes have a parent");
+                                recorder.record(Event::NodeAdded { point: node.point, parent });
+                            }
+                        }
+                    }
+                }
+                if let StepResult::GoalReached { .. } = result {
+                    // Tembel modda aday yolun kenarları şimdi doğrulanır; geçersiz alt ağaçlar budanır
+                    for root in rrt.validate_goal_path() {
+                        if let Some(recorder) = recorder.as_mut() {
+                            recorder.record(Event::SubtreePruned { root });
+                        }
+                    }
+                }
+                if let (StepResult::Extended { .. } | StepResult::GoalReached { .. }, Some(goal_node)) = (result, rrt.goal_node()) {
+                    goal_reached = true;
+                    just_reached = true;
+                    optimal_path = rrt.best_path().unwrap_or_default();
+                    println!("Goal Reached!");
+                    if rrt.lazy_collision {
+                        let stats = rrt.lazy_stats;
+                        println!(
+                            "Lazy collision checks: {} performed, {} saved, {} subtrees pruned",
+                            stats.checks_performed,
+                            stats.checks_saved(),
+                            stats.subtrees_pruned
+                        );
+                    }
+                    if let Some(recorder) = recorder.as_mut() {
+                        recorder.record(Event::GoalReached { node: goal_node });
+                    }
+                    save_recording(&recorder, &cli.record);
+                }
+            }
+            planning_time += FrameClock.now() - frame_start;
+        }
+
+        // W bulunan yolu CSV ve nav_msgs/Path YAML'ı olarak dışa aktarır
+        if is_key_pressed(KeyCode::W) || panel_action == Some(PanelAction::ExportPath) {
+            if goal_reached {
+                export_path_csv(&optimal_path);
+                export_path_ros(&optimal_path, &config);
+            } else {
+                println!("No path to export yet");
+            }
+        }
+
+        // T ağaç istatistiklerini yazdırır
+        if is_key_pressed(KeyCode::T) {
+            print_tree_statistics(&rrt);
+        }
+
+        // A ızgara A* karşılaştırmasını açıp kapatır
+        if is_key_pressed(KeyCode::A) {
+            show_astar = !show_astar;
+        }
+        if show_astar && astar_path.is_none() {
+            let grid = GridAstar::from_rrt(&rrt, config.astar_cell_size);
+            astar_path = Some(grid.search(&rrt.starts[0], &rrt.goal()));
+        }
+
+        if is_key_pressed(KeyCode::PageUp) || is_key_pressed(KeyCode::KpAdd) {
+            iterations_per_frame = (iterations_per_frame * 2).min(MAX_ITERATIONS_PER_FRAME);
+        }
+        if is_key_pressed(KeyCode::PageDown) || is_key_pressed(KeyCode::KpSubtract) {
+            iterations_per_frame = (iterations_per_frame / 2).max(1);
+        }
+
+        if is_key_pressed(KeyCode::Z) {
+            auto_fit = !auto_fit;
+        }
+        layers.toggle_pressed();
+        if is_key_pressed(KeyCode::E) {
+            edge_coloring = edge_coloring.next();
+        }
+        if is_key_pressed(KeyCode::H) {
+            show_heatmap = !show_heatmap;
+        }
+        if is_key_pressed(KeyCode::L) {
+            show_clearance = !show_clearance;
+        }
+        if is_key_pressed(KeyCode::X) {
+            if let Some(heatmap) = rrt.heatmap.as_mut() {
+                heatmap.clear();
+            }
+        }
+        let now = get_time();
+        rejected.retain(|&(_, at)| now - at < REJECTED_FADE_SECS);
 
-            if rrt.is_collision_free(&new_point) {
-                rrt.add_node(new_point, nearest_index);
+        // C kare yakalamayı açıp kapatır
+        if is_key_pressed(KeyCode::C) {
+            if capture.is_some() {
+                finish_capture(&mut capture);
+            } else {
+                capture = start_capture(&cli);
             }
+        }
+
+        if is_quit_requested() || is_key_pressed(KeyCode::Escape) {
+            save_recording(&recorder, &cli.record);
+            finish_event_log(&mut rrt, &cli.event_log);
+            finish_capture(&mut capture);
+            finish_capture(&mut gif_recording);
+            break;
+        }
 
-            if new_point.distance(&rrt.goal) < rrt.goal_threshold {
-                goal_reached = true;
-                optimal_path = rrt.trace_path();
-                println!("Goal Reached!");
+        clear_background(style.theme.background);
+        let camera = auto_fit.then(|| fit_camera(rrt.tree_bounds(), rrt.step_size() * 4.0));
+        if let Some(camera) = &camera {
+            set_camera(camera);
+        }
+
+        // İmlecin altındaki düğüm; seçim yarıçapı pikselden dünya birimine çevrilir, böylece
+        // yakınlaştırma ne olursa olsun boş alanın üzerinde hiçbir şey seçilmez
+        let to_world = |p: Vec2| camera.as_ref().map_or(p, |c| c.screen_to_world(p));
+        let mouse = Vec2::from(mouse_position());
+        let cursor = to_world(mouse);
+        let pick_radius = to_world(mouse + vec2(HOVER_PICK_RADIUS_PX, 0.0)).distance(cursor);
+        let cursor_point = Point::from(cursor);
+        let hovered = Some(rrt.find_nearest(&cursor_point))
+            .filter(|&i| rrt.node(i).point.distance(&cursor_point) <= pick_radius);
+
+        // Arazi tonu ve yoğunluk haritası ağacın arkasında kalır
+        if let Some(terrain) = &rrt.terrain {
+            draw_cost_map(terrain, &style.theme);
+        }
+        if show_clearance {
+            let resolution = config.clearance_field_resolution;
+            let field = ClearanceField::sample(CellFrame::fitted(rrt.bounds, resolution, resolution), &rrt.checker(), rrt.robot_radius());
+            // Açıklık cezası açıksa renk cezanın bittiği uzaklıkta doyar, değilse en açık hücrede
+            let saturation = if rrt.clearance_weight > 0.0 { rrt.clearance_distance } else { field.max_value() };
+            draw_clearance_field(&field, if saturation.is_finite() { saturation } else { 1.0 }, &style.theme);
+        }
+        if show_heatmap {
+            if let Some(heatmap) = &rrt.heatmap {
+                draw_heatmap(heatmap, &style.theme);
+            }
+        }
+        if layers.coverage {
+            if let Some(coverage) = &rrt.coverage {
+                draw_coverage(coverage, &style.theme);
             }
         }
 
-        clear_background(WHITE);
+        // Engelleri çiz
+        for obstacle in &rrt.obstacles {
+            style.draw_obstacle(obstacle, rrt.robot_radius());
+        }
+
+        // Çapraz kenarlar ağaç kenarlarının altında soluk renkle çizilir
+        for &(a, b) in &rrt.graph_edges {
+            let (a, b) = (rrt.node(a).point, rrt.node(b).point);
+            draw_line(a.x, a.y, b.x, b.y, style.edge_width, style.theme.roadmap);
+        }
 
         // Düğümleri ve yolları çiz
-        for node in &rrt.nodes {
-            if let Some(parent_index) = node.parent {
-                let parent_node = &rrt.nodes[parent_index];
-                draw_line(
-                    node.point.x,
-                    node.point.y,
-                    parent_node.point.x,
-                    parent_node.point.y,
-                    1.0,
-                    BLUE,
-                );
+        style.draw_tree(&rrt, edge_coloring);
+
+        if layers.node_dots {
+            for (point, _) in rrt.nodes() {
+                draw_circle(point.x, point.y, style.vertex_radius, style.theme.node);
+            }
+        }
+
+        // Hedefe henüz ulaşılmadıysa hedefe en çok yaklaşan düğümden en yakın hedefe soluk çizgi
+        if !goal_reached {
+            if let Some(best) = rrt.nodes().nth(rrt.best_goal_node).map(|(point, _)| *point) {
+                let nearest_goal = rrt.goals.iter().min_by(|a, b| best.distance(a).total_cmp(&best.distance(b)));
+                if let Some(goal) = nearest_goal {
+                    draw_line(best.x, best.y, goal.x, goal.y, 1.0, Color { a: 0.35, ..style.theme.goal });
+                }
             }
         }
 
         // Hedefe ulaşıldığında en iyi yolu çiz
         if goal_reached {
-            for i in 1..optimal_path.len() {
-                let start = optimal_path[i - 1];
-                let end = optimal_path[i];
-                draw_line(start.x, start.y, end.x, end.y, 2.0, GREEN);
+            style.draw_path(&optimal_path, style.theme.path);
+            if layers.spline {
+                style.draw_path(&rrt.spline_path(&optimal_path, SPLINE_SAMPLES_PER_SEGMENT), style.theme.spline);
+            }
+        }
+
+        // Hareketli engeller yol bulunmadan t = 0 anında, bulunduktan sonra yol boyunca ilerleyen
+        // robotla eşzamanlı olarak döngü halinde çizilir
+        if !rrt.dynamic_obstacles.is_empty() {
+            let goal = rrt.goal_node().filter(|_| goal_reached).filter(|&g| rrt.node(g).time > 0.0);
+            let t = goal.map_or(0.0, |g| (now as f32) % rrt.node(g).time);
+            for obstacle in &rrt.dynamic_obstacles {
+                style.draw_obstacle(&obstacle.at(t), rrt.robot_radius());
             }
+            if let Some(goal) = goal {
+                let robot = rrt.position_at(goal, t);
+                style.draw_robot(&rrt.robot_shape, rrt.robot_radius(), robot, style.theme.highlight);
+            }
+        }
+
+        // Karşılaştırma açıksa A* yolunu çiz
+        let astar = astar_path.as_ref().and_then(Option::as_ref).filter(|_| show_astar);
+        if let Some(path) = astar {
+            style.draw_path(path, style.theme.reference_path);
         }
 
-        // Başlangıç ve hedef noktalarını göster
-        draw_circle(rrt.nodes[0].point.x, rrt.nodes[0].point.y, 5.0, GREEN);
-        draw_circle(rrt.goal.x, rrt.goal.y, 5.0, RED);
+        // Seçili düğümü ve köke giden kenar zincirini vurgula
+        if let Some(index) = hovered {
+            let mut current = index;
+            while let Some(parent) = rrt.node(current).parent {
+                let (a, b) = (rrt.node(current).point, rrt.node(parent).point);
+                draw_line(a.x, a.y, b.x, b.y, style.path_width, style.theme.highlight);
+                current = parent;
+            }
+            let p = rrt.node(index).point;
+            draw_circle(p.x, p.y, style.node_radius * 0.6, style.theme.highlight);
+        }
+
+        // Hata ayıklama katmanları
+        if layers.rejected {
+            for &(p, at) in &rejected {
+                let fade = 1.0 - ((now - at) / REJECTED_FADE_SECS) as f32;
+                draw_circle(p.x, p.y, 2.0, Color { a: 0.5 * fade, ..style.theme.rejected });
+            }
+        }
+        if layers.goal_region {
+            for goal in &rrt.goals {
+                draw_circle_lines(goal.x, goal.y, rrt.goal_threshold(), 1.0, style.theme.goal);
+            }
+        }
+        if layers.sample {
+            if let Some(step) = rrt.last_step {
+                if let Some((nearest, _)) = rrt.nodes().nth(step.nearest) {
+                    draw_line(nearest.x, nearest.y, step.sample.x, step.sample.y, 1.0, style.theme.sample);
+                }
+                let color = if step.accepted { style.theme.accepted } else { style.theme.rejected };
+                draw_circle(step.steered.x, step.steered.y, 2.5, color);
+                draw_circle(step.sample.x, step.sample.y, 3.0, style.theme.sample);
+            }
+        }
+
+        // Başlangıç ve hedef noktalarını göster; sürüklenen hedef imlecin altında çizilir
+        let mut goals = rrt.goals.clone();
+        if dragging_goal {
+            goals[0] = cursor_point;
+            draw_circle_lines(cursor.x, cursor.y, rrt.goal_threshold(), 1.0, style.theme.goal);
+        }
+        style.draw_endpoints(&rrt.starts, &goals);
+        // Çokgen robotun başlangıçtaki yönü ve boyutu gösterilir
+        if let RobotShape::Polygon(_) = rrt.robot_shape {
+            style.draw_robot(&rrt.robot_shape, rrt.robot_radius(), rrt.starts[0], style.theme.start);
+        }
+
+        // Yazılar kameradan bağımsız olarak ekran koordinatlarında çizilir
+        set_default_camera();
+        draw_text(layers.hud_text(), 10.0, 20.0, 18.0, style.theme.text);
+        draw_text(format!("E edges: {}", edge_coloring.name()), 10.0, 38.0, 18.0, style.theme.text);
+        let cap = match rrt.max_nodes {
+            Some(_) if rrt.saturated => " (saturated)".to_string(),
+            Some(_) if rrt.nodes_pruned > 0 => format!(" ({} pruned)", rrt.nodes_pruned),
+            Some(cap) => format!(" / {}", cap),
+            None => String::new(),
+        };
+        let budget = if budget_exhausted { "  time budget exhausted" } else { "" };
+        // Çok yüksek ret oranı haritanın çok dolu veya robot yarıçapının çok büyük olduğunu gösterir
+        let rejected_percent = if rrt.samples_total == 0 { 0.0 } else { 100.0 * rrt.samples_rejected as f32 / rrt.samples_total as f32 };
+        let coverage = rrt.coverage_fraction().map_or_else(String::new, |c| format!("  coverage: {:.1}%", 100.0 * c));
+        let cost_limit = rrt.max_path_cost.map_or_else(String::new, |limit| format!("  over cost {:.0}: {}", limit, rrt.cost_limit_rejections));
+        let rate = format!(
+            "FPS: {}  iterations/frame: {} (PgUp/PgDn)  nodes: {}{}  rejected: {:.1}%{}{}{}",
+            get_fps(),
+            iterations_per_frame,
+            rrt.node_count(),
+            cap,
+            rejected_percent,
+            coverage,
+            cost_limit,
+            budget
+        );
+        draw_text(rate, 10.0, 56.0, 18.0, style.theme.text);
+        if show_heatmap {
+            let total = rrt.heatmap.as_ref().map_or(0, SampleHeatmap::total);
+            draw_text(format!("H heatmap: {} samples (X clears)", total), 10.0, 74.0, 18.0, style.theme.text);
+        }
+        if !goal_reached {
+            let progress = format!(
+                "best distance to goal: {:.1} (iteration {})",
+                rrt.best_goal_distance, rrt.best_goal_iteration
+            );
+            draw_text(progress, 10.0, 110.0, 18.0, style.theme.text);
+        }
+        if let Some((message, at)) = &parameter_flash {
+            if get_time() - at < PARAMETER_FLASH_SECS {
+                draw_text(message, 10.0, 92.0, 18.0, style.theme.text);
+            }
+        }
+        if goal_reached {
+            let clearance = path_clearance(&optimal_path, &rrt.obstacles);
+            let clearance = if clearance.is_finite() { format!("{:.1}", clearance) } else { "-".to_string() };
+            let y = screen_height() - if show_astar { 50.0 } else { 10.0 };
+            draw_text(format!("min clearance: {}", clearance), 10.0, y, 20.0, style.theme.path_label);
+        }
+        if show_astar {
+            let rrt_length = if goal_reached {
+                format!("{:.1}", optimal_path.length())
+            } else {
+                "-".to_string()
+            };
+            let astar_length = match astar {
+                Some(path) => format!("{:.1}", path_length(path)),
+                None => "no path".to_string(),
+            };
+            draw_text(format!("RRT: {}", rrt_length), 10.0, screen_height() - 30.0, 20.0, style.theme.path_label);
+            draw_text(format!("A*: {}", astar_length), 10.0, screen_height() - 10.0, 20.0, style.theme.reference_path);
+        }
+
+        // Ormanda her kökün hedefe ulaşıp ulaşmadığı ve kendi ağacındaki en ucuz yol
+        if rrt.starts.len() > 1 {
+            for report in rrt.root_reports() {
+                let line = match (report.reached_at, report.cost) {
+                    (Some(at), Some(cost)) => format!("root {}: reached at iteration {}, cost {:.1}", report.root, at, cost),
+                    (None, Some(cost)) => format!("root {}: cost {:.1}", report.root, cost),
+                    (Some(at), None) => format!("root {}: reached at iteration {}, path pruned", report.root, at),
+                    (None, None) => format!("root {}: not reached", report.root),
+                };
+                let y = 20.0 + 18.0 * report.root as f32;
+                draw_text(line, screen_width() - 330.0, y, 18.0, ROOT_COLORS[report.root % ROOT_COLORS.len()]);
+            }
+        }
+
+        if let Some(index) = hovered {
+            draw_node_tooltip(&rrt, index, mouse, &style.theme);
+        }
+
+        // Kareler çizim bittikten sonra yakalanır; hedefe ulaşıldığında son kare eklenip kayıt kapatılır
+        if let Some(active) = capture.as_mut() {
+            if just_reached {
+                active.capture_now(iteration);
+                finish_capture(&mut capture);
+            } else if planned {
+                active.capture(iteration);
+            }
+        }
+        frame += 1;
+        if let Some(active) = gif_recording.as_mut() {
+            if just_reached {
+                active.capture_now(frame);
+                finish_capture(&mut gif_recording);
+            } else {
+                active.capture(frame);
+            }
+        }
 
         next_frame().await;
     }
@@ -0,0 +1,250 @@
+// Deney haritalarını elle kurmak yerine tohumla yeniden üretilebilen harita üreteçleri
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::config::RRTConfig;
+use crate::obstacle::{Bounds, Obstacle};
+use crate::rrt::{seeded_rng, Point};
+
+// Rastgele engel yerleştirirken engel başına yapılan en fazla deneme
+const CLUTTER_ATTEMPTS_PER_OBSTACLE: usize = 100;
+
+// Senaryo dosyasındaki `[clutter]` tablosu
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct ClutterSpec {
+    pub count: usize,
+    pub min_size: f32,
+    pub max_size: f32,
+    pub seed: Option<u64>, // Verilmezse senaryonun tohumu kullanılır
+}
+
+// Senaryo dosyasındaki `[maze]` tablosu
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct MazeSpec {
+    pub cols: usize,
+    pub rows: usize,
+    pub wall_thickness: f32,
+    pub seed: Option<u64>, // Verilmezse senaryonun tohumu kullanılır
+}
+
+// Engeli çevreleyen daire; örtüşme kontrolünü şekilden bağımsız ve temkinli yapmak için kullanılır
+fn bounding_circle(obstacle: &Obstacle) -> (Point, f32) {
+    match *obstacle {
+        Obstacle::Circle { center, radius } => (center, radius),
+        Obstacle::Rect { min, max } => {
+            let center = Point { x: (min.x + max.x) / 2.0, y: (min.y + max.y) / 2.0 };
+            (center, center.distance(&max))
+        }
+    }
+}
+
+// Alan içine birbiriyle örtüşmeyen `n` adet daire veya dikdörtgen yerleştirir. Hiçbir engel
+// başlangıç ya da hedefin `min_size / 2` yakınına girmez. Yer bulunamazsa daha az engel döner;
+// alanın genişliği veya yüksekliği yoksa hiç engel yerleştirilmez.
+pub fn random_clutter(
+    bounds: &Bounds,
+    start: &Point,
+    goal: &Point,
+    n: usize,
+    min_size: f32,
+    max_size: f32,
+    seed: u64,
+) -> Vec<Obstacle> {
+    if !(bounds.min_x < bounds.max_x && bounds.min_y < bounds.max_y) {
+        return Vec::new();
+    }
+    let mut rng: StdRng = seeded_rng(Some(seed));
+    let max_size = max_size.max(min_size);
+    let margin = min_size / 2.0;
+    let mut obstacles: Vec<Obstacle> = Vec::with_capacity(n);
+    for _ in 0..n * CLUTTER_ATTEMPTS_PER_OBSTACLE {
+        if obstacles.len() == n {
+            break;
+        }
+        let size = if max_size > min_size { rng.gen_range(min_size..=max_size) } else { min_size };
+        let x = rng.gen_range(bounds.min_x..bounds.max_x);
+        let y = rng.gen_range(bounds.min_y..bounds.max_y);
+        let candidate = if rng.gen_bool(0.5) {
+            Obstacle::Circle { center: Point { x, y }, radius: size / 2.0 }
+        } else {
+            let aspect = rng.gen_range(0.5..=1.0);
+            let (w, h) = if rng.gen_bool(0.5) { (size, size * aspect) } else { (size * aspect, size) };
+            Obstacle::Rect { min: Point { x, y }, max: Point { x: x + w, y: y + h } }
+        };
+        if candidate.signed_distance(start) <= margin || candidate.signed_distance(goal) <= margin {
+            continue;
+        }
+        let (center, radius) = bounding_circle(&candidate);
+        let overlaps = obstacles.iter().any(|other| {
+            let (other_center, other_radius) = bounding_circle(other);
+            center.distance(&other_center) <= radius + other_radius
+        });
+        if !overlaps {
+            obstacles.push(candidate);
+        }
+    }
+    obstacles
+}
+
+// Alanı `cols x rows` hücreye bölüp rastgele derinlik öncelikli gezinmeyle kusursuz bir labirent
+// üretir: her hücre çifti arasında tam olarak bir yol vardır. Açılmayan hücre sınırları
+// `wall_thickness` kalınlığında dikdörtgen duvarlar olarak döner; alanın dış kenarı duvar sayılmaz.
+pub fn maze(bounds: &Bounds, cols: usize, rows: usize, wall_thickness: f32, seed: u64) -> Vec<Obstacle> {
+    let (cols, rows) = (cols.max(1), rows.max(1));
+    let mut rng: StdRng = seeded_rng(Some(seed));
+    // Hücrenin sağındaki ve altındaki geçitlerin açık olup olmadığı
+    let mut open_right = vec![false; cols * rows];
+    let mut open_down = vec![false; cols * rows];
+    let mut visited = vec![false; cols * rows];
+    let mut stack = vec![0usize];
+    visited[0] = true;
+    while let Some(&cell) = stack.last() {
+        let (c, r) = (cell % cols, cell / cols);
+        let mut unvisited = Vec::with_capacity(4);
+        if c > 0 && !visited[cell - 1] {
+            unvisited.push(cell - 1);
+        }
+        if c + 1 < cols && !visited[cell + 1] {
+            unvisited.push(cell + 1);
+        }
+        if r > 0 && !visited[cell - cols] {
+            unvisited.push(cell - cols);
+        }
+        if r + 1 < rows && !visited[cell + cols] {
+            unvisited.push(cell + cols);
+        }
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = unvisited[rng.gen_range(0..unvisited.len())];
+        match next {
+            n if n + 1 == cell => open_right[n] = true,
+            n if n == cell + 1 => open_right[cell] = true,
+            n if n + cols == cell => open_down[n] = true,
+            _ => open_down[cell] = true,
+        }
+        visited[next] = true;
+        stack.push(next);
+    }
+
+    let cw = (bounds.max_x - bounds.min_x) / cols as f32;
+    let ch = (bounds.max_y - bounds.min_y) / rows as f32;
+    let half = wall_thickness / 2.0;
+    let mut walls = Vec::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let cell = r * cols + c;
+            let (x0, y0) = (bounds.min_x + c as f32 * cw, bounds.min_y + r as f32 * ch);
+            // Duvarlar köşelerde boşluk kalmaması için yarım kalınlık kadar uzatılır
+            if c + 1 < cols && !open_right[cell] {
+                walls.push(Obstacle::Rect {
+                    min: Point { x: x0 + cw - half, y: y0 - half },
+                    max: Point { x: x0 + cw + half, y: y0 + ch + half },
+                });
+            }
+            if r + 1 < rows && !open_down[cell] {
+                walls.push(Obstacle::Rect {
+                    min: Point { x: x0 - half, y: y0 + ch - half },
+                    max: Point { x: x0 + cw + half, y: y0 + ch + half },
+                });
+            }
+        }
+    }
+    walls
+}
+
+// Senaryonun elle verilen engellerine `[clutter]` ve `[maze]` tablolarından üretilenleri ekler.
+// Labirent duvarlarından herhangi bir başlangıcı veya hedefi örtenler çıkarılır; duvar çıkarmak
+// yalnızca yeni geçit açtığından labirent bağlı kalır. Ek başlangıç ve hedefleri (ek robotlarınkiler
+// dahil) örten rastgele engeller de atılır.
+pub fn scenario_obstacles(config: &RRTConfig, start: &Point, goal: &Point) -> Vec<Obstacle> {
+    let mut obstacles = config.obstacles.clone();
+    let robots = config.robots.iter().flat_map(|r| [&r.start, &r.goal]);
+    let extras = || config.extra_goals.iter().chain(&config.extra_starts).chain(robots.clone());
+    let fallback_seed = config.seed.unwrap_or(0);
+    if let Some(spec) = config.maze {
+        let walls = maze(&config.bounds, spec.cols, spec.rows, spec.wall_thickness, spec.seed.unwrap_or(fallback_seed));
+        obstacles.extend(walls.into_iter().filter(|w| {
+            !w.contains(start) && !w.contains(goal) && !extras().any(|p| w.contains(p))
+        }));
+    }
+    if let Some(spec) = config.clutter {
+        let clutter = random_clutter(
+            &config.bounds,
+            start,
+            goal,
+            spec.count,
+            spec.min_size,
+            spec.max_size,
+            spec.seed.unwrap_or(fallback_seed),
+        );
+        obstacles.extend(clutter.into_iter().filter(|o| !extras().any(|p| o.contains(p))));
+    }
+    obstacles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clutter_in_an_empty_area_places_nothing() {
+        let (start, goal) = (Point { x: 0.0, y: 0.0 }, Point { x: 10.0, y: 0.0 });
+        let flat = Bounds::new(0.0, 100.0, 5.0, 5.0);
+        assert!(random_clutter(&flat, &start, &goal, 10, 2.0, 8.0, 1).is_empty());
+    }
+
+    #[test]
+    fn clutter_keeps_clear_of_start_goal_and_other_obstacles() {
+        let bounds = Bounds::new(0.0, 400.0, 0.0, 400.0);
+        let (start, goal) = (Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 });
+        for seed in 0..20 {
+            let clutter = random_clutter(&bounds, &start, &goal, 30, 10.0, 40.0, seed);
+            assert!(!clutter.is_empty());
+            for (i, obstacle) in clutter.iter().enumerate() {
+                assert!(obstacle.signed_distance(&start) > 5.0 && obstacle.signed_distance(&goal) > 5.0, "seed {} covers an endpoint", seed);
+                let (center, radius) = bounding_circle(obstacle);
+                assert!(clutter[i + 1..].iter().all(|other| {
+                    let (other_center, other_radius) = bounding_circle(other);
+                    center.distance(&other_center) > radius + other_radius
+                }));
+            }
+        }
+    }
+
+    #[test]
+    fn maze_free_space_is_a_single_connected_region() {
+        let bounds = Bounds::new(0.0, 200.0, 0.0, 160.0);
+        let (cols, rows) = (5, 4);
+        for seed in 0..5 {
+            let walls = maze(&bounds, cols, rows, 4.0, seed);
+            // Kusursuz labirentte hücre sayısından bir eksik geçit açılır
+            assert_eq!(walls.len(), (cols - 1) * rows + cols * (rows - 1) - (cols * rows - 1));
+            // Serbest alan 2 piksellik ızgarada taşma doldurmayla gezilir
+            let (w, h) = (100, 80);
+            let free: Vec<bool> = (0..w * h)
+                .map(|i| {
+                    let p = Point { x: (i % w) as f32 * 2.0 + 1.0, y: (i / w) as f32 * 2.0 + 1.0 };
+                    !walls.iter().any(|wall| wall.contains(&p))
+                })
+                .collect();
+            let mut reached = vec![false; w * h];
+            let mut stack = vec![0];
+            reached[0] = true;
+            while let Some(i) = stack.pop() {
+                let (x, y) = (i % w, i / w);
+                let neighbors = [(x > 0).then(|| i - 1), (x + 1 < w).then_some(i + 1), (y > 0).then(|| i - w), (y + 1 < h).then_some(i + w)];
+                for n in neighbors.into_iter().flatten() {
+                    if free[n] && !reached[n] {
+                        reached[n] = true;
+                        stack.push(n);
+                    }
+                }
+            }
+            assert!(free[0]);
+            assert!(free.iter().zip(&reached).all(|(&f, &r)| f == r), "seed {} leaves unreachable free space", seed);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+// Planlayıcı olaylarını dinleyen gözlemciler: kayıt ve analiz katmanları planlayıcıyı değiştirmeden
+// ağacın büyümesini izleyebilir
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+// Yönlendirilen noktanın ağaca eklenmeme nedeni
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RejectReason {
+    Collision, // Kenar (tembel modda uç nokta) bir engele çarpıyor veya alan dışına çıkıyor
+    Clearance, // Açıklık maliyeti açıkken engele yakın kenar olasılıkla reddedildi
+    BranchAndBound, // Noktanın alt sınırı bulunan en iyi yolun maliyetini aşıyor
+    NodeCap, // Ağaç düğüm sınırında ve yer açılamadı
+    CostLimit, // Yeni düğümün kökten maliyeti `max_path_cost` bütçesini aşıyor
+}
+
+impl RejectReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RejectReason::Collision => "collision",
+            RejectReason::Clearance => "clearance",
+            RejectReason::BranchAndBound => "branch_and_bound",
+            RejectReason::NodeCap => "node_cap",
+            RejectReason::CostLimit => "cost_limit",
+        }
+    }
+}
+
+// `RRT::set_observer` ile verilen gözlemcinin çağrılan yöntemleri; hepsi varsayılan olarak bir şey
+// yapmaz, böylece gözlemci yalnızca ilgilendiği olayları uygular. Gözlemci verilmemişse planlayıcı
+// hiçbir yöntemi çağırmaz. Planlayıcı toplu genişletmede iş parçacıkları arasında paylaşıldığından
+// gözlemci `Send + Sync` olmalıdır.
+pub trait PlannerObserver<T: Scalar = f32>: Send + Sync {
+    // Çekilen her örnek (hedef yanlılığı, ara nokta ve sınır örnekleri dahil)
+    fn on_sample(&mut self, _sample: &Point<T>) {}
+
+    // `add_node` ile eklenen düğüm; tekrar oynatmada uygulanan düğümler de bildirilir
+    fn on_node_added(&mut self, _index: usize, _parent: usize) {}
+
+    // Bu iterasyonda ağaca eklenmeyen yönlendirilmiş nokta
+    fn on_rejected(&mut self, _point: &Point<T>, _reason: RejectReason) {}
+
+    // Hedef bölgesine ilk ulaşan düğüm eklendi
+    fn on_goal_reached(&mut self, _index: usize) {}
+
+    // Düğümün ebeveyni değişti. Ağaç eklenen düğümleri yeniden bağlamadığından şu anda
+    // çağrılmaz; yeniden bağlayan planlayıcılar için ayrılmıştır.
+    fn on_rewire(&mut self, _child: usize, _old_parent: usize, _new_parent: usize) {}
+
+    // Tamponlanmış olayları yazar; yazma sırasında oluşan ilk hata burada döner
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Her olayı tek satırlık bir JSON nesnesi olarak yazan gözlemci (JSON Lines). Sonlu olmayan
+// koordinatlar JSON'da gösterilemediğinden `null` yazılır. Yazma hatasından sonra yeni olay
+// yazılmaz; hata `flush` ile alınır.
+pub struct JsonLinesObserver<W: Write> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl JsonLinesObserver<BufWriter<File>> {
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(JsonLinesObserver::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write> JsonLinesObserver<W> {
+    pub fn new(writer: W) -> Self {
+        JsonLinesObserver { writer, error: None }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_line(&mut self, line: std::fmt::Arguments<'_>) {
+        if self.error.is_none() {
+            if let Err(e) = writeln!(self.writer, "{}", line) {
+                self.error = Some(e);
+            }
+        }
+    }
+}
+
+pub(crate) fn json_number<T: Scalar>(value: T) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+impl<T: Scalar, W: Write + Send + Sync> PlannerObserver<T> for JsonLinesObserver<W> {
+    fn on_sample(&mut self, sample: &Point<T>) {
+        self.write_line(format_args!(
+            r#"{{"event":"sample","x":{},"y":{}}}"#,
+            json_number(sample.x),
+            json_number(sample.y)
+        ));
+    }
+
+    fn on_node_added(&mut self, index: usize, parent: usize) {
+        self.write_line(format_args!(r#"{{"event":"node_added","index":{},"parent":{}}}"#, index, parent));
+    }
+
+    fn on_rejected(&mut self, point: &Point<T>, reason: RejectReason) {
+        self.write_line(format_args!(
+            r#"{{"event":"rejected","x":{},"y":{},"reason":"{}"}}"#,
+            json_number(point.x),
+            json_number(point.y),
+            reason.as_str()
+        ));
+    }
+
+    fn on_goal_reached(&mut self, index: usize) {
+        self.write_line(format_args!(r#"{{"event":"goal_reached","index":{}}}"#, index));
+    }
+
+    fn on_rewire(&mut self, child: usize, old_parent: usize, new_parent: usize) {
+        self.write_line(format_args!(
+            r#"{{"event":"rewire","child":{},"old_parent":{},"new_parent":{}}}"#,
+            child, old_parent, new_parent
+        ));
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => self.writer.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::obstacle::Obstacle;
+    use crate::rrt::RRT;
+
+    #[test]
+    fn json_lines_observer_writes_one_event_per_line() {
+        #[derive(Clone, Default)]
+        struct SharedBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl std::io::Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let p = |x: f32, y: f32| Point { x, y };
+        let buffer = SharedBuffer::default();
+        let mut rrt: RRT = RRT::new(p(100.0, 100.0), p(115.0, 100.0), 10.0, 2.0);
+        rrt.obstacles.push(Obstacle::Circle { center: p(100.0, 110.0), radius: 4.0 });
+        rrt.sample_source = Some(Box::new(vec![p(100.0, 50.0), p(100.0, 121.0), p(115.0, 100.0), p(115.0, 100.0)].into_iter()));
+        rrt.set_observer(Some(Box::new(JsonLinesObserver::new(buffer.clone()))));
+        assert_eq!(rrt.run(10).count(), 4);
+        rrt.take_observer().unwrap().flush().unwrap();
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let expected = [
+            r#"{"event":"sample","x":100,"y":50}"#,
+            r#"{"event":"node_added","index":1,"parent":0}"#,
+            r#"{"event":"sample","x":100,"y":121}"#,
+            r#"{"event":"rejected","x":100,"y":110,"reason":"collision"}"#,
+            r#"{"event":"sample","x":115,"y":100}"#,
+            r#"{"event":"node_added","index":2,"parent":0}"#,
+            r#"{"event":"sample","x":115,"y":100}"#,
+            r#"{"event":"node_added","index":3,"parent":2}"#,
+            r#"{"event":"goal_reached","index":3}"#,
+        ];
+        assert_eq!(output.lines().collect::<Vec<_>>(), expected);
+
+        let mut observer = JsonLinesObserver::new(Vec::new());
+        PlannerObserver::<f32>::on_rejected(&mut observer, &p(f32::NAN, 1.5), RejectReason::NodeCap);
+        assert_eq!(String::from_utf8(observer.into_inner()).unwrap(), "{\"event\":\"rejected\",\"x\":null,\"y\":1.5,\"reason\":\"node_cap\"}\n");
+    }
+}
@@ -0,0 +1,532 @@
+use std::cmp::Ordering;
+
+use num_traits::clamp;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+use crate::state::State;
+
+// Örnekleme ve çarpışma kontrolü için kullanılan dikdörtgen çalışma alanı
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct Bounds<T = f32> {
+    pub min_x: T,
+    pub max_x: T,
+    pub min_y: T,
+    pub max_y: T,
+}
+
+impl<T: Scalar> Bounds<T> {
+    pub fn new(min_x: T, max_x: T, min_y: T, max_y: T) -> Self {
+        Bounds { min_x, max_x, min_y, max_y }
+    }
+
+    // Noktanın alan içinde olup olmadığını kontrol eder
+    pub fn contains(&self, p: &Point<T>) -> bool {
+        p.x >= self.min_x && p.x <= self.max_x && p.y >= self.min_y && p.y <= self.max_y
+    }
+}
+
+impl<T: Scalar> Default for Bounds<T> {
+    // Görselleştirmenin bugüne kadar kullandığı 400x400 alan
+    fn default() -> Self {
+        Bounds::new(T::zero(), T::cast(400.0), T::zero(), T::cast(400.0))
+    }
+}
+
+// Haritadaki engeller: daire veya eksen hizalı dikdörtgen
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Obstacle<T = f32> {
+    Circle { center: Point<T>, radius: T },
+    Rect { min: Point<T>, max: Point<T> },
+}
+
+impl<T: Scalar> Obstacle<T> {
+    // Noktanın engelin içinde kalıp kalmadığını kontrol eder
+    pub fn contains(&self, p: &Point<T>) -> bool {
+        match self {
+            Obstacle::Circle { center, radius } => center.distance(p) <= *radius,
+            Obstacle::Rect { min, max } => {
+                p.x >= min.x && p.x <= max.x && p.y >= min.y && p.y <= max.y
+            }
+        }
+    }
+
+    // Noktanın engel sınırına işaretli uzaklığı: dışarıda pozitif, sınırda sıfır, içeride negatif
+    pub fn signed_distance(&self, p: &Point<T>) -> T {
+        match self {
+            Obstacle::Circle { center, radius } => center.distance(p) - *radius,
+            Obstacle::Rect { min, max } => {
+                // Eksen başına kutu sınırından taşma miktarı (içeride negatif)
+                let dx = (min.x - p.x).max(p.x - max.x);
+                let dy = (min.y - p.y).max(p.y - max.y);
+                let outside = (dx.max(T::zero()).powi(2) + dy.max(T::zero()).powi(2)).sqrt();
+                let inside = dx.max(dy).min(T::zero());
+                outside + inside
+            }
+        }
+    }
+
+    // İşaretli uzaklığın artış yönü: engelden dışarı bakan birim vektör. Dikdörtgenin içinde en
+    // yakın kenarın normalidir; çemberin merkezinde ve köşe eşitliklerinde yön `x` ekseni
+    // (ve eksenlerden ilki) olarak seçilir.
+    pub fn distance_gradient(&self, p: &Point<T>) -> Point<T> {
+        let x_axis = Point { x: T::one(), y: T::zero() };
+        match self {
+            Obstacle::Circle { center, .. } => (*p - *center).normalize().unwrap_or(x_axis),
+            Obstacle::Rect { min, max } => {
+                let nearest = Point { x: clamp(p.x, min.x, max.x), y: clamp(p.y, min.y, max.y) };
+                if let Some(outward) = (*p - nearest).normalize() {
+                    return outward;
+                }
+                // İçeride: en az taşan eksendeki en yakın kenara doğru
+                let faces = [
+                    (p.x - min.x, -x_axis),
+                    (max.x - p.x, x_axis),
+                    (p.y - min.y, Point { x: T::zero(), y: -T::one() }),
+                    (max.y - p.y, Point { x: T::zero(), y: T::one() }),
+                ];
+                faces.iter().fold(faces[0], |best, &face| if face.0 < best.0 { face } else { best }).1
+            }
+        }
+    }
+
+    // Noktanın engele olan en kısa mesafesi; nokta engelin içindeyse sıfırdır
+    pub fn distance(&self, p: &Point<T>) -> T {
+        self.signed_distance(p).max(T::zero())
+    }
+
+    // Aynı engelin `offset` kadar kaydırılmış hali
+    pub fn translated(&self, offset: Point<T>) -> Obstacle<T> {
+        match *self {
+            Obstacle::Circle { center, radius } => Obstacle::Circle { center: center + offset, radius },
+            Obstacle::Rect { min, max } => Obstacle::Rect { min: min + offset, max: max + offset },
+        }
+    }
+
+    // Dışbükey çokgenle (köşeleri saat yönünün tersine sıralı) kesişiyor veya ona değiyor mu. Tek
+    // noktalı ve iki noktalı (doğru parçasına dönüşmüş) çokgenler de kabul edilir.
+    pub fn intersects_convex(&self, hull: &[Point<T>]) -> bool {
+        match (self, hull) {
+            (_, []) => false,
+            (_, [p]) => self.contains(p),
+            (_, [a, b]) => self.segment_distance(a, b) <= T::zero(),
+            (Obstacle::Circle { center, radius }, _) => {
+                polygon_contains(hull, center) || polygon_edges(hull).any(|(u, v)| point_segment_distance(center, u, v) <= *radius)
+            }
+            (Obstacle::Rect { min, max }, _) => {
+                // Ayırıcı eksen teoremi: iki dışbükey çokgen ancak bir kenarlarının normaline
+                // izdüşümleri ayrık kalıyorsa kesişmez. Dikdörtgenin normalleri eksenlerdir.
+                let corners = [*min, Point { x: max.x, y: min.y }, *max, Point { x: min.x, y: max.y }];
+                let axes = [Point { x: T::one(), y: T::zero() }, Point { x: T::zero(), y: T::one() }];
+                let normals = polygon_edges(hull).map(|(u, v)| Point { x: u.y - v.y, y: v.x - u.x });
+                let separated = |axis: Point<T>| {
+                    let (a_min, a_max) = project(&corners, &axis);
+                    let (b_min, b_max) = project(hull, &axis);
+                    a_max < b_min || b_max < a_min
+                };
+                !axes.into_iter().chain(normals).any(separated)
+            }
+        }
+    }
+
+    // Doğru parçasının engele olan en kısa mesafesi; parça engele değiyor veya giriyorsa sıfırdır
+    pub fn segment_distance(&self, a: &Point<T>, b: &Point<T>) -> T {
+        match self {
+            Obstacle::Circle { center, radius } => (point_segment_distance(center, a, b) - *radius).max(T::zero()),
+            Obstacle::Rect { min, max } => {
+                let corners = [*min, Point { x: max.x, y: min.y }, *max, Point { x: min.x, y: max.y }];
+                polygon_segment_distance(&corners, a, b)
+            }
+        }
+    }
+}
+
+// Robotun çarpışma modeli. Disk, `robot_radius` yarıçaplı dairedir (yarıçap sıfırsa nokta).
+// Çokgen, düğüm noktasına göre verilen köşelerdir; robot dönmeden yalnızca öteleme yaptığından
+// yönü sabittir. Dışbükey olmayan çokgenler dışbükey zarflarıyla kontrol edilir.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "vertices", rename_all = "lowercase")]
+pub enum RobotShape<T = f32> {
+    #[default]
+    Disk,
+    Polygon(Vec<Point<T>>),
+}
+
+impl<T: Scalar> RobotShape<T> {
+    // Robotun `at` noktasındaki kapladığı alanın dışbükey zarfı; disk için `None`
+    pub fn footprint(&self, at: &Point<T>) -> Option<Vec<Point<T>>> {
+        self.swept(at, at)
+    }
+
+    // Robot `from` noktasından `to` noktasına düz ilerlerken taradığı alan. Dışbükey bir çokgenin
+    // bir doğru parçası boyunca taradığı alan iki uçtaki kopyalarının dışbükey zarfıdır; disk için
+    // `None` döner.
+    pub fn swept(&self, from: &Point<T>, to: &Point<T>) -> Option<Vec<Point<T>>> {
+        match self {
+            RobotShape::Disk => None,
+            RobotShape::Polygon(vertices) => {
+                let mut points: Vec<Point<T>> = vertices.iter().map(|&v| v + *from).collect();
+                if from != to {
+                    points.extend(vertices.iter().map(|&v| v + *to));
+                }
+                Some(convex_hull(points))
+            }
+        }
+    }
+}
+
+// Sabit hızla hareket eden engel: `shape` t = 0 anındaki biçim ve konum, `velocity` saniyedeki
+// yer değiştirmedir
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct DynamicObstacle<T = f32> {
+    pub shape: Obstacle<T>,
+    pub velocity: Point<T>,
+}
+
+impl<T: Scalar> DynamicObstacle<T> {
+    // Engelin `t` anındaki hali
+    pub fn at(&self, t: T) -> Obstacle<T> {
+        self.shape.translated(self.velocity * t)
+    }
+
+    pub fn occupied(&self, p: &Point<T>, t: T) -> bool {
+        self.at(t).contains(p)
+    }
+}
+
+// Noktanın `a`-`b` doğru parçasına en kısa mesafesi
+pub fn point_segment_distance<T: Scalar>(p: &Point<T>, a: &Point<T>, b: &Point<T>) -> T {
+    let ab = *b - *a;
+    let length_squared = ab.length_squared();
+    if length_squared == T::zero() {
+        return p.distance(a);
+    }
+    let t = ((*p - *a).dot(&ab) / length_squared).max(T::zero()).min(T::one());
+    p.distance(&Point::lerp(*a, *b, t))
+}
+
+// `p`'den `q`'ya dönüşün yönü: sola pozitif, sağa negatif, doğrusalda sıfır
+fn orientation<T: Scalar>(p: &Point<T>, q: &Point<T>, r: &Point<T>) -> T {
+    (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)
+}
+
+// İki doğru parçası kesişiyor veya birbirine değiyor mu
+pub fn segments_intersect<T: Scalar>(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> bool {
+    let (d1, d2) = (orientation(c, d, a), orientation(c, d, b));
+    let (d3, d4) = (orientation(a, b, c), orientation(a, b, d));
+    let zero = T::zero();
+    if ((d1 > zero && d2 < zero) || (d1 < zero && d2 > zero)) && ((d3 > zero && d4 < zero) || (d3 < zero && d4 > zero)) {
+        return true;
+    }
+    // Doğrusal durumlarda uç noktanın diğer parçanın üzerinde olup olmadığına bakılır
+    let on_segment = |p: &Point<T>, q: &Point<T>, r: &Point<T>| {
+        r.x >= p.x.min(q.x) && r.x <= p.x.max(q.x) && r.y >= p.y.min(q.y) && r.y <= p.y.max(q.y)
+    };
+    (d1 == zero && on_segment(c, d, a))
+        || (d2 == zero && on_segment(c, d, b))
+        || (d3 == zero && on_segment(a, b, c))
+        || (d4 == zero && on_segment(a, b, d))
+}
+
+// Çokgenin kenarları (son köşeden ilkine dönen kenar dahil)
+fn polygon_edges<T>(vertices: &[Point<T>]) -> impl Iterator<Item = (&Point<T>, &Point<T>)> {
+    vertices.iter().zip(vertices.iter().cycle().skip(1))
+}
+
+// Köşelerin eksen üzerine izdüşümlerinin aralığı (eksenin boyuyla ölçeklenmiş)
+fn project<T: Scalar>(vertices: &[Point<T>], axis: &Point<T>) -> (T, T) {
+    vertices.iter().map(|v| v.dot(axis)).fold((T::infinity(), T::neg_infinity()), |(lo, hi), d| (lo.min(d), hi.max(d)))
+}
+
+// Noktaların saat yönünün tersine sıralı dışbükey zarfı (Andrew'un monoton zincir yöntemi).
+// Doğrusal köşeler atılır; bütün noktalar bir doğru üzerindeyse iki uç, hepsi aynıysa tek nokta döner.
+pub fn convex_hull<T: Scalar>(mut points: Vec<Point<T>>) -> Vec<Point<T>> {
+    points.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal).then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    // Soldan sağa alt zincir, sağdan sola üst zincir; her zincirin son noktası diğerinin ilkidir
+    let chain = |ordered: &mut dyn Iterator<Item = &Point<T>>| {
+        let mut chain: Vec<Point<T>> = Vec::new();
+        for p in ordered {
+            while chain.len() >= 2 && orientation(&chain[chain.len() - 2], &chain[chain.len() - 1], p) <= T::zero() {
+                chain.pop();
+            }
+            chain.push(*p);
+        }
+        chain.pop();
+        chain
+    };
+    let mut hull = chain(&mut points.iter());
+    hull.extend(chain(&mut points.iter().rev()));
+    hull
+}
+
+// Noktanın çokgenin içinde olup olmadığı (ışın atma); çokgenin dışbükey olması gerekmez
+pub fn polygon_contains<T: Scalar>(vertices: &[Point<T>], p: &Point<T>) -> bool {
+    let mut inside = false;
+    for (i, u) in vertices.iter().enumerate() {
+        let v = &vertices[(i + 1) % vertices.len()];
+        if (u.y > p.y) != (v.y > p.y) && p.x < u.x + (p.y - u.y) / (v.y - u.y) * (v.x - u.x) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+// Doğru parçasının köşeleri sırayla verilen çokgene en kısa mesafesi; parça çokgene değiyor veya
+// içindeyse sıfırdır. Ayrık iki parçanın en yakın noktalarından biri her zaman bir uç noktadır,
+// bu yüzden parça uçlarının kenarlara ve köşelerin parçaya mesafelerine bakmak yeterlidir.
+pub fn polygon_segment_distance<T: Scalar>(vertices: &[Point<T>], a: &Point<T>, b: &Point<T>) -> T {
+    if polygon_contains(vertices, a) || polygon_contains(vertices, b) {
+        return T::zero();
+    }
+    let mut best = T::infinity();
+    for (i, u) in vertices.iter().enumerate() {
+        let v = &vertices[(i + 1) % vertices.len()];
+        if segments_intersect(a, b, u, v) {
+            return T::zero();
+        }
+        best = best
+            .min(point_segment_distance(a, u, v))
+            .min(point_segment_distance(b, u, v))
+            .min(point_segment_distance(u, a, b));
+    }
+    best
+}
+
+// Uyarlamalı kenar kontrolünde engele bu kadar yaklaşan noktalar çarpışma sayılır
+const ADAPTIVE_CONTACT_DISTANCE: f64 = 1e-3;
+
+// Çarpışma kontrolü için alan, engeller ve kenar çözünürlüğünü bir araya getiren görünüm.
+// Farklı planlayıcıların aynı çarpışma anlamını paylaşması için kullanılır.
+#[derive(Clone, Copy)]
+pub struct CollisionChecker<'a, T = f32> {
+    pub bounds: &'a Bounds<T>,
+    pub obstacles: &'a [Obstacle<T>],
+    pub resolution: T,
+    pub adaptive: bool, // Kenarlar sabit adım yerine engel mesafesine göre kontrol edilir
+    pub robot_radius: T, // Engeller bu kadar şişirilir; sıfırsa robot nokta kabul edilir
+    pub robot_shape: &'a RobotShape<T>, // Çokgense `robot_radius` yerine robotun kapladığı alan kontrol edilir
+}
+
+impl<T: Scalar> CollisionChecker<'_, T> {
+    // Nokta alan içinde olmalı ve `robot_radius` kadar şişirilmiş hiçbir engelin içine düşmemeli.
+    // Şişirilmiş daire büyür, şişirilmiş dikdörtgenin köşeleri yuvarlanır.
+    // Çokgen robotun bütün köşeleri alanın içinde kalmalı ve hiçbir engele değmemelidir.
+    pub fn is_point_free(&self, p: &Point<T>) -> bool {
+        if let Some(footprint) = self.robot_shape.footprint(p) {
+            return self.is_region_free(&footprint);
+        }
+        self.bounds.contains(p)
+            && !self.obstacles.iter().any(|o| o.signed_distance(p) <= self.robot_radius)
+    }
+
+    // Dışbükey bölge alanın içinde mi ve hiçbir engele değmiyor mu; alan dışbükey olduğundan
+    // köşelerin içeride olması yeterlidir
+    fn is_region_free(&self, hull: &[Point<T>]) -> bool {
+        hull.iter().all(|v| self.bounds.contains(v)) && !self.obstacles.iter().any(|o| o.intersects_convex(hull))
+    }
+
+    // Alan içinde düzgün örneklenen, çarpışmasız bir nokta. `attempts` denemede bulunamazsa
+    // (serbest alan çok küçükse) `None` döner.
+    pub fn random_free_point<R: Rng + ?Sized>(&self, rng: &mut R, attempts: usize) -> Option<Point<T>> {
+        (0..attempts).map(|_| Point::sample_uniform(self.bounds, rng)).find(|p| self.is_point_free(p))
+    }
+
+    // En yakın engel sınırına işaretli uzaklık (engel içinde negatif); engel yoksa sonsuzdur
+    pub fn clearance(&self, p: &Point<T>) -> T {
+        self.obstacles
+            .iter()
+            .map(|o| o.signed_distance(p))
+            .fold(T::infinity(), T::min)
+    }
+
+    // En yakın engelin işaretli uzaklığı ve o engelden dışarı bakan birim yön; engel yoksa `None`
+    pub fn nearest_obstacle(&self, p: &Point<T>) -> Option<(T, Point<T>)> {
+        let mut nearest: Option<(T, &Obstacle<T>)> = None;
+        for obstacle in self.obstacles {
+            let d = obstacle.signed_distance(p);
+            if nearest.is_none_or(|(best, _)| d < best) {
+                nearest = Some((d, obstacle));
+            }
+        }
+        nearest.map(|(d, obstacle)| (d, obstacle.distance_gradient(p)))
+    }
+
+    // En yakın engele olan mesafe; nokta bir engelin içindeyse sıfır, engel yoksa sonsuzdur
+    pub fn distance_to_nearest_obstacle(&self, p: &Point<T>) -> T {
+        self.clearance(p).max(T::zero())
+    }
+
+    // Seçili yönteme göre doğru parçasının çarpışmasız olup olmadığını kontrol eder. Çokgen robotun
+    // taradığı alan örneklenmeden, engellerle tam olarak kesiştirilir.
+    pub fn is_edge_free(&self, from: &Point<T>, to: &Point<T>) -> bool {
+        if let Some(swept) = self.robot_shape.swept(from, to) {
+            return self.is_region_free(&swept);
+        }
+        if self.adaptive {
+            self.is_edge_free_adaptive(from, to)
+        } else {
+            self.is_edge_free_fixed(from, to)
+        }
+    }
+
+    // Doğru parçası boyunca en yakın engel mesafesi kadar ilerler: o yarıçap içinde hiçbir engel
+    // olmadığından aradaki kısım güvenlidir. Açık alanda büyük, engel yakınında küçük adımlar
+    // atıldığı için ince duvarlar atlanmaz.
+    pub fn is_edge_free_adaptive(&self, from: &Point<T>, to: &Point<T>) -> bool {
+        // Alan dışbükey olduğundan iki uç içerideyse parçanın tamamı da içeridedir
+        if !self.bounds.contains(from) || !self.bounds.contains(to) {
+            return false;
+        }
+        let length = from.distance(to);
+        let mut travelled = T::zero();
+        loop {
+            let t = if length > T::zero() { (travelled / length).min(T::one()) } else { T::one() };
+            let p = Point {
+                x: from.x + (to.x - from.x) * t,
+                y: from.y + (to.y - from.y) * t,
+            };
+            let clearance = self.distance_to_nearest_obstacle(&p) - self.robot_radius;
+            if clearance < T::cast(ADAPTIVE_CONTACT_DISTANCE) {
+                return false;
+            }
+            if t >= T::one() {
+                return true;
+            }
+            travelled += clearance;
+        }
+    }
+
+    // Doğru parçasını `resolution` aralıklarla örnekleyerek kontrol eder
+    pub fn is_edge_free_fixed(&self, from: &Point<T>, to: &Point<T>) -> bool {
+        let length = from.distance(to);
+        let steps = (length / self.resolution).ceil().max(T::one()).to_usize().unwrap_or(usize::MAX);
+        (0..=steps).all(|i| {
+            let t = T::cast(i) / T::cast(steps);
+            let p = Point {
+                x: from.x + (to.x - from.x) * t,
+                y: from.y + (to.y - from.y) * t,
+            };
+            self.is_point_free(&p)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RRTConfig;
+    use crate::rrt::RRT;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn swept_polygon_is_the_hull_of_both_ends() {
+        let p = |x: f32, y: f32| Point { x, y };
+        let square = RobotShape::Polygon(vec![p(0.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), p(0.0, 1.0)]);
+        let swept = square.swept(&p(0.0, 0.0), &p(3.0, 3.0)).unwrap();
+        assert_eq!(swept, vec![p(0.0, 0.0), p(1.0, 0.0), p(4.0, 3.0), p(4.0, 4.0), p(3.0, 4.0), p(0.0, 1.0)]);
+        assert_eq!(RobotShape::<f32>::Disk.swept(&p(0.0, 0.0), &p(1.0, 0.0)), None);
+        assert_eq!(convex_hull(vec![p(2.0, 2.0), p(0.0, 0.0), p(1.0, 1.0)]), vec![p(0.0, 0.0), p(2.0, 2.0)]);
+        // Taranan alanın kutusu dikdörtgene değse de köşegen kenarın normali onları ayırır
+        let corner = Obstacle::Rect { min: p(3.0, 0.0), max: p(5.0, 1.5) };
+        assert!(!corner.intersects_convex(&swept));
+        assert!(Obstacle::Rect { min: p(3.0, 0.0), max: p(5.0, 2.5) }.intersects_convex(&swept));
+        assert!(Obstacle::Circle { center: p(2.0, 2.0), radius: 0.1 }.intersects_convex(&swept));
+        assert!(!Obstacle::Circle { center: p(3.5, 1.0), radius: 0.5 }.intersects_convex(&swept));
+
+        let config = RRTConfig::from_toml_str(
+            "robot_shape = { type = \"polygon\", vertices = [{ x = 0.0, y = 0.0 }, { x = 1.0, y = 0.0 }, { x = 1.0, y = 1.0 }, { x = 0.0, y = 1.0 }] }",
+        )
+        .expect("config parses");
+        assert_eq!(config.robot_shape, square);
+        assert_eq!(RRTConfig::default().robot_shape, RobotShape::Disk);
+        assert!(RRTConfig::from_toml_str("robot_shape = { type = \"polygon\", vertices = [] }").is_err());
+    }
+
+    #[test]
+    fn distance_gradients_point_away_from_each_obstacle_type() {
+        let circle = Obstacle::Circle { center: Point { x: 0.0, y: 0.0 }, radius: 10.0 };
+        assert_eq!(circle.distance_gradient(&Point { x: 0.0, y: 30.0 }), Point { x: 0.0, y: 1.0 });
+        assert_eq!(circle.distance_gradient(&Point { x: -3.0, y: 0.0 }), Point { x: -1.0, y: 0.0 });
+        assert_eq!(circle.distance_gradient(&Point { x: 0.0, y: 0.0 }), Point { x: 1.0, y: 0.0 });
+        let rect: Obstacle = Obstacle::Rect { min: Point { x: 0.0, y: 0.0 }, max: Point { x: 40.0, y: 10.0 } };
+        assert_eq!(rect.distance_gradient(&Point { x: 20.0, y: 25.0 }), Point { x: 0.0, y: 1.0 });
+        let corner = rect.distance_gradient(&Point { x: 43.0, y: -4.0 });
+        assert_close(corner.x, 0.6);
+        assert_close(corner.y, -0.8);
+        // İçeride en yakın kenarın normali
+        assert_eq!(rect.distance_gradient(&Point { x: 20.0, y: 8.0 }), Point { x: 0.0, y: 1.0 });
+        assert_eq!(rect.distance_gradient(&Point { x: 1.0, y: 5.0 }), Point { x: -1.0, y: 0.0 });
+        // Gradyan yönünde küçük bir adım işaretli uzaklığı adım kadar artırır
+        for p in [Point { x: 43.0, y: -4.0 }, Point { x: 20.0, y: 8.0 }, Point { x: 7.0, y: 6.0 }] {
+            let g = circle.distance_gradient(&p);
+            assert_close(circle.signed_distance(&(p + g * 0.5)) - circle.signed_distance(&p), 0.5);
+        }
+
+        let mut rrt: RRT = RRT::new(Point { x: 200.0, y: 200.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+        assert!(rrt.checker().nearest_obstacle(&Point { x: 0.0, y: 0.0 }).is_none());
+        rrt.obstacles = vec![rect, Obstacle::Circle { center: Point { x: 100.0, y: 5.0 }, radius: 10.0 }];
+        let (distance, away) = rrt.checker().nearest_obstacle(&Point { x: 80.0, y: 5.0 }).expect("obstacles exist");
+        assert_close(distance, 10.0);
+        assert_eq!(away, Point { x: -1.0, y: 0.0 });
+    }
+
+    #[test]
+    fn adaptive_check_catches_a_thin_wall_that_a_coarse_fixed_step_skips() {
+        let bounds = Bounds::new(0.0, 100.0, 0.0, 100.0);
+        let wall = [Obstacle::Rect { min: Point { x: 49.5, y: 0.0 }, max: Point { x: 50.5, y: 100.0 } }];
+        let checker = CollisionChecker { bounds: &bounds, obstacles: &wall, resolution: 7.0, adaptive: true, robot_radius: 0.0, robot_shape: &RobotShape::Disk };
+        let (from, to) = (Point { x: 0.0, y: 50.0 }, Point { x: 100.0, y: 50.0 });
+        // 7 piksellik adımlar duvarın iki yanına düşer
+        assert!(checker.is_edge_free_fixed(&from, &to));
+        assert!(!checker.is_edge_free_adaptive(&from, &to));
+        assert!(!checker.is_edge_free(&from, &to));
+        assert!(checker.is_edge_free_adaptive(&from, &Point { x: 49.0, y: 90.0 }));
+    }
+
+    #[test]
+    fn circle_signed_distance_is_negative_inside_and_zero_on_the_boundary() {
+        let circle = Obstacle::Circle { center: Point { x: 10.0, y: 10.0 }, radius: 5.0 };
+        assert_close(circle.signed_distance(&Point { x: 10.0, y: 10.0 }), -5.0);
+        assert_close(circle.signed_distance(&Point { x: 12.0, y: 10.0 }), -3.0);
+        assert_close(circle.signed_distance(&Point { x: 13.0, y: 14.0 }), 0.0);
+        assert_close(circle.signed_distance(&Point { x: 10.0, y: 0.0 }), 5.0);
+        assert_close(circle.signed_distance(&Point { x: 16.0, y: 18.0 }), 5.0);
+
+        // Açıklık en yakın engelin işaretli uzaklığıdır; mesafe ise sıfırda kesilir
+        let bounds = Bounds::new(0.0, 100.0, 0.0, 100.0);
+        let obstacles = [circle, Obstacle::Circle { center: Point { x: 40.0, y: 10.0 }, radius: 5.0 }];
+        let checker = CollisionChecker { bounds: &bounds, obstacles: &obstacles, resolution: 1.0, adaptive: false, robot_radius: 0.0, robot_shape: &RobotShape::Disk };
+        assert_close(checker.clearance(&Point { x: 30.0, y: 10.0 }), 5.0);
+        assert_close(checker.clearance(&Point { x: 11.0, y: 10.0 }), -4.0);
+        assert_close(checker.distance_to_nearest_obstacle(&Point { x: 11.0, y: 10.0 }), 0.0);
+        assert!(!checker.is_point_free(&Point { x: 13.0, y: 14.0 }));
+        assert!(checker.is_point_free(&Point { x: 13.0, y: 14.1 }));
+    }
+
+    #[test]
+    fn segment_distances_match_hand_computed_values() {
+        let p = |x, y| Point { x, y };
+        let circle = Obstacle::Circle { center: p(0.0, 0.0), radius: 1.0 };
+        let rect = Obstacle::Rect { min: p(0.0, 0.0), max: p(2.0, 2.0) };
+
+        assert_close(circle.segment_distance(&p(-2.0, 3.0), &p(2.0, 3.0)), 2.0);
+        assert_close(rect.segment_distance(&p(3.0, -1.0), &p(3.0, 5.0)), 1.0);
+        // En yakın nokta parçanın ortasında, dikdörtgenin köşesinin karşısında
+        assert_close(rect.segment_distance(&p(3.0, 4.0), &p(4.0, 3.0)), 3.0 / 2f32.sqrt());
+        // İçinden geçen ve tamamen içinde kalan parçalar
+        assert_eq!(rect.segment_distance(&p(-1.0, 1.0), &p(3.0, 1.0)), 0.0);
+        assert_eq!(rect.segment_distance(&p(0.5, 0.5), &p(1.5, 1.5)), 0.0);
+        let triangle = [p(0.0, 0.0), p(4.0, 0.0), p(0.0, 4.0)];
+        assert_close(polygon_segment_distance(&triangle, &p(3.0, 3.0), &p(5.0, 5.0)), 2f32.sqrt());
+    }
+}
@@ -0,0 +1,118 @@
+// Parametreleri canlı ayarlamak için macroquad'ın anlık kipli arayüzüyle çizilen kontrol paneli
+use macroquad::prelude::*;
+use macroquad::ui::{hash, root_ui, widgets};
+use rrt_visualization::rrt::{GOAL_THRESHOLD_RANGE, STEP_SIZE_RANGE};
+use rrt_visualization::RRT;
+
+use crate::DebugLayers;
+
+const PANEL_WIDTH: f32 = 260.0;
+const PANEL_HEIGHT: f32 = 400.0;
+
+// Panelde tıklanan ve ağacı etkileyen düğmeler; uygulanması pencere döngüsüne bırakılır
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PanelAction {
+    Reset,
+    ClearObstacles,
+    ExportPath,
+    Restart, // Bekleyen adım boyutu ve robot yarıçapı uygulandı, ağaç yeniden başlatılmalı
+}
+
+// Adım boyutu ve robot yarıçapı var olan ağacın geometrisini değiştirdiğinden anında uygulanmaz;
+// "Restart with new params" düğmesine basılana kadar bekletilir. Diğer değerler yalnızca bundan
+// sonraki iterasyonları etkilediği için hemen uygulanır.
+pub struct ControlPanel {
+    pub open: bool,
+    pending_step_size: f32,
+    pending_robot_radius: f32,
+}
+
+impl ControlPanel {
+    pub fn new(rrt: &RRT) -> Self {
+        ControlPanel { open: false, pending_step_size: rrt.step_size(), pending_robot_radius: rrt.robot_radius() }
+    }
+
+    // İmleç panelin üzerindeyse haritaya yapılan tıklamalar yok sayılmalıdır
+    pub fn is_mouse_over(&self) -> bool {
+        root_ui().is_mouse_over(Vec2::from(mouse_position()))
+    }
+
+    // Paneli çizer ve değişen parametreleri ağaca uygular. Kapalıyken yalnızca paneli açan küçük
+    // bir düğme gösterilir, böylece harita örtülmez.
+    pub fn show(
+        &mut self,
+        rrt: &mut RRT,
+        layers: &mut DebugLayers,
+        iterations_per_frame: &mut usize,
+    ) -> Option<PanelAction> {
+        let position = vec2(screen_width() - PANEL_WIDTH - 10.0, 10.0);
+        if !self.open {
+            widgets::Window::new(hash!(), position + vec2(PANEL_WIDTH - 110.0, 0.0), vec2(110.0, 34.0))
+                .titlebar(false)
+                .movable(false)
+                .ui(&mut root_ui(), |ui| {
+                    if ui.button(None, "Parameters") {
+                        self.open = true;
+                    }
+                });
+            return None;
+        }
+
+        let mut action = None;
+        let mut goal_bias = rrt.goal_bias();
+        let mut goal_threshold = rrt.goal_threshold();
+        let mut rate = *iterations_per_frame as f32;
+        widgets::Window::new(hash!(), position, vec2(PANEL_WIDTH, PANEL_HEIGHT))
+            .label("Parameters")
+            .titlebar(true)
+            .movable(true)
+            .ui(&mut root_ui(), |ui| {
+                if ui.button(None, "Hide panel") {
+                    self.open = false;
+                }
+                ui.separator();
+                ui.label(None, "Applied immediately:");
+                ui.slider(hash!(), "goal bias", 0.0..1.0, &mut goal_bias);
+                ui.slider(hash!(), "goal threshold", GOAL_THRESHOLD_RANGE.0..GOAL_THRESHOLD_RANGE.1, &mut goal_threshold);
+                ui.slider(hash!(), "iterations/frame", 1.0..1000.0, &mut rate);
+                ui.separator();
+                ui.label(None, "Applied on restart:");
+                ui.slider(hash!(), "step size", STEP_SIZE_RANGE.0..STEP_SIZE_RANGE.1, &mut self.pending_step_size);
+                ui.slider(hash!(), "robot radius", 0.0..30.0, &mut self.pending_robot_radius);
+                if ui.button(None, "Restart with new params") {
+                    self.pending_step_size = rrt.set_step_size(self.pending_step_size);
+                    rrt.set_robot_radius(self.pending_robot_radius);
+                    action = Some(PanelAction::Restart);
+                }
+                ui.separator();
+                ui.label(None, "Overlays:");
+                ui.checkbox(hash!(), "sample", &mut layers.sample);
+                ui.checkbox(hash!(), "rejected", &mut layers.rejected);
+                ui.checkbox(hash!(), "goal region", &mut layers.goal_region);
+                ui.checkbox(hash!(), "nodes", &mut layers.node_dots);
+                ui.checkbox(hash!(), "spline", &mut layers.spline);
+                ui.separator();
+                if ui.button(None, "Reset") {
+                    action = Some(PanelAction::Reset);
+                }
+                ui.same_line(0.0);
+                if ui.button(None, "Clear obstacles") {
+                    action = Some(PanelAction::ClearObstacles);
+                }
+                ui.same_line(0.0);
+                if ui.button(None, "Export path") {
+                    action = Some(PanelAction::ExportPath);
+                }
+            });
+
+        // Eşik değişimi hedef düğümünü yeniden değerlendirdiğinden yalnızca değer değiştiğinde uygulanır
+        if goal_bias != rrt.goal_bias() {
+            rrt.set_goal_bias(goal_bias);
+        }
+        if goal_threshold != rrt.goal_threshold() {
+            rrt.set_goal_threshold(goal_threshold);
+        }
+        *iterations_per_frame = (rate.round() as usize).max(1);
+        action
+    }
+}
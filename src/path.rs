@@ -0,0 +1,205 @@
+// Planlayıcıların döndürdüğü yol: ara noktalar ve onlardan türetilen uzunluklar ve yönler
+use std::fmt;
+use std::ops::Deref;
+
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+// Yol üzerindeki konum ve yön; yön x ekseninden saat yönünün tersine ölçülür (radyan)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Pose<T = f32> {
+    pub point: Point<T>,
+    pub heading: T,
+}
+
+// Ara noktalar sırasıyla başlangıçtan hedefe gider. Birikimli yay uzunlukları oluşturulurken bir
+// kez hesaplanır; dilim olarak kullanılabildiğinden (`Deref`) çizim ve uzunluk gibi yardımcılar
+// yolu doğrudan alır.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path<T = f32> {
+    waypoints: Vec<Point<T>>,
+    cumulative: Vec<T>, // `i`. eleman başlangıçtan `i`. ara noktaya olan yay uzunluğu
+    goal_node: Option<usize>, // Yolun bittiği ağaç düğümü; ağaçtan çıkarılmayan yollarda `None`
+}
+
+impl<T: Scalar> Path<T> {
+    pub fn new(waypoints: Vec<Point<T>>, goal_node: Option<usize>) -> Self {
+        let mut cumulative = Vec::with_capacity(waypoints.len());
+        let mut total = T::zero();
+        for (i, p) in waypoints.iter().enumerate() {
+            if i > 0 {
+                total += waypoints[i - 1].distance(p);
+            }
+            cumulative.push(total);
+        }
+        Path { waypoints, cumulative, goal_node }
+    }
+
+    pub fn points(&self) -> &[Point<T>] {
+        &self.waypoints
+    }
+
+    pub fn into_points(self) -> Vec<Point<T>> {
+        self.waypoints
+    }
+
+    pub fn goal_node(&self) -> Option<usize> {
+        self.goal_node
+    }
+
+    // Toplam uzunluk; boş veya tek noktalı yolda sıfırdır
+    pub fn length(&self) -> T {
+        self.cumulative.last().copied().unwrap_or_else(T::zero)
+    }
+
+    // Her ara noktaya kadar olan yay uzunlukları; ilki sıfır, sonuncusu toplam uzunluktur
+    pub fn cumulative_lengths(&self) -> &[T] {
+        &self.cumulative
+    }
+
+    pub fn segment_lengths(&self) -> Vec<T> {
+        self.cumulative.windows(2).map(|w| w[1] - w[0]).collect()
+    }
+
+    // Her parçanın x ekseninden saat yönünün tersine ölçülen yönü (radyan)
+    pub fn headings(&self) -> Vec<T> {
+        self.waypoints.windows(2).map(|w| (w[1].y - w[0].y).atan2(w[1].x - w[0].x)).collect()
+    }
+
+    // Her ara noktada bir poz: yön önceki ara noktadan bu noktaya olan doğrultudur. İlk poz ilk
+    // parçanın yönünü alır; uzunluğu sıfır olan parçalar önceki yönü korur. Tek noktalı yolun yönü
+    // sıfırdır.
+    pub fn poses(&self) -> Vec<Pose<T>> {
+        let first = self.headings().into_iter().zip(self.segment_lengths()).find(|&(_, length)| length > T::zero());
+        let mut heading = first.map_or_else(T::zero, |(h, _)| h);
+        self.waypoints
+            .iter()
+            .enumerate()
+            .map(|(i, &point)| {
+                if i > 0 && point != self.waypoints[i - 1] {
+                    let previous = self.waypoints[i - 1];
+                    heading = (point.y - previous.y).atan2(point.x - previous.x);
+                }
+                Pose { point, heading }
+            })
+            .collect()
+    }
+
+    // Başlangıçtan yol boyunca `s` uzaklıktaki nokta; `s` [0, uzunluk] aralığına kırpılır. Tam bir
+    // ara noktanın yay uzunluğunda o ara noktanın kendisi döner. Yol boşsa panik oluşur.
+    pub fn point_at_arclength(&self, s: T) -> Point<T> {
+        let last = *self.waypoints.last().expect("point_at_arclength on an empty path");
+        if s.is_nan() || s <= T::zero() {
+            return self.waypoints[0];
+        }
+        if s >= self.length() {
+            return last;
+        }
+        // `s` ile başlayan veya `s` değerini içeren son parça; uzunluğu sıfır olan parçalar atlanır,
+        // seçilen parçanın uzunluğu bu yüzden her zaman pozitiftir
+        let i = self.cumulative.partition_point(|&c| c <= s).saturating_sub(1);
+        let (a, b) = (self.waypoints[i], self.waypoints[i + 1]);
+        let length = self.cumulative[i + 1] - self.cumulative[i];
+        Point::lerp(a, b, (s - self.cumulative[i]) / length)
+    }
+
+    // Yolu yay uzunluğu boyunca `spacing` aralıklarla yeniden örnekler: 0, spacing, 2·spacing, ...
+    // noktalarına son nokta eklenir (son aralık daha kısa olabilir). Aralık pozitif değilse yol
+    // olduğu gibi döner. Hedef düğümü korunur.
+    pub fn resample(&self, spacing: T) -> Path<T> {
+        if self.waypoints.len() < 2 || spacing.is_nan() || spacing <= T::zero() {
+            return self.clone();
+        }
+        let length = self.length();
+        let count = (length / spacing).floor().to_usize().unwrap_or(0);
+        let mut points: Vec<Point<T>> = (0..=count).map(|k| self.point_at_arclength(T::cast(k) * spacing)).collect();
+        if T::cast(count) * spacing < length {
+            points.push(*self.waypoints.last().expect("path has at least two waypoints"));
+        }
+        Path::new(points, self.goal_node)
+    }
+}
+
+impl<T: Scalar> From<Vec<Point<T>>> for Path<T> {
+    fn from(waypoints: Vec<Point<T>>) -> Self {
+        Path::new(waypoints, None)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Path<T> {
+    type Item = &'a Point<T>;
+    type IntoIter = std::slice::Iter<'a, Point<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.waypoints.iter()
+    }
+}
+
+impl<T> Deref for Path<T> {
+    type Target = [Point<T>];
+
+    fn deref(&self) -> &[Point<T>] {
+        &self.waypoints
+    }
+}
+
+// `RRT::validate_path` tarafından bulunan ilk kusur; indeksler parçanın başlangıç noktasınındır
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathError<T = f32> {
+    Empty,
+    NotAtStart(Point<T>), // İlk nokta ağacın köklerinden biri değil
+    GoalNotReached(Point<T>), // Son nokta hedef bölgesinde değil
+    SegmentTooLong { index: usize, length: T }, // Parça ağacın kurabileceği en uzun kenardan uzun
+    Collision { index: usize }, // Parça bir engele değiyor veya alan dışına çıkıyor
+}
+
+impl<T: Scalar> fmt::Display for PathError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Empty => write!(f, "path is empty"),
+            PathError::NotAtStart(p) => write!(f, "path starts at ({}, {}), which is not a start", p.x, p.y),
+            PathError::GoalNotReached(p) => write!(f, "path ends at ({}, {}), outside the goal region", p.x, p.y),
+            PathError::SegmentTooLong { index, length } => write!(f, "segment {} is {} long, longer than an edge", index, length),
+            PathError::Collision { index } => write!(f, "segment {} is not collision-free", index),
+        }
+    }
+}
+
+impl<T: Scalar> std::error::Error for PathError<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn resampling_at_a_divisor_of_every_segment_length_reproduces_the_waypoints() {
+        // 3 ve 4 uzunluğundaki parçaların sınırları 1 aralığının tam katlarına düşer
+        let corners = [(0.0, 0.0), (3.0, 0.0), (3.0, 4.0)].map(|(x, y)| Point { x, y });
+        let path = Path::new(corners.to_vec(), Some(7));
+        assert_eq!((path.length(), path.segment_lengths()), (7.0, vec![3.0, 4.0]));
+        assert_eq!(path.cumulative_lengths(), &[0.0, 3.0, 7.0]);
+        assert_close(path.headings()[1], std::f32::consts::FRAC_PI_2);
+        let resampled = path.resample(1.0);
+        assert_eq!((resampled.len(), resampled.goal_node()), (8, Some(7)));
+        assert_eq!((resampled[0], resampled[3], resampled[7]), (corners[0], corners[1], corners[2]));
+        assert_eq!(resampled.segment_lengths(), vec![1.0; 7]);
+        // Kalan kısa aralık son noktayla kapatılır
+        let coarse = path.resample(2.0);
+        assert_eq!(coarse.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>(), vec![(0.0, 0.0), (2.0, 0.0), (3.0, 1.0), (3.0, 3.0), (3.0, 4.0)]);
+    }
+
+    #[test]
+    fn point_at_arclength_walks_the_path_and_clamps_at_its_ends() {
+        let path = Path::from([(0.0, 0.0), (3.0, 0.0), (3.0, 4.0)].map(|(x, y)| Point { x, y }).to_vec());
+        assert_eq!(path.point_at_arclength(0.0), path[0]);
+        assert_eq!(path.point_at_arclength(1.5), Point { x: 1.5, y: 0.0 });
+        assert_eq!(path.point_at_arclength(3.0), path[1]);
+        assert_eq!(path.point_at_arclength(5.0), Point { x: 3.0, y: 2.0 });
+        assert_eq!(path.point_at_arclength(path.length()), path[2]);
+        assert_eq!((path.point_at_arclength(-1.0), path.point_at_arclength(100.0)), (path[0], path[2]));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::rrt::{PlanResult, Point, RRT};
+
+// Farklı planlama algoritmalarının görselleştirme ve ekransız çalıştırma için ortak arayüzü
+pub trait Planner {
+    // En fazla `max_iters` iterasyonla başlangıçtan hedefe yol arar
+    fn plan(&mut self, max_iters: usize) -> PlanResult;
+
+    // Çizim için planlayıcının kurduğu grafın kenarları
+    fn edges(&self) -> Vec<(Point, Point)>;
+
+    fn start(&self) -> Point;
+
+    fn goal(&self) -> Point;
+}
+
+impl Planner for RRT {
+    fn plan(&mut self, max_iters: usize) -> PlanResult {
+        RRT::plan(self, max_iters)
+    }
+
+    fn edges(&self) -> Vec<(Point, Point)> {
+        self.nodes
+            .iter()
+            .filter_map(|node| node.parent.map(|parent| (self.nodes[parent].point, node.point)))
+            .collect()
+    }
+
+    fn start(&self) -> Point {
+        self.nodes[0].point
+    }
+
+    fn goal(&self) -> Point {
+        self.goal()
+    }
+}
@@ -0,0 +1,261 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::config::RRTConfig;
+use crate::mapgen::scenario_obstacles;
+use crate::obstacle::{Bounds, CollisionChecker, Obstacle, RobotShape};
+use crate::path::Path;
+use crate::planner::Planner;
+use crate::rrt::{seeded_rng, PlanResult, PlanStats, Point};
+use crate::search::astar;
+
+// Olasılıksal yol haritası (PRM): aynı harita üzerinde çok sayıda başlangıç/hedef sorgusu
+// için yol haritası bir kez kurulur ve her sorgu graf araması ile yanıtlanır
+pub struct Prm {
+    pub vertices: Vec<Point>, // Yol haritasının çarpışmasız köşeleri
+    pub adjacency: Vec<Vec<(usize, f32)>>, // Her köşenin komşuları ve kenar uzunlukları
+    pub start: Point,
+    pub goal: Point,
+    pub samples: usize, // Kurulumda örneklenecek köşe sayısı
+    pub neighbors: usize, // Her köşenin bağlanmaya çalışılacağı en yakın komşu sayısı
+    pub bounds: Bounds,
+    pub obstacles: Vec<Obstacle>,
+    pub collision_resolution: f32,
+    pub adaptive_collision: bool,
+    pub robot_radius: f32,
+    pub robot_shape: RobotShape,
+    rng: StdRng,
+}
+
+impl Prm {
+    pub fn from_config(config: &RRTConfig) -> Self {
+        let mut rng = seeded_rng(config.seed);
+        let bounds = config.bounds;
+        let random_in_bounds = |rng: &mut StdRng| Point {
+            x: rng.gen_range(bounds.min_x..bounds.max_x),
+            y: rng.gen_range(bounds.min_y..bounds.max_y),
+        };
+        let start = config.start.unwrap_or_else(|| random_in_bounds(&mut rng));
+        let goal = config.goal.unwrap_or_else(|| random_in_bounds(&mut rng));
+        Prm {
+            vertices: Vec::new(),
+            adjacency: Vec::new(),
+            start,
+            goal,
+            samples: config.prm_samples,
+            neighbors: config.prm_neighbors,
+            bounds,
+            obstacles: scenario_obstacles(config, &start, &goal),
+            collision_resolution: config.collision_resolution,
+            adaptive_collision: config.adaptive_collision,
+            robot_radius: config.robot_radius,
+            robot_shape: config.robot_shape.clone(),
+            rng,
+        }
+    }
+
+    pub fn checker(&self) -> CollisionChecker<'_> {
+        CollisionChecker {
+            bounds: &self.bounds,
+            obstacles: &self.obstacles,
+            resolution: self.collision_resolution,
+            adaptive: self.adaptive_collision,
+            robot_radius: self.robot_radius,
+            robot_shape: &self.robot_shape,
+        }
+    }
+
+    pub fn is_built(&self) -> bool {
+        !self.vertices.is_empty()
+    }
+
+    // `point` noktasına en yakın `k` köşenin indekslerini mesafeye göre sıralı döndürür
+    fn nearest_vertices(&self, point: &Point, k: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.vertices.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.vertices[a]
+                .distance(point)
+                .total_cmp(&self.vertices[b].distance(point))
+                .then(a.cmp(&b))
+        });
+        order.truncate(k);
+        order
+    }
+
+    // Yol haritasını kurar: en fazla `max_attempts` denemeyle `samples` çarpışmasız köşe örnekler
+    // ve her birini çarpışmasız kenarlarla en yakın komşularına bağlar. Örnekleme denemesi
+    // sayısını döndürür.
+    pub fn build(&mut self, max_attempts: usize) -> usize {
+        self.vertices.clear();
+        self.adjacency.clear();
+        let b = self.bounds;
+        let mut attempts = 0;
+        while self.vertices.len() < self.samples && attempts < max_attempts {
+            attempts += 1;
+            let p = Point {
+                x: self.rng.gen_range(b.min_x..b.max_x),
+                y: self.rng.gen_range(b.min_y..b.max_y),
+            };
+            if self.checker().is_point_free(&p) {
+                self.vertices.push(p);
+            }
+        }
+
+        self.adjacency = vec![Vec::new(); self.vertices.len()];
+        for i in 0..self.vertices.len() {
+            let from = self.vertices[i];
+            // İlk eleman köşenin kendisidir
+            for j in self.nearest_vertices(&from, self.neighbors + 1) {
+                if j == i || self.adjacency[i].iter().any(|&(n, _)| n == j) {
+                    continue;
+                }
+                let to = self.vertices[j];
+                if self.checker().is_edge_free(&from, &to) {
+                    let length = from.distance(&to);
+                    self.adjacency[i].push((j, length));
+                    self.adjacency[j].push((i, length));
+                }
+            }
+        }
+        attempts
+    }
+
+    // Başlangıç ve hedefi yol haritasına geçici olarak bağlayıp A* ile en kısa yolu arar
+    pub fn query(&self, start: &Point, goal: &Point) -> Option<Vec<Point>> {
+        let checker = self.checker();
+        if !checker.is_point_free(start) || !checker.is_point_free(goal) {
+            return None;
+        }
+        if checker.is_edge_free(start, goal) {
+            return Some(vec![*start, *goal]);
+        }
+
+        let n = self.vertices.len();
+        let (start_index, goal_index) = (n, n + 1);
+        let point_of = |i: usize| match i {
+            i if i == start_index => *start,
+            i if i == goal_index => *goal,
+            i => self.vertices[i],
+        };
+        let connect = |p: &Point| -> Vec<(usize, f32)> {
+            self.nearest_vertices(p, self.neighbors)
+                .into_iter()
+                .filter(|&j| checker.is_edge_free(p, &self.vertices[j]))
+                .map(|j| (j, p.distance(&self.vertices[j])))
+                .collect()
+        };
+        let start_links = connect(start);
+        let goal_links = connect(goal);
+        if start_links.is_empty() || goal_links.is_empty() {
+            return None;
+        }
+
+        let indices = astar(
+            n + 2,
+            start_index,
+            goal_index,
+            |i, out| {
+                if i == start_index {
+                    out.extend_from_slice(&start_links);
+                    return;
+                }
+                if i < n {
+                    out.extend_from_slice(&self.adjacency[i]);
+                }
+                if let Some(&(_, length)) = goal_links.iter().find(|&&(j, _)| j == i) {
+                    out.push((goal_index, length));
+                }
+            },
+            |i| point_of(i).distance(goal),
+        )?;
+        Some(indices.into_iter().map(point_of).collect())
+    }
+}
+
+impl Planner for Prm {
+    // Yol haritası henüz kurulmadıysa `max_iters` örnekleme denemesiyle kurulur, ardından
+    // kayıtlı başlangıç/hedef için sorgu yapılır
+    fn plan(&mut self, max_iters: usize) -> PlanResult {
+        let iterations = if self.is_built() { 0 } else { self.build(max_iters) };
+        let path = self.query(&self.start, &self.goal);
+        let stats = PlanStats {
+            iterations,
+            nodes: self.vertices.len(),
+            budget_exhausted: false,
+            best_goal_distance: if path.is_some() { 0.0 } else { f32::INFINITY },
+            best_goal_iteration: iterations,
+            // Örnekleme denemelerinden engele düşenler köşe olmaz
+            samples_total: iterations,
+            samples_rejected: if iterations == 0 { 0 } else { iterations - self.vertices.len() },
+        };
+        match path {
+            Some(path) => PlanResult::Found { path: Path::from(path), stats },
+            None => PlanResult::NotFound { stats },
+        }
+    }
+
+    fn edges(&self) -> Vec<(Point, Point)> {
+        let mut edges = Vec::new();
+        for (i, links) in self.adjacency.iter().enumerate() {
+            for &(j, _) in links {
+                if i < j {
+                    edges.push((self.vertices[i], self.vertices[j]));
+                }
+            }
+        }
+        edges
+    }
+
+    fn start(&self) -> Point {
+        self.start
+    }
+
+    fn goal(&self) -> Point {
+        self.goal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prm(obstacles: Vec<Obstacle>) -> Prm {
+        let config = RRTConfig {
+            start: Some(Point { x: 20.0, y: 20.0 }),
+            goal: Some(Point { x: 380.0, y: 380.0 }),
+            bounds: Bounds::new(0.0, 400.0, 0.0, 400.0),
+            seed: Some(3),
+            obstacles,
+            ..RRTConfig::default()
+        };
+        Prm::from_config(&config)
+    }
+
+    #[test]
+    fn empty_map_roadmap_answers_queries_with_nearly_straight_paths() {
+        let mut prm = prm(Vec::new());
+        let PlanResult::Found { path, stats } = prm.plan(10_000) else { panic!("empty map has a path") };
+        assert!(prm.is_built() && stats.nodes == prm.samples);
+        // Görünür uç noktalar doğrudan bağlanır
+        assert_eq!(path.to_vec(), vec![prm.start, prm.goal]);
+        assert!(prm.edges().iter().all(|(a, b)| prm.checker().is_edge_free(a, b)));
+        // Yol haritası üzerinden giden sorgu da düz çizgiye yakın kalır
+        let (a, b) = (Point { x: 10.0, y: 200.0 }, Point { x: 390.0, y: 200.0 });
+        prm.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 5.0 });
+        let path = Path::from(prm.query(&a, &b).expect("roadmap connects both sides"));
+        assert!(path.length() < 1.2 * a.distance(&b), "path length {}", path.length());
+    }
+
+    #[test]
+    fn walled_off_goal_reports_no_path() {
+        let wall = |min_x: f32, min_y: f32, max_x: f32, max_y: f32| Obstacle::Rect { min: Point { x: min_x, y: min_y }, max: Point { x: max_x, y: max_y } };
+        // Hedefi çevreleyen kapalı kutu
+        let mut prm = prm(vec![
+            wall(330.0, 330.0, 400.0, 340.0),
+            wall(330.0, 330.0, 340.0, 400.0),
+        ]);
+        assert!(matches!(prm.plan(10_000), PlanResult::NotFound { .. }));
+        assert!(prm.is_built());
+        assert_eq!(prm.query(&prm.start, &prm.goal), None);
+    }
+}
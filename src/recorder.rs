@@ -0,0 +1,382 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use crate::obstacle::Obstacle;
+use crate::rrt::{Point, RRT};
+
+// Bir planlama oturumunda kaydedilen olaylar
+#[derive(Clone, Copy, Debug)]
+pub enum Event {
+    Sample(Point), // Çekilen rastgele örnek
+    NodeAdded { point: Point, parent: usize }, // Ağaca eklenen düğüm
+    GoalReached { node: usize }, // Hedefe ulaşan düğüm
+    ObstacleAdded(Obstacle), // Haritaya eklenen engel
+    ObstacleMoved { index: usize, obstacle: Obstacle }, // Yeri veya boyutu değişen engel
+    ObstaclesCleared, // Tüm engellerin kaldırılması (ör. yeni harita üretildiğinde)
+    Reset { start: Point, goal: Point }, // Ağacın sıfırlanması
+    SubtreePruned { root: usize }, // Tembel modda geçersiz kenar nedeniyle budanan alt ağaç
+    Rejected(Point), // Çarpışma veya başka bir nedenle eklenmeyen yönlendirilmiş nokta
+    GoalMoved(Point), // Ağaç korunarak taşınan birincil hedef
+}
+
+fn invalid(line: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("invalid recording line: {}", line))
+}
+
+fn write_obstacle<W: Write>(w: &mut W, obstacle: &Obstacle) -> io::Result<()> {
+    match obstacle {
+        Obstacle::Circle { center, radius } => write!(w, "c {} {} {}", center.x, center.y, radius),
+        Obstacle::Rect { min, max } => write!(w, "r {} {} {} {}", min.x, min.y, max.x, max.y),
+    }
+}
+
+fn parse_obstacle(fields: &[&str]) -> Option<Obstacle> {
+    let nums: Vec<f32> = fields[1..].iter().map(|f| f.parse().ok()).collect::<Option<_>>()?;
+    match (fields[0], nums.as_slice()) {
+        ("c", &[x, y, radius]) => Some(Obstacle::Circle { center: Point { x, y }, radius }),
+        ("r", &[x1, y1, x2, y2]) => Some(Obstacle::Rect {
+            min: Point { x: x1, y: y1 },
+            max: Point { x: x2, y: y2 },
+        }),
+        _ => None,
+    }
+}
+
+impl Event {
+    // Olayı tek satırlık kompakt metne yazar; f32 değerleri kayıpsız geri okunacak şekilde yazılır
+    pub fn write_line<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        match self {
+            Event::Sample(p) => write!(w, "S {} {}", p.x, p.y)?,
+            Event::NodeAdded { point, parent } => write!(w, "N {} {} {}", point.x, point.y, parent)?,
+            Event::GoalReached { node } => write!(w, "G {}", node)?,
+            Event::ObstacleAdded(obstacle) => {
+                write!(w, "O ")?;
+                write_obstacle(w, obstacle)?;
+            }
+            Event::ObstacleMoved { index, obstacle } => {
+                write!(w, "M {} ", index)?;
+                write_obstacle(w, obstacle)?;
+            }
+            Event::Reset { start, goal } => write!(w, "R {} {} {} {}", start.x, start.y, goal.x, goal.y)?,
+            Event::ObstaclesCleared => write!(w, "X")?,
+            Event::SubtreePruned { root } => write!(w, "P {}", root)?,
+            Event::Rejected(p) => write!(w, "F {} {}", p.x, p.y)?,
+            Event::GoalMoved(p) => write!(w, "T {} {}", p.x, p.y)?,
+        }
+        writeln!(w)
+    }
+
+    // `write_line` ile yazılmış bir satırı geri okur
+    pub fn parse_line(line: &str) -> io::Result<Event> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let num = |i: usize| -> io::Result<f32> {
+            fields.get(i).and_then(|f| f.parse().ok()).ok_or_else(|| invalid(line))
+        };
+        let index = |i: usize| -> io::Result<usize> {
+            fields.get(i).and_then(|f| f.parse().ok()).ok_or_else(|| invalid(line))
+        };
+        let event = match fields.first().copied() {
+            Some("S") => Event::Sample(Point { x: num(1)?, y: num(2)? }),
+            Some("F") => Event::Rejected(Point { x: num(1)?, y: num(2)? }),
+            Some("T") => Event::GoalMoved(Point { x: num(1)?, y: num(2)? }),
+            Some("N") => Event::NodeAdded { point: Point { x: num(1)?, y: num(2)? }, parent: index(3)? },
+            Some("G") => Event::GoalReached { node: index(1)? },
+            Some("P") => Event::SubtreePruned { root: index(1)? },
+            Some("X") => Event::ObstaclesCleared,
+            Some("O") if fields.len() > 1 => {
+                Event::ObstacleAdded(parse_obstacle(&fields[1..]).ok_or_else(|| invalid(line))?)
+            }
+            Some("M") if fields.len() > 2 => Event::ObstacleMoved {
+                index: index(1)?,
+                obstacle: parse_obstacle(&fields[2..]).ok_or_else(|| invalid(line))?,
+            },
+            Some("R") => Event::Reset {
+                start: Point { x: num(1)?, y: num(2)? },
+                goal: Point { x: num(3)?, y: num(4)? },
+            },
+            _ => return Err(invalid(line)),
+        };
+        Ok(event)
+    }
+
+    // Olayı ağaca uygular; yeniden oynatma sırasında rastgele sayı üreteci kullanılmaz
+    pub fn apply(&self, rrt: &mut RRT) {
+        match *self {
+            Event::Sample(_) | Event::Rejected(_) | Event::GoalReached { .. } => {}
+            Event::NodeAdded { point, parent } => rrt.add_node(point, parent),
+            Event::ObstacleAdded(obstacle) => {
+                rrt.add_obstacle(obstacle);
+            }
+            Event::ObstacleMoved { index, obstacle } => {
+                rrt.move_obstacle(index, obstacle);
+            }
+            Event::Reset { start, goal } => rrt.reset(start, goal),
+            Event::ObstaclesCleared => rrt.obstacles.clear(),
+            Event::GoalMoved(goal) => {
+                rrt.move_goal(goal);
+            }
+            Event::SubtreePruned { root } => {
+                rrt.remove_subtree(root);
+            }
+        }
+    }
+}
+
+// Olayları sırasıyla biriktiren kaydedici
+#[derive(Default)]
+pub struct Recorder {
+    events: Vec<Event>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Recorder::default()
+    }
+
+    // Mevcut ağacın başlangıç durumunu (uç noktalar ve engeller) kaydederek başlar
+    pub fn starting_from(rrt: &RRT) -> Self {
+        let mut recorder = Recorder::new();
+        recorder.record(Event::Reset { start: rrt.starts[0], goal: rrt.goal() });
+        for obstacle in &rrt.obstacles {
+            recorder.record(Event::ObstacleAdded(*obstacle));
+        }
+        recorder
+    }
+
+    pub fn record(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    // Tüm olayları satır satır yazar
+    pub fn write<W: Write>(&self, w: W) -> io::Result<()> {
+        let mut w = BufWriter::new(w);
+        for event in &self.events {
+            event.write_line(&mut w)?;
+        }
+        w.flush()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write(File::create(path)?)
+    }
+}
+
+// Kaydedilmiş olayları iterasyon iterasyon yeniden oynatan yapı
+pub struct Replayer {
+    events: Vec<Event>,
+    cursor: usize,
+}
+
+impl Replayer {
+    pub fn new(events: Vec<Event>) -> Self {
+        Replayer { events, cursor: 0 }
+    }
+
+    pub fn read<R: io::Read>(r: R) -> io::Result<Self> {
+        let mut events = Vec::new();
+        for line in BufReader::new(r).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                events.push(Event::parse_line(&line)?);
+            }
+        }
+        Ok(Replayer::new(events))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Replayer::read(File::open(path)?)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.events.len()
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    // Kayıtta örnek varsa ve ilk örnekten sonra harita ya da uç noktalar değişmiyorsa aynı ağaç
+    // planlayıcının kendisiyle kayıttaki örneklerden yeniden büyütülebilir. Tembel modda budanan
+    // alt ağaçlar planlayıcının kendi kararı olduğundan harita değişikliği sayılmaz.
+    pub fn regrows_from_samples(&self) -> bool {
+        let Some(first) = self.events.iter().position(|e| matches!(e, Event::Sample(_))) else { return false };
+        !self.events[first..].iter().any(|e| {
+            matches!(
+                e,
+                Event::ObstacleAdded(_) | Event::ObstacleMoved { .. } | Event::ObstaclesCleared | Event::Reset { .. } | Event::GoalMoved(_)
+            )
+        })
+    }
+
+    // Bir sonraki örneğe kadar olan olayları (örnek dahil, sonraki örnek hariç) uygular.
+    // Böylece her çağrı kaydedilen bir planlama iterasyonuna karşılık gelir.
+    pub fn step_iteration(&mut self, rrt: &mut RRT) -> Vec<Event> {
+        let mut applied = Vec::new();
+        while let Some(event) = self.events.get(self.cursor).copied() {
+            if matches!(event, Event::Sample(_)) && applied.iter().any(|e| matches!(e, Event::Sample(_))) {
+                break;
+            }
+            event.apply(rrt);
+            applied.push(event);
+            self.cursor += 1;
+        }
+        applied
+    }
+}
+
+// Kayıttaki örnekleri sırasıyla veren örnekleyici. `RRT::sample_source` olarak verildiğinde ağaç
+// kaydedilen olayları uygulamak yerine planlayıcının kendisiyle, rastgele sayı üretecinden
+// bağımsız olarak yeniden büyütülür; böylece planlayıcıdaki bir hata aynı örneklerle tekrar
+// üretilebilir. Aynı ağacın çıkması için yapılandırma kayıttakiyle aynı olmalıdır.
+pub struct RecordedSampler {
+    samples: VecDeque<Point>,
+}
+
+impl RecordedSampler {
+    pub fn from_events(events: &[Event]) -> Self {
+        let samples = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Sample(p) => Some(*p),
+                _ => None,
+            })
+            .collect();
+        RecordedSampler { samples }
+    }
+
+    pub fn from_recording<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(RecordedSampler::from_events(Replayer::load(path)?.events()))
+    }
+
+    // Kalan örnek sayısı
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+impl Iterator for RecordedSampler {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        self.samples.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rrt::StepResult;
+
+    // Bir iterasyonu ikili programdaki gibi kaydeder: örnek, ardından reddedilen nokta veya eklenen düğümler
+    fn record_step(rrt: &mut RRT, recorder: &mut Recorder) {
+        let result = rrt.step();
+        let step = rrt.last_step.expect("step records its intermediate values");
+        recorder.record(Event::Sample(step.sample));
+        match result {
+            StepResult::Rejected | StepResult::Exhausted => recorder.record(Event::Rejected(step.steered)),
+            StepResult::Extended { .. } | StepResult::GoalReached { .. } => {
+                for index in rrt.node_count() - step.nodes_added..rrt.node_count() {
+                    let node = rrt.node(index);
+                    recorder.record(Event::NodeAdded { point: node.point, parent: node.parent.expect("added nodes have a parent") });
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn replaying_a_recording_rebuilds_the_identical_tree() {
+        let mut rrt = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+        rrt.reseed(9);
+        let mut recorder = Recorder::starting_from(&rrt);
+        for _ in 0..300 {
+            record_step(&mut rrt, &mut recorder);
+        }
+        // Oturum ortasında çizilen engel de kayda girer ve kestiği alt ağaçlar iki tarafta da budanır
+        let wall = Obstacle::Rect { min: Point { x: 0.0, y: 60.0 }, max: Point { x: 100.0, y: 70.0 } };
+        assert!(rrt.add_obstacle(wall) > 0);
+        recorder.record(Event::ObstacleAdded(wall));
+        for _ in 0..300 {
+            record_step(&mut rrt, &mut recorder);
+        }
+
+        let mut file = Vec::new();
+        recorder.write(&mut file).unwrap();
+        let mut replayer = Replayer::read(file.as_slice()).unwrap();
+        assert_eq!(replayer.events().len(), recorder.events().len());
+        // Çizilen engel ağacı örneklerle yeniden büyütmeyi olanaksız kılar
+        assert!(!replayer.regrows_from_samples());
+        // Yeniden oynatılan ağaç farklı uç noktalarla ve engelsiz başlar; hepsi kayıttan gelir
+        let mut replayed = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 1.0 }, 10.0, 10.0);
+        let mut iterations = 0;
+        while !replayer.is_finished() {
+            replayer.step_iteration(&mut replayed);
+            iterations += 1;
+        }
+        // Örnekten önceki olaylar (başlangıç durumu, çizilen engel) bir iterasyonla birlikte uygulanır
+        assert_eq!(iterations, 600);
+        assert_eq!(replayed.nodes().collect::<Vec<_>>(), rrt.nodes().collect::<Vec<_>>());
+        assert_eq!((replayed.obstacles.len(), replayed.goal_node(), replayed.goal()), (2, rrt.goal_node(), rrt.goal()));
+    }
+
+    #[test]
+    fn replaying_recorded_samples_regrows_the_identical_tree() {
+        let build = || {
+            let mut rrt = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+            rrt.set_goal_bias(0.1);
+            rrt
+        };
+        let mut original = build();
+        original.reseed(3);
+        let mut recorder = Recorder::starting_from(&original);
+        for _ in 0..300 {
+            let extension = original.extend();
+            recorder.record(Event::Sample(extension.sample));
+            recorder.record(match extension.added {
+                Some(_) => Event::NodeAdded { point: extension.new_point, parent: extension.parent },
+                None => Event::Rejected(extension.new_point),
+            });
+        }
+        let path = std::env::temp_dir().join(format!("rrt_sample_replay_{}.log", std::process::id()));
+        recorder.save(&path).unwrap();
+        let recording = Replayer::load(&path).unwrap();
+        let sampler = RecordedSampler::from_recording(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        // Harita ilk örnekten sonra değişmediğinden `--replay` bu kaydı örneklerden yeniden büyütür
+        assert!(recording.regrows_from_samples());
+        assert_eq!(sampler.len(), 300);
+
+        // Farklı tohum, örnekler kayıttan geldiği için sonucu değiştirmez
+        let mut replayed = build();
+        replayed.reseed(99);
+        replayed.sample_source = Some(Box::new(sampler));
+        let outcomes: Vec<Event> = (0..300)
+            .map(|_| {
+                let extension = replayed.extend();
+                match extension.added {
+                    Some(_) => Event::NodeAdded { point: extension.new_point, parent: extension.parent },
+                    None => Event::Rejected(extension.new_point),
+                }
+            })
+            .collect();
+        let recorded: Vec<Event> = recording
+            .events()
+            .iter()
+            .copied()
+            .filter(|e| matches!(e, Event::NodeAdded { .. } | Event::Rejected(_)))
+            .collect();
+        assert_eq!(format!("{:?}", outcomes), format!("{:?}", recorded));
+        assert_eq!(replayed.nodes().collect::<Vec<_>>(), original.nodes().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,388 @@
+// Reeds-Shepp eğrileri: en küçük dönüş yarıçapı verilen, ileri ve geri gidebilen araba benzeri
+// robotun iki poz arasındaki en kısa yolu. Yol en fazla beş parçadır; her parça sola dönüş, sağa
+// dönüş veya düz gidiştir ve ileri ya da geri sürülür. Formüller Reeds ve Shepp'in (1990) aileleri
+// ile zamanı tersine çevirme ve yansıtma simetrilerini izler (OMPL'deki uygulamayla aynı
+// düzeltmelerle). Hesap birim yarıçaplı koordinatlarda f64 ile yapılır.
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::path::Pose;
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+// Aday geçerliliğinde ve sıfır uzunluklu parçaların atılmasında kullanılan tolerans
+const ZERO: f64 = 1e-9;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Steering {
+    Left,
+    Straight,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gear {
+    Forward,
+    Reverse,
+}
+
+// Yolun bir parçası; uzunluk dünya biriminde yay uzunluğudur (dönüşlerde açı çarpı yarıçap)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment<T = f32> {
+    pub steering: Steering,
+    pub gear: Gear,
+    pub length: T,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReedsSheppPath<T = f32> {
+    pub segments: Vec<Segment<T>>,
+    pub turning_radius: T,
+}
+
+impl<T: Scalar> ReedsSheppPath<T> {
+    // Parçaların toplam uzunluğu; boş yolda sıfırdır
+    pub fn length(&self) -> T {
+        self.segments.iter().fold(T::zero(), |total, s| total + s.length)
+    }
+
+    pub fn has_reverse(&self) -> bool {
+        self.segments.iter().any(|s| s.gear == Gear::Reverse)
+    }
+
+    // `from` pozundan çıkıp yol boyunca `s` kadar gidildiğindeki poz ve o andaki vites; `s` yolun
+    // dışındaysa uca kırpılır
+    pub fn pose_at(&self, from: &Pose<T>, s: T) -> (Pose<T>, Gear) {
+        let mut pose = *from;
+        let mut left = s.max(T::zero());
+        let mut gear = self.segments.first().map_or(Gear::Forward, |s| s.gear);
+        for segment in &self.segments {
+            gear = segment.gear;
+            let length = segment.length.min(left);
+            pose = advance(&pose, segment.steering, gear, length, self.turning_radius);
+            left -= length;
+            if left <= T::zero() {
+                break;
+            }
+        }
+        (pose, gear)
+    }
+
+    pub fn end_pose(&self, from: &Pose<T>) -> Pose<T> {
+        self.pose_at(from, self.length()).0
+    }
+
+    // Yolun ilk `s` uzunluğu; yol daha kısaysa olduğu gibi döner
+    pub fn truncated(&self, s: T) -> Self {
+        let mut segments = Vec::with_capacity(self.segments.len());
+        let mut left = s;
+        for segment in &self.segments {
+            if left <= T::zero() {
+                break;
+            }
+            segments.push(Segment { length: segment.length.min(left), ..*segment });
+            left -= segment.length;
+        }
+        ReedsSheppPath { segments, turning_radius: self.turning_radius }
+    }
+
+    // Yol boyunca en fazla `resolution` aralıklı pozlar ve vitesleri; iki uç dahildir ve her parça
+    // sınırında bir örnek vardır, böylece ileri ve geri kısımlar ayrı çizilebilir
+    pub fn sample(&self, from: &Pose<T>, resolution: T) -> Vec<(Pose<T>, Gear)> {
+        let mut pose = *from;
+        let mut samples = vec![(pose, self.segments.first().map_or(Gear::Forward, |s| s.gear))];
+        for segment in &self.segments {
+            let steps = (segment.length / resolution).ceil().max(T::one()).to_usize().unwrap_or(1);
+            let start = pose;
+            for i in 1..=steps {
+                let length = segment.length * T::cast(i) / T::cast(steps);
+                pose = advance(&start, segment.steering, segment.gear, length, self.turning_radius);
+                samples.push((pose, segment.gear));
+            }
+        }
+        samples
+    }
+}
+
+// `pose` pozundan tek bir parça boyunca `length` kadar gider
+fn advance<T: Scalar>(pose: &Pose<T>, steering: Steering, gear: Gear, length: T, radius: T) -> Pose<T> {
+    let signed = if gear == Gear::Forward { length } else { -length };
+    let (x, y, h) = (pose.point.x, pose.point.y, pose.heading);
+    let (point, heading) = match steering {
+        Steering::Straight => (Point { x: x + signed * h.cos(), y: y + signed * h.sin() }, h),
+        Steering::Left => {
+            let turn = signed / radius;
+            let point = Point { x: x + radius * ((h + turn).sin() - h.sin()), y: y + radius * (h.cos() - (h + turn).cos()) };
+            (point, h + turn)
+        }
+        Steering::Right => {
+            let turn = signed / radius;
+            let point = Point { x: x + radius * (h.sin() - (h - turn).sin()), y: y + radius * ((h - turn).cos() - h.cos()) };
+            (point, h - turn)
+        }
+    };
+    Pose { point, heading: T::cast(mod2pi(heading.to_f64().unwrap_or(0.0))) }
+}
+
+// `from` ile `to` arasındaki en kısa Reeds-Shepp yolu; sıfır uzunluklu parçalar atılır, iki poz
+// aynıysa yol boştur
+pub fn shortest_path<T: Scalar>(from: &Pose<T>, to: &Pose<T>, turning_radius: T) -> ReedsSheppPath<T> {
+    let f = |v: T| v.to_f64().unwrap_or(0.0);
+    let r = f(turning_radius);
+    let (dx, dy) = (f(to.point.x - from.point.x) / r, f(to.point.y - from.point.y) / r);
+    let (s, c) = f(from.heading).sin_cos();
+    let (x, y, phi) = (c * dx + s * dy, -s * dx + c * dy, mod2pi(f(to.heading) - f(from.heading)));
+
+    let mut words = Vec::new();
+    for candidates in [csc, ccc, cccc, ccsc, ccscc] {
+        candidates(x, y, phi, &mut words);
+    }
+    let total = |word: &Word| word.iter().map(|(_, l)| l.abs()).sum::<f64>();
+    let best = words.into_iter().min_by(|a, b| total(a).total_cmp(&total(b))).unwrap_or_default();
+    let segments = best
+        .into_iter()
+        .filter(|(_, l)| l.abs() > ZERO)
+        .map(|(steering, l)| Segment {
+            steering,
+            gear: if l > 0.0 { Gear::Forward } else { Gear::Reverse },
+            length: T::cast(l.abs() * r),
+        })
+        .collect();
+    ReedsSheppPath { segments, turning_radius }
+}
+
+// `from` ile `to` arasındaki en kısa Reeds-Shepp yolunun uzunluğu
+pub fn distance<T: Scalar>(from: &Pose<T>, to: &Pose<T>, turning_radius: T) -> T {
+    shortest_path(from, to, turning_radius).length()
+}
+
+// Birim yarıçapta işaretli parça uzunlukları; negatif uzunluk geri sürülür
+type Word = Vec<(Steering, f64)>;
+
+fn mod2pi(x: f64) -> f64 {
+    let v = x % TAU;
+    if v < -PI {
+        v + TAU
+    } else if v > PI {
+        v - TAU
+    } else {
+        v
+    }
+}
+
+fn polar(x: f64, y: f64) -> (f64, f64) {
+    (x.hypot(y), y.atan2(x))
+}
+
+fn tau_omega(u: f64, v: f64, xi: f64, eta: f64, phi: f64) -> (f64, f64) {
+    let delta = mod2pi(u - v);
+    let a = u.sin() - delta.sin();
+    let b = u.cos() - delta.cos() - 1.0;
+    let t1 = (eta * a - xi * b).atan2(xi * a + eta * b);
+    let t2 = 2.0 * (delta.cos() - v.cos() - u.cos()) + 3.0;
+    let tau = if t2 < 0.0 { mod2pi(t1 + PI) } else { mod2pi(t1) };
+    (tau, mod2pi(tau - u + v - phi))
+}
+
+// Ailelerin temel formülleri `(t, u, v)` döndürür; adlardaki p/m parçanın ileri/geri sürüldüğünü,
+// u/um ise aynı `u` uzunluğunun ileri/geri kullanıldığını gösterir
+type Formula = fn(f64, f64, f64) -> Option<(f64, f64, f64)>;
+
+fn lp_sp_lp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (u, t) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    let v = mod2pi(phi - t);
+    (t >= -ZERO && v >= -ZERO).then_some((t, u, v))
+}
+
+fn lp_sp_rp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (u1, t1) = polar(x + phi.sin(), y - 1.0 - phi.cos());
+    let u1 = u1 * u1;
+    if u1 < 4.0 {
+        return None;
+    }
+    let u = (u1 - 4.0).sqrt();
+    let t = mod2pi(t1 + 2f64.atan2(u));
+    let v = mod2pi(t - phi);
+    (t >= -ZERO && v >= -ZERO).then_some((t, u, v))
+}
+
+fn lp_rm_l(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (u1, theta) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if u1 > 4.0 {
+        return None;
+    }
+    let u = -2.0 * (0.25 * u1).asin();
+    let t = mod2pi(theta + 0.5 * u + PI);
+    let v = mod2pi(phi - t + u);
+    (t >= -ZERO && u <= ZERO).then_some((t, u, v))
+}
+
+fn lp_rup_lum_rm(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let rho = 0.25 * (2.0 + xi.hypot(eta));
+    if rho > 1.0 {
+        return None;
+    }
+    let u = rho.acos();
+    let (t, v) = tau_omega(u, -u, xi, eta, phi);
+    (t >= -ZERO && v <= ZERO).then_some((t, u, v))
+}
+
+fn lp_rum_lum_rp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let rho = (20.0 - xi * xi - eta * eta) / 16.0;
+    if !(0.0..=1.0).contains(&rho) {
+        return None;
+    }
+    let u = -rho.acos();
+    if u < -FRAC_PI_2 {
+        return None;
+    }
+    let (t, v) = tau_omega(u, u, xi, eta, phi);
+    (t >= -ZERO && v >= -ZERO).then_some((t, u, v))
+}
+
+fn lp_rm_sm_lm(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (rho, theta) = polar(x - phi.sin(), y - 1.0 + phi.cos());
+    if rho < 2.0 {
+        return None;
+    }
+    let r = (rho * rho - 4.0).sqrt();
+    let u = 2.0 - r;
+    let t = mod2pi(theta + r.atan2(-2.0));
+    let v = mod2pi(phi - FRAC_PI_2 - t);
+    (t >= -ZERO && u <= ZERO && v <= ZERO).then_some((t, u, v))
+}
+
+fn lp_rm_sm_rm(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let (rho, theta) = polar(-eta, xi);
+    if rho < 2.0 {
+        return None;
+    }
+    let (t, u) = (theta, 2.0 - rho);
+    let v = mod2pi(t + FRAC_PI_2 - phi);
+    (t >= -ZERO && u <= ZERO && v <= ZERO).then_some((t, u, v))
+}
+
+fn lp_rm_s_lm_rp(x: f64, y: f64, phi: f64) -> Option<(f64, f64, f64)> {
+    let (xi, eta) = (x + phi.sin(), y - 1.0 - phi.cos());
+    let (rho, _) = polar(xi, eta);
+    if rho < 2.0 {
+        return None;
+    }
+    let u = 4.0 - (rho * rho - 4.0).sqrt();
+    if u > ZERO {
+        return None;
+    }
+    let t = mod2pi(((4.0 - u) * xi - 2.0 * eta).atan2(-2.0 * xi + (u - 4.0) * eta));
+    let v = mod2pi(t - phi);
+    (t >= -ZERO && v >= -ZERO).then_some((t, u, v))
+}
+
+// Bir formülün dört simetrik kullanımı: olduğu gibi, zamanı tersine çevrilmiş (`-x`, tüm
+// uzunluklar ters işaretli), yansıtılmış (`-y`, sol ve sağ yer değiştirir) ve ikisi birden.
+// `backwards` verilirse hedef ile başlangıcın rolleri değişir; parçalar ters sırayla okunur.
+fn family(x: f64, y: f64, phi: f64, formula: Formula, word: &str, lengths: fn(f64, f64, f64) -> Vec<f64>, backwards: bool) -> Vec<Word> {
+    let (x, y) = if backwards { (x * phi.cos() + y * phi.sin(), x * phi.sin() - y * phi.cos()) } else { (x, y) };
+    let mut out = Vec::new();
+    for (sx, sy, flip, reflect) in [(1.0, 1.0, 1.0, false), (-1.0, 1.0, -1.0, false), (1.0, -1.0, 1.0, true), (-1.0, -1.0, -1.0, true)] {
+        let Some((t, u, v)) = formula(sx * x, sy * y, sx * sy * phi) else {
+            continue;
+        };
+        let mut steps: Word = word
+            .chars()
+            .zip(lengths(t, u, v))
+            .map(|(c, l)| {
+                let steering = match (c, reflect) {
+                    ('S', _) => Steering::Straight,
+                    ('L', false) | ('R', true) => Steering::Left,
+                    _ => Steering::Right,
+                };
+                (steering, flip * l)
+            })
+            .collect();
+        if backwards {
+            steps.reverse();
+        }
+        out.push(steps);
+    }
+    out
+}
+
+fn csc(x: f64, y: f64, phi: f64, out: &mut Vec<Word>) {
+    out.extend(family(x, y, phi, lp_sp_lp, "LSL", |t, u, v| vec![t, u, v], false));
+    out.extend(family(x, y, phi, lp_sp_rp, "LSR", |t, u, v| vec![t, u, v], false));
+}
+
+fn ccc(x: f64, y: f64, phi: f64, out: &mut Vec<Word>) {
+    out.extend(family(x, y, phi, lp_rm_l, "LRL", |t, u, v| vec![t, u, v], false));
+    out.extend(family(x, y, phi, lp_rm_l, "LRL", |t, u, v| vec![t, u, v], true));
+}
+
+fn cccc(x: f64, y: f64, phi: f64, out: &mut Vec<Word>) {
+    out.extend(family(x, y, phi, lp_rup_lum_rm, "LRLR", |t, u, v| vec![t, u, -u, v], false));
+    out.extend(family(x, y, phi, lp_rum_lum_rp, "LRLR", |t, u, v| vec![t, u, u, v], false));
+}
+
+fn ccsc(x: f64, y: f64, phi: f64, out: &mut Vec<Word>) {
+    for backwards in [false, true] {
+        out.extend(family(x, y, phi, lp_rm_sm_lm, "LRSL", |t, u, v| vec![t, -FRAC_PI_2, u, v], backwards));
+        out.extend(family(x, y, phi, lp_rm_sm_rm, "LRSR", |t, u, v| vec![t, -FRAC_PI_2, u, v], backwards));
+    }
+}
+
+fn ccscc(x: f64, y: f64, phi: f64, out: &mut Vec<Word>) {
+    out.extend(family(x, y, phi, lp_rm_s_lm_rp, "LRSLR", |t, u, v| vec![t, -FRAC_PI_2, u, -FRAC_PI_2, v], false));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn reeds_shepp_paths_reach_the_target_pose_and_reverse_when_parking() {
+        let pose = |x: f32, y: f32, heading: f32| Pose { point: Point { x, y }, heading };
+        let origin = pose(0.0, 0.0, 0.0);
+
+        // Düz ileri ve düz geri tek parçadır
+        let forward = shortest_path(&origin, &pose(50.0, 0.0, 0.0), 10.0);
+        assert_eq!(forward.segments.len(), 1);
+        assert_eq!((forward.segments[0].steering, forward.segments[0].gear), (Steering::Straight, Gear::Forward));
+        assert_close(forward.length(), 50.0);
+        let backward = shortest_path(&origin, &pose(-50.0, 0.0, 0.0), 10.0);
+        assert!(matches!(backward.segments[..], [Segment { steering: Steering::Straight, gear: Gear::Reverse, .. }]));
+        assert_close(backward.length(), 50.0);
+
+        // Yerinde dönülemez: yalnızca yön değişse de araba ileri geri manevra yapıp yerine dönmelidir
+        let turn = shortest_path(&origin, &pose(0.0, 0.0, std::f32::consts::FRAC_PI_2), 10.0);
+        assert!(turn.segments.len() > 1 && turn.has_reverse(), "{:?}", turn);
+        assert!(turn.length() >= 10.0 * std::f32::consts::FRAC_PI_2 - 1e-3);
+
+        // Yan yana park: aynı yönde biraz yana kaymak geri vites gerektirir
+        let park = shortest_path(&origin, &pose(0.0, 4.0, 0.0), 10.0);
+        assert!(park.has_reverse(), "{:?}", park);
+        assert!(park.segments.iter().any(|s| s.gear == Gear::Forward));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..500 {
+            let mut random = || pose(rng.gen_range(-60.0..60.0), rng.gen_range(-60.0..60.0), rng.gen_range(-3.1..3.1));
+            let (a, b) = (random(), random());
+            let path = shortest_path(&a, &b, 10.0);
+            let end = path.end_pose(&a);
+            assert!(end.point.distance(&b.point) < 1e-2, "{:?} -> {:?}: {:?} ends at {:?}", a, b, path, end);
+            assert!((end.heading - b.heading).sin().abs() < 1e-3 && (end.heading - b.heading).cos() > 0.0);
+            // Eğri düz çizgiden kısa olamaz ve ters yönde aynı uzunluktadır
+            assert!(path.length() >= a.point.distance(&b.point) - 1e-3);
+            assert!((path.length() - distance(&b, &a, 10.0)).abs() < 1e-2 * path.length().max(1.0));
+            let samples = path.sample(&a, 1.0);
+            assert!(samples.windows(2).all(|w| w[0].0.point.distance(&w[1].0.point) <= 1.0 + 1e-4));
+            assert!(samples[samples.len() - 1].0.point.distance(&end.point) < 1e-3);
+        }
+    }
+}
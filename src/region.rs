@@ -0,0 +1,60 @@
+// Örnekleme bölgeleri: planlayıcının rastgele örnekleri çektiği alanın biçimi
+use rand::{Rng, RngCore};
+
+use crate::obstacle::Bounds;
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+use crate::state::State;
+
+// Düzgün örneklemenin yapıldığı bölge; dikdörtgen olmayan çalışma alanları için verilebilir
+pub trait SamplingRegion<T: Scalar = f32>: Send + Sync {
+    fn contains(&self, p: &Point<T>) -> bool;
+
+    // Bölge içinde düzgün dağılımlı nokta
+    fn sample(&mut self, rng: &mut dyn RngCore) -> Point<T>;
+}
+
+// Alanın dikdörtgeni; bölge verilmediğinde kullanılan ve bugüne kadarki sayı dizisini üreten bölge
+#[derive(Clone, Copy, Debug)]
+pub struct RectRegion<T = f32> {
+    pub bounds: Bounds<T>,
+}
+
+impl<T: Scalar> SamplingRegion<T> for RectRegion<T> {
+    fn contains(&self, p: &Point<T>) -> bool {
+        self.bounds.contains(p)
+    }
+
+    fn sample(&mut self, rng: &mut dyn RngCore) -> Point<T> {
+        Point::sample_uniform(&self.bounds, rng)
+    }
+}
+
+// Merkezine en fazla `radius` uzaklıktaki noktalardan oluşan daire
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CircleRegion<T = f32> {
+    pub center: Point<T>,
+    pub radius: T,
+}
+
+impl<T: Scalar> SamplingRegion<T> for CircleRegion<T> {
+    fn contains(&self, p: &Point<T>) -> bool {
+        p.distance(&self.center) <= self.radius
+    }
+
+    // Çevreleyen kareden çekilip daire dışında kalanlar reddedilir (ortalama 4/π deneme); çekilen
+    // nokta böylece `contains` ile aynı ölçütle her zaman dairenin içindedir. Yarıçap pozitif ve
+    // sonlu değilse merkez döner.
+    fn sample(&mut self, rng: &mut dyn RngCore) -> Point<T> {
+        let (c, r) = (self.center, self.radius);
+        if !r.is_finite() || r <= T::zero() {
+            return c;
+        }
+        loop {
+            let p = Point { x: rng.gen_range(c.x - r..c.x + r), y: rng.gen_range(c.y - r..c.y + r) };
+            if self.contains(&p) {
+                return p;
+            }
+        }
+    }
+}
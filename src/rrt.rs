@@ -0,0 +1,5065 @@
+use std::fmt;
+use std::io::{Read, Write};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::time::Duration;
+
+use macroquad::math::Vec2;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{Clock, SystemClock};
+use crate::fleet::MovingRobot;
+use crate::config::{BiasSchedule, ClearanceCost, ConfigError, ForestGrowth, MapLayout, NeighborIndexKind, NodeCapPolicy, PotentialField, RRTConfig, SamplerKind, StepPolicy};
+use crate::coverage::CoverageGrid;
+use crate::goal::GoalRegion;
+use crate::grid::CellFrame;
+use crate::heatmap::SampleHeatmap;
+use crate::mapgen::scenario_obstacles;
+use crate::obstacle::{Bounds, CollisionChecker, DynamicObstacle, Obstacle, RobotShape};
+use crate::observer::{PlannerObserver, RejectReason};
+use crate::path::{Path, PathError, Pose};
+use crate::region::{RectRegion, SamplingRegion};
+use crate::scalar::Scalar;
+use crate::search::astar;
+use num_traits::clamp;
+use crate::spatial::{sorted_indices, KdTree, SpatialGrid};
+use crate::state::State;
+use crate::terrain::{CostMap, TerrainSpec};
+
+// Tohum verilmişse belirlenimci, verilmemişse işletim sistemi entropisiyle bir üreteç oluşturur.
+// Web hedefinde entropi kaynağı olmadığından tohumun çağıran tarafından verilmesi beklenir.
+pub(crate) fn seeded_rng<R: SeedableRng>(seed: Option<u64>) -> R {
+    match seed {
+        Some(seed) => R::seed_from_u64(seed),
+        #[cfg(not(target_arch = "wasm32"))]
+        None => R::from_entropy(),
+        #[cfg(target_arch = "wasm32")]
+        None => R::seed_from_u64(0),
+    }
+}
+
+// İki boyutlu bir noktayı temsil eden yapı; koordinat tipi varsayılan olarak f32'dir
+#[derive(Clone, Copy, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Point<T = f32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Scalar> Point<T> {
+    // İki nokta arasındaki öklid mesafesini hesaplayan fonksiyon
+    pub fn distance(&self, other: &Point<T>) -> T {
+        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+    }
+
+    // İki koordinat da NaN veya sonsuz değilse doğru
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    // Noktaları vektör olarak ele alıp iç çarpımlarını hesaplar
+    pub fn dot(&self, other: &Point<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    // Orijine uzaklık (vektörün boyu)
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    // Karekök almadan boyun karesi; yalnızca karşılaştırma yapılacaksa daha ucuzdur
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+
+    // Aynı yöndeki birim vektör; sıfır vektörün yönü olmadığından `None` döner
+    pub fn normalize(&self) -> Option<Point<T>> {
+        let length = self.length();
+        (length > T::zero()).then(|| *self * (T::one() / length))
+    }
+
+    // `a` ile `b` arasında doğrusal ara değer: t = 0'da `a`, t = 1'de `b`
+    pub fn lerp(a: Point<T>, b: Point<T>, t: T) -> Point<T> {
+        a + (b - a) * t
+    }
+}
+
+impl<T: Scalar> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: Scalar> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: Scalar> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, scale: T) -> Point<T> {
+        Point { x: self.x * scale, y: self.y * scale }
+    }
+}
+
+impl<T: Scalar> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+// Çizim kodu noktaları macroquad vektörlerine dönüştürerek kullanır
+impl From<Vec2> for Point {
+    fn from(v: Vec2) -> Point {
+        Point { x: v.x, y: v.y }
+    }
+}
+
+impl From<Point> for Vec2 {
+    fn from(p: Point) -> Vec2 {
+        Vec2::new(p.x, p.y)
+    }
+}
+
+// Düğüm yapısı, bir nokta, ebeveyn indeksini, kökten itibaren yol maliyetini ve derinliği içerir
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Node<T = f32> {
+    pub point: Point<T>,
+    pub parent: Option<usize>,
+    pub cost: T,
+    pub depth: usize, // Köke olan kenar sayısı
+    pub nearest_count: usize, // Örneklere en yakın düğüm olarak kaç kez seçildiği; az seçilenler sınırdadır
+    pub time: T, // Robotun kökten yola çıkıp bu düğüme vardığı an; hareketli engeller bu andaki konumlarıyla kontrol edilir
+    pub edge_checked: bool, // Ebeveyne giden kenarın çarpışma kontrolü yapıldı mı (tembel mod)
+    pub domain_radius: T, // Dinamik alan örneklemesinde görünürlük yarıçapı; başarısız genişleme olana kadar sonsuz
+    pub root: usize, // Düğümün büyüdüğü kök; kökler düğüm listesinin başında durduğundan hem kök düğümün hem `starts` içindeki başlangıcın indeksidir
+}
+
+impl<T: Scalar> Node<T> {
+    // Yeni bir düğüm oluşturur, noktayı ve ebeveynini alır; maliyet ağaca eklenirken hesaplanır
+    pub fn new(point: Point<T>, parent: Option<usize>) -> Self {
+        Node { point, parent, cost: T::zero(), depth: 0, nearest_count: 0, time: T::zero(), edge_checked: true, domain_radius: T::infinity(), root: 0 }
+    }
+
+    // Ormanın `root` numaralı kökü
+    fn root(point: Point<T>, root: usize) -> Self {
+        Node { root, ..Node::new(point, None) }
+    }
+}
+
+// Tembel çarpışma kontrolünün kazancını ölçmek için sayaçlar
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct LazyStats {
+    pub checks_performed: usize, // Yapılan kenar kontrolleri
+    pub checks_deferred: usize, // Eklenirken ertelenen kenar kontrolleri
+    pub checks_validated_later: usize, // Ertelenip yol doğrulanırken yapılan kontroller
+    pub subtrees_pruned: usize, // Geçersiz kenar nedeniyle budanan alt ağaçlar
+}
+
+// Ağacın biçimini özetleyen sayılar: uzun zincirler (derin, dallanması az) adım boyunun küçük
+// kaldığını, gür ağaçlar (sığ, dallanması çok) alanın iyi kaplandığını gösterir
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TreeStats {
+    pub max_depth: usize,
+    pub mean_depth: f32,
+    pub max_branching: usize, // Bir düğümün en fazla çocuk sayısı
+    pub mean_branching: f32, // Yaprak olmayan düğümlerin ortalama çocuk sayısı
+    pub leaves: usize,
+}
+
+// `RRT::write_binary` dosyalarının ilk baytları ve düğüm kaydının boyu
+const BINARY_MAGIC: &[u8; 4] = b"RRT1";
+const BINARY_RECORD_SIZE: usize = 12;
+
+// Kenar uzunluğu histogramının kutu sayısı
+pub const EDGE_LENGTH_BINS: usize = 10;
+
+// Farklı parametrelerin ürettiği ağaçları karşılaştırmak için ayrıntılı tanım: biçim özeti,
+// derinlik dağılımı ve kenar uzunluklarının dağılımı. Uzunluk histogramı `[0, en uzun kenar]`
+// aralığını `EDGE_LENGTH_BINS` eşit kutuya böler; en uzun kenar son kutuya düşer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TreeStatistics<T = f32> {
+    pub nodes: usize,
+    pub shape: TreeStats,
+    pub depth_histogram: Vec<usize>, // `d`. eleman `d` derinliğindeki düğüm sayısı
+    pub min_edge_length: T,
+    pub mean_edge_length: T,
+    pub max_edge_length: T,
+    pub edge_length_histogram: Vec<usize>, // Ağaçta kenar yoksa boş
+}
+
+// Oturum dosyası: ağacı kuran parametreler (`RRT::to_config`) ve yapılandırmadan yeniden
+// kurulamayan planlama durumu. Üretecin konumu da yazıldığından yüklenen ağaç kesintisiz bir
+// çalışmayla aynı örnek dizisini çekmeye devam eder.
+#[derive(Deserialize, Serialize)]
+struct Session {
+    config: RRTConfig,
+    state: SessionState,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SessionState {
+    iteration: usize,
+    nodes: Vec<Node>,
+    goal_node: Option<usize>,
+    best_cost: Option<f32>,
+    bnb_best_cost: f32,
+    graph_edges: Vec<(usize, usize)>,
+    graph_edge_costs: Vec<f32>,
+    waypoint_cache: Vec<Point>,
+    lazy_stats: LazyStats,
+    samples_total: usize,
+    samples_rejected: usize,
+    domain_resamples: usize,
+    bnb_nodes_pruned: usize,
+    bnb_extensions_rejected: usize,
+    goal_connect_attempts: usize,
+    cost_limit_rejections: usize,
+    saturated: bool,
+    nodes_pruned: usize,
+    max_depth: usize,
+    max_cost: f32,
+    best_goal_distance: f32,
+    best_goal_node: usize,
+    best_goal_iteration: usize,
+    roots_reached: Vec<(usize, usize)>, // (kök, hedefe ulaştığı iterasyon); TOML dizileri boş değer tutamaz
+    next_root: usize,
+    // ChaCha üretecinin tohumu, akışı ve kelime konumu; TOML tamsayıları 64 bitlik işaretli
+    // olduğundan son ikisi metin olarak yazılır
+    rng_seed: [u8; 32],
+    rng_stream: String,
+    rng_word_pos: String,
+}
+
+// Ormandaki bir kökün hedefe ulaşıp ulaşmadığı: aday başlangıç noktalarından hangisinin hedefe en
+// ucuz ulaştığını karşılaştırmak için
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootReport<T = f32> {
+    pub root: usize,
+    pub start: Point<T>,
+    pub reached_at: Option<usize>, // Kökün ağacının hedef bölgesine ilk ulaştığı iterasyon
+    pub goal_node: Option<usize>, // Kökün ağacında hedef bölgesindeki en ucuz düğüm
+    pub cost: Option<T>,
+    pub path: Option<Path<T>>, // Kökten `goal_node` düğümüne ağaç kenarları boyunca giden yol
+}
+
+impl LazyStats {
+    // Hiç yapılmasına gerek kalmayan kontrol sayısı
+    pub fn checks_saved(&self) -> usize {
+        self.checks_deferred - self.checks_validated_later
+    }
+}
+
+// Bir yolun toplam uzunluğu
+pub fn path_length<T: Scalar>(path: &[Point<T>]) -> T {
+    path.windows(2).map(|w| w[0].distance(&w[1])).fold(T::zero(), |total, d| total + d)
+}
+
+// Yolun her parçasının en yakın engele mesafesi; engel yoksa sonsuzdur. Tek noktalı yolda o
+// noktanın mesafesi döner. Robot yarıçapı çıkarılmaz, ölçülen engel geometrisine uzaklıktır.
+pub fn segment_clearances<T: Scalar>(path: &[Point<T>], obstacles: &[Obstacle<T>]) -> Vec<T> {
+    let clearance = |a: &Point<T>, b: &Point<T>| {
+        obstacles.iter().map(|o| o.segment_distance(a, b)).fold(T::infinity(), T::min)
+    };
+    match path {
+        [p] => vec![clearance(p, p)],
+        _ => path.windows(2).map(|w| clearance(&w[0], &w[1])).collect(),
+    }
+}
+
+// Yolun herhangi bir engele en çok yaklaştığı mesafe
+pub fn path_clearance<T: Scalar>(path: &[Point<T>], obstacles: &[Obstacle<T>]) -> T {
+    segment_clearances(path, obstacles).into_iter().fold(T::infinity(), T::min)
+}
+
+// Tek bir genişletme iterasyonunun ara sonuçları
+#[derive(Clone, Copy, Debug)]
+pub struct Extension<T = f32> {
+    pub sample: Point<T>, // Çekilen örnek
+    pub nearest: usize, // Örneğe en yakın düğüm
+    pub parent: usize, // Yeni düğüme ebeveyn olarak seçilen düğüm
+    pub new_point: Point<T>, // Yönlendirme sonucu ulaşılan nokta
+    pub added: Option<usize>, // Çarpışma yoksa eklenen düğümün indeksi
+    pub full: bool, // Düğüm sınırına ulaşıldığı ve yer açılamadığı için nokta denenmedi
+    pub reached_goal: bool, // Eklenen düğümlerden biri (bölünmüş kenarda bir ara düğüm olabilir) ilk hedef düğümü oldu
+}
+
+// `extend` içinde denetlenmiş, henüz ağaca işlenmemiş aday
+struct Candidate<T> {
+    sample: Point<T>,
+    nearest: usize,
+    new_point: Point<T>,
+    out_of_bound: bool,
+    over_budget: bool, // En yakın düğümden bağlanınca maliyet bütçesi aşılıyor
+    edge_ok: bool, // Çarpışma kontrolünden geçti (açıklık testi eklenirken yapılır)
+}
+
+// `RRT::step` ile çalıştırılan bir iterasyonun sonucu
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepResult {
+    Extended { node: usize }, // Ağaca düğüm eklendi, hedefe ulaşılmadı
+    Rejected, // Yönlendirilen nokta çarpıştı veya sınır dışında kaldı
+    GoalReached { node: usize }, // Eklenen düğüm hedef bölgesine ilk ulaşan düğüm oldu
+    Exhausted, // Ağaç düğüm sınırında; yer açılamadığından büyüyemez
+}
+
+// `RRT::run` tarafından döndürülen, iterasyonları sırasıyla çalıştıran yineleyici
+pub struct Run<'a, T = f32> {
+    rrt: &'a mut RRT<T>,
+    remaining: usize,
+    finished: bool,
+}
+
+impl<T: Scalar> Iterator for Run<'_, T> {
+    type Item = StepResult;
+
+    // Hedefe ulaşıldığında veya ağaç büyüyemez olduğunda o sonuç döner ve yineleme biter. Tembel
+    // modda aday yol burada doğrulanır; geçersiz çıktıysa iterasyon reddedilmiş sayılır.
+    fn next(&mut self) -> Option<StepResult> {
+        if self.finished || self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let result = match self.rrt.step() {
+            StepResult::GoalReached { .. } => {
+                self.rrt.validate_goal_path();
+                self.rrt.goal_node.map_or(StepResult::Rejected, |node| StepResult::GoalReached { node })
+            }
+            result => result,
+        };
+        self.finished = matches!(result, StepResult::GoalReached { .. } | StepResult::Exhausted);
+        Some(result)
+    }
+}
+
+// Son iterasyonun hata ayıklama katmanlarında gösterilen ara verileri
+#[derive(Clone, Copy, Debug)]
+pub struct LastStepInfo<T = f32> {
+    pub sample: Point<T>,
+    pub nearest: usize,
+    pub steered: Point<T>,
+    pub accepted: bool, // Yönlendirilen nokta çarpışmasız bulunup ağaca eklendi mi
+    pub nodes_added: usize, // Eklenen düğüm sayısı; kenar `max_edge_length` ile bölündüyse birden fazladır, son düğüm yönlendirilen noktadır
+}
+
+// Ekransız planlamanın özet sayıları
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlanStats {
+    pub iterations: usize,
+    pub nodes: usize,
+    pub budget_exhausted: bool, // Planlama iterasyon sınırı yerine zaman bütçesi dolduğu için durdu
+    // Hedefe en çok yaklaşan düğümün uzaklığı ve bu uzaklığa ulaşılan iterasyon; yol bulunamayan
+    // denemelerde kıl payı kaçanları tamamen başarısız olanlardan ayırır. Ağaç kurmayan
+    // planlayıcılar yol bulunduysa sıfır, bulunamadıysa sonsuz verir.
+    pub best_goal_distance: f32,
+    pub best_goal_iteration: usize,
+    // Çarpışma kontrolüne giren örnekler ve bunlardan reddedilenler; ret oranının yüksek olması
+    // haritanın çok dolu veya robot yarıçapının çok büyük olduğunu gösterir
+    pub samples_total: usize,
+    pub samples_rejected: usize,
+}
+
+impl PlanStats {
+    // Reddedilen örneklerin oranı [0, 1]; hiç örnek kontrol edilmediyse sıfır
+    pub fn rejection_rate(&self) -> f32 {
+        if self.samples_total == 0 {
+            0.0
+        } else {
+            self.samples_rejected as f32 / self.samples_total as f32
+        }
+    }
+}
+
+// Ekransız planlamanın sonucu
+#[derive(Clone, Debug)]
+pub enum PlanResult<T = f32> {
+    Found { path: Path<T>, stats: PlanStats },
+    NotFound { stats: PlanStats },
+}
+
+impl<T> PlanResult<T> {
+    pub fn stats(&self) -> &PlanStats {
+        match self {
+            PlanResult::Found { stats, .. } | PlanResult::NotFound { stats } => stats,
+        }
+    }
+
+    pub fn path(&self) -> Option<&Path<T>> {
+        match self {
+            PlanResult::Found { path, .. } => Some(path),
+            PlanResult::NotFound { .. } => None,
+        }
+    }
+}
+
+// Planlamaya başlamadan saptanan, hiçbir iterasyonun düzeltemeyeceği uç nokta hatası
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlanError {
+    StartInObstacle, // Hiçbir başlangıç (robot yarıçapıyla) çarpışmasız değil veya alan içinde değil
+    GoalUnreachableInObstacle, // Hiçbir hedef (robot yarıçapıyla) çarpışmasız değil veya alan içinde değil
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::StartInObstacle => write!(f, "the start is inside an obstacle or outside the bounds; move it into free space"),
+            PlanError::GoalUnreachableInObstacle => write!(f, "the goal is inside an obstacle or outside the bounds; move it into free space"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+// Serbest alanda uç nokta ararken yapılan en fazla deneme
+const FREE_POINT_ATTEMPTS: usize = 1000;
+
+// Uç noktalar için çarpışmasız yer bulunamadığında dönen hata
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FreeSpaceError {
+    pub attempts: usize,
+}
+
+impl fmt::Display for FreeSpaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no collision-free point found in {} attempts; free space is too small", self.attempts)
+    }
+}
+
+impl std::error::Error for FreeSpaceError {}
+
+// Dinamik alan örneklemesinde bir iterasyonda en fazla bu kadar örnek reddedilir
+const DYNAMIC_DOMAIN_MAX_RESAMPLES: usize = 100;
+
+// Açıklık maliyetinde yeni düğümün ebeveyni bu kadar adım boyutu uzaklıktaki düğümler arasından seçilir
+const CHEAPEST_PARENT_RADIUS_STEPS: f64 = 2.0;
+
+// Düğüm sınırında budama yapılırken sınırın bu oranı kadar yaprak birlikte silinir; böylece
+// sıkıştırma ve indeks yeniden kurulumu her iterasyonda değil seyrek olarak yapılır
+const NODE_CAP_PRUNE_FRACTION: f64 = 0.1;
+
+// Sınır örneklemesinde örnek bu kadar düzgün aday arasından seçilir
+const FRONTIER_CANDIDATES: usize = 8;
+
+// Ret örneklemesinde engel içine düşen düzgün örnek en fazla bu kadar kez çekilir; serbest alan
+// çok küçükse sonsuz döngüye girilmez
+const REJECTION_SAMPLING_ATTEMPTS: usize = 100;
+
+// Ara nokta çevresinden çekilen örnek bu kadar denemede alana düşmezse düzgün örneğe dönülür
+const WAYPOINT_ATTEMPTS: usize = 16;
+
+// Varsayılan k-d ağacı yeniden dengeleme oranı
+const KD_TREE_REBALANCE_FRACTION: f64 = 0.3;
+
+// Potansiyel alanın karışık vektörü kazançlar toplamının bu oranından kısaysa yönsüz sayılır
+const FIELD_CANCEL_TOLERANCE: f64 = 1e-3;
+
+// Yol doğrulamasında parça uzunluğu en uzun kenarı bu oran kadar aşabilir (kayan nokta hatası)
+const PATH_SEGMENT_TOLERANCE: f64 = 1e-3;
+
+// Çalışırken değiştirilebilen parametrelerin geçerli aralıkları
+pub const STEP_SIZE_RANGE: (f32, f32) = (0.5, 100.0);
+pub const GOAL_THRESHOLD_RANGE: (f32, f32) = (0.5, 100.0);
+
+// En yakın düğüm aramasının indeksi; düğümler eklendikçe güncellenir
+enum NeighborIndex<T> {
+    Linear,
+    Grid(SpatialGrid<T>),
+    KdTree(KdTree<T>),
+}
+
+// RRT ağacını tanımlayan yapı
+pub struct RRT<T = f32> {
+    pub(crate) nodes: Vec<Node<T>>, // Ağacın düğümleri
+    pub goals: Vec<Point<T>>, // Kabul edilen hedefler; en az bir tane bulunur, ilki birincil hedeftir
+    pub starts: Vec<Point<T>>, // Ağacın kökleri; düğüm listesinin başında bu sırayla durur, ilki birincil başlangıçtır
+    pub forest_growth: ForestGrowth, // Birden çok kök varken genişletilecek düğümün seçimi
+    pub root_goal_iterations: Vec<Option<usize>>, // Kök başına, o kökün ağacının hedef bölgesine ilk ulaştığı iterasyon
+    next_root: usize, // Sıralı büyümede sonraki örneğin gideceği kök
+    step_size: T, // Adım boyutu
+    pub step_policy: StepPolicy, // Sabit değilse adım uzunluğu kaynak noktanın açıklığına göre belirlenir
+    pub potential_field: Option<PotentialField>, // Verilmişse adımın yönü örnek, hedef ve en yakın engelden gelen vektörlerin karışımıdır
+    pub max_edge_length: Option<T>, // Verilmişse genişletmede daha uzun kenarlar (ör. her açılı ebeveyne) eşit ara düğümlere bölünür, çapraz kenarlar bu uzunlukla sınırlanır
+    goal_threshold: T, // Hedef eşiği
+    goal_bias: T, // Hedefin doğrudan örneklenme olasılığı
+    pub bias_schedule: BiasSchedule, // Sabit değilse `goal_bias` yerine iterasyona göre hesaplanır
+    pub iteration: usize, // Sıfırlamadan bu yana tamamlanan `extend` çağrıları
+    pub bounds: Bounds<T>, // Örnekleme alanı
+    sampler: SamplerKind, // Örnekleme stratejisi
+    pub frontier_bias: T, // Sınır örneklemesinde örneğin sınır düğümlerine yönlendirilme olasılığı
+    pub waypoint_cache: Vec<Point<T>>, // Önceki planlama döngüsünün yolu; sıfırlamada korunur
+    pub waypoint_bias: T, // Örneğin önbellekteki bir ara noktanın çevresinden çekilme olasılığı
+    pub waypoint_sigma: T, // Ara nokta çevresindeki örneklerin standart sapması
+    pub obstacles: Vec<Obstacle<T>>, // Haritadaki engeller
+    pub dynamic_obstacles: Vec<DynamicObstacle<T>>, // Zamanla hareket eden engeller
+    pub moving_robots: Vec<MovingRobot<T>>, // Önceden planlanmış robotlar; zamanlanmış yollarıyla hareketli engel sayılır
+    pub speed: T, // Robotun hızı; düğümlerin varış zamanı kenar uzunluğu / hız kadar ilerler
+    pub collision_resolution: T, // Kenar kontrolünde ardışık örnekler arasındaki mesafe
+    pub adaptive_collision: bool, // Kenarlar engel mesafesine göre uyarlamalı adımlarla kontrol edilir
+    robot_radius: T, // Robotun yarıçapı; engeller bu kadar şişirilerek kontrol edilir
+    pub robot_shape: RobotShape<T>, // Çokgense kenarlar robotun taradığı alanla kontrol edilir ve `robot_radius` kullanılmaz
+    pub clearance_weight: T, // Sıfırdan büyükse kenar maliyetine engellere yakınlık cezası eklenir
+    pub terrain: Option<CostMap<T>>, // Verilmişse kenar uzunluğu kenar boyunca ortalama arazi çarpanıyla çarpılır
+    pub terrain_bias: T, // Arazi varken örneğin hücre maliyetiyle ters orantılı çekilme olasılığı
+    pub clearance_distance: T, // Engele bundan yakın geçen kenarlar cezalandırılır
+    pub clearance_cost: ClearanceCost, // Açıklık cezasının biçimi
+    goal_node: Option<usize>, // Hedef bölgesine ilk ulaşan düğüm; `best_cost` ile birlikte güncellenmesi için `set_goal_node` ile atanır
+    pub any_angle: bool, // Yeni düğümü görüş hattı olan en uzak atasına bağlar
+    pub any_angle_depth: usize, // Görüş hattı aranacak en fazla ata sayısı
+    pub cross_edges: bool, // Yeni düğüm yarıçap içindeki görünür düğümlere de bağlanır (ağaç bir çizgeye dönüşür); hareketli engeller varken kullanılmaz
+    pub cross_edge_radius: T, // Çapraz kenar aranan yarıçap
+    pub graph_edges: Vec<(usize, usize)>, // Ebeveyn bağlantıları dışındaki çapraz kenarlar (eski düğüm, yeni düğüm)
+    graph_edge_costs: Vec<T>, // `graph_edges` ile aynı sırada kenar maliyetleri; en kısa yol aramasında yeniden örneklenmez
+    pub lazy_collision: bool, // Kenarlar kontrol edilmeden eklenir, yalnızca aday yol doğrulanır
+    pub lazy_stats: LazyStats,
+    pub samples_total: usize, // Yönlendirilen noktası çarpışma kontrolünden geçirilen örnekler; doymuş ağaçta ve dal-sınırda reddedilenler sayılmaz
+    pub samples_rejected: usize, // Bunlardan çarpışma (veya açıklık) testinde reddedilip ağaca eklenmeyenler
+    pub dynamic_domain: bool, // Örnekler en yakın düğümün görünürlük yarıçapı içinde tutulur
+    pub dynamic_domain_radius: T, // İlk başarısız genişlemede düğüme atanan yarıçap
+    pub dynamic_domain_shrink: T, // Sonraki her başarısız genişlemede yarıçapın çarpıldığı oran
+    pub domain_resamples: usize, // Dinamik alan dışında kaldığı için yeniden çekilen örnekler
+    pub rejection_sampling: bool, // Düzgün örnekler çarpışmasız olana kadar (en fazla `REJECTION_SAMPLING_ATTEMPTS` kez) çekilir
+    pub branch_and_bound: bool, // Yol bulunduktan sonra en iyi yolu iyileştiremeyecek düğümler budanır
+    pub branch_and_bound_interval: usize, // Budama geçişleri arasındaki iterasyon sayısı
+    pub bnb_nodes_pruned: usize, // Dal-sınır budamasında silinen düğümler
+    pub bnb_extensions_rejected: usize, // Alt sınırı en iyi yolu aştığı için reddedilen genişlemeler
+    // Sıfırdan büyükse her `goal_connect_interval`. iterasyonda, genişleme kabul edildiyse yeni
+    // düğümden hedefe düz kenar denenir; 1 her iterasyonda dener. Kenar kontrolü pahalı olduğundan dolu haritalarda seyrek tutulur.
+    pub goal_connect_interval: usize,
+    pub goal_connect_attempts: usize, // Sıfırlamadan bu yana denenen hedef bağlantıları
+    // Verilmişse kökten maliyeti bu değeri aşacak düğümler eklenmez ve hedef bölgesindeki düğüm
+    // ancak maliyeti bütçe içindeyse hedefe ulaşmış sayılır; bütçeyi aşan bir dal hedefe değse bile
+    // planlayıcı bütçe içinde bir yol aramaya devam eder
+    pub max_path_cost: Option<T>,
+    pub cost_limit_rejections: usize, // Maliyet bütçesini aştığı için kenar kontrolünden önce reddedilen genişlemeler
+    pub max_nodes: Option<usize>, // Verilmişse ağaç bu kadar düğümü aşmaz
+    pub node_cap_policy: NodeCapPolicy,
+    pub saturated: bool, // Düğüm sınırına ulaşıldı ve yer açılamadığı için düğüm eklenmedi
+    pub nodes_pruned: usize, // Düğüm sınırı nedeniyle silinen yapraklar
+    pub last_step: Option<LastStepInfo<T>>, // Son `extend` çağrısının ara verileri
+    pub max_depth: usize, // Ağaçtaki en büyük düğüm derinliği; renklendirmede normalleştirme için
+    pub max_cost: T, // Ağaçtaki en büyük kökten maliyet
+    longest_edge: T, // Ağaçtaki en uzun ebeveyn kenarı; adım boyu sonradan küçülse de yol denetimi buna göre yapılır
+    pub best_goal_distance: T, // Şimdiye kadar hedefe en çok yaklaşan düğümün en yakın hedefe uzaklığı
+    pub best_goal_node: usize, // Bu uzaklığa sahip düğüm
+    pub best_goal_iteration: usize, // Bu uzaklığa ulaşıldığında tamamlanmış iterasyon sayısı
+    pub time_budget: Option<Duration>, // Verilmişse ekransız ve kare başına planlama bu süre sonunda durur
+    pub batch_size: usize, // Birden büyükse ekransız planlama iterasyonları bu kadarlık toplularla çalıştırır (`extend_batch`)
+    pub heatmap: Option<SampleHeatmap>, // Verilmişse çekilen her örnek (reddedilenler dahil) sayılır
+    pub coverage: Option<CoverageGrid<T>>, // Verilmişse eklenen her düğümün düştüğü hücre kaplanmış sayılır; `set_coverage_resolution` ile kurulur
+    pub sample_source: Option<Box<dyn Iterator<Item = Point<T>> + Send + Sync>>, // Verilmişse örnekler rastgele sayı üreteci yerine buradan alınır; bitince üretece dönülür
+    neighbor_index: NeighborIndex<T>, // `find_nearest` tarafından kullanılan indeks
+    kd_tree_rebalance: T, // k-d ağacı, son kurulumdan beri eklenen düğümler boyunun bu oranını aşınca dengeli olarak yeniden kurulur
+    goal_region: Option<Box<dyn GoalRegion<T>>>, // Verilmişse hedef noktaları ve eşik yerine kullanılır
+    sampling_region: Option<Box<dyn SamplingRegion<T>>>, // Verilmişse düzgün örnekler alanın dikdörtgeni yerine buradan çekilir
+    observer: Option<Box<dyn PlannerObserver<T>>>, // Verilmişse örnekler, eklenen ve reddedilen düğümler bildirilir
+    best_cost: Option<T>, // Hedef düğümüne giden en iyi yolun maliyeti; hedef düğümü veya çapraz kenarlar değiştikçe yenilenir
+    bnb_best_cost: T, // Son dal-sınır geçişindeki en iyi yol maliyeti; genişlemeler buna göre reddedilir
+    // Rastgele sayı üreteci; planlayıcıya aittir ve `Send + Sync` olduğundan planlayıcı iş
+    // parçacıkları arasında taşınabilir. `StdRng` ile aynı üreteçtir (aynı tohumla aynı dizi), ancak
+    // konumu okunup geri yüklenebildiğinden oturum dosyasına yazılabilir.
+    rng: ChaCha12Rng,
+}
+
+impl<T: Scalar> RRT<T> {
+    // Başlangıç ve hedef noktalar, adım boyutu ve hedef eşiği ile yeni bir RRT ağacı oluşturur
+    pub fn new(start: Point<T>, goal: Point<T>, step_size: T, goal_threshold: T) -> Self {
+        RRT::with_capacity(start, goal, step_size, goal_threshold, 1)
+    }
+
+    // `new` ile aynıdır, ancak düğüm vektörü `capacity` düğüm için baştan ayrılır. Büyüyen ağacın
+    // tekrar tekrar yeniden ayırma yapmasını (görselleştirmede kare takılmalarını) önler.
+    pub fn with_capacity(
+        start: Point<T>,
+        goal: Point<T>,
+        step_size: T,
+        goal_threshold: T,
+        capacity: usize,
+    ) -> Self {
+        let mut nodes = Vec::with_capacity(capacity.max(1));
+        nodes.push(Node::new(start, None)); // Başlangıç düğümünü kök olarak ekler
+        RRT {
+            nodes,
+            goals: vec![goal],
+            starts: vec![start],
+            forest_growth: ForestGrowth::Nearest,
+            root_goal_iterations: vec![None],
+            next_root: 0,
+            step_size,
+            step_policy: StepPolicy::Fixed,
+            potential_field: None,
+            max_edge_length: None,
+            goal_threshold,
+            goal_bias: T::zero(),
+            bias_schedule: BiasSchedule::Constant,
+            iteration: 0,
+            bounds: Bounds::default(),
+            sampler: SamplerKind::Uniform,
+            frontier_bias: T::cast(0.8),
+            waypoint_cache: Vec::new(),
+            waypoint_bias: T::zero(),
+            waypoint_sigma: T::cast(10.0),
+            obstacles: Vec::new(),
+            dynamic_obstacles: Vec::new(),
+            moving_robots: Vec::new(),
+            speed: T::cast(50.0),
+            collision_resolution: T::one(),
+            adaptive_collision: false,
+            robot_radius: T::zero(),
+            robot_shape: RobotShape::Disk,
+            clearance_weight: T::zero(),
+            terrain: None,
+            terrain_bias: T::zero(),
+            clearance_distance: T::cast(20.0),
+            clearance_cost: ClearanceCost::Shortfall,
+            goal_node: None,
+            any_angle: false,
+            any_angle_depth: 8,
+            cross_edges: false,
+            cross_edge_radius: T::cast(25.0),
+            graph_edges: Vec::new(),
+            graph_edge_costs: Vec::new(),
+            lazy_collision: false,
+            lazy_stats: LazyStats::default(),
+            dynamic_domain: false,
+            dynamic_domain_radius: T::cast(50.0),
+            dynamic_domain_shrink: T::cast(0.8),
+            domain_resamples: 0,
+            samples_total: 0,
+            samples_rejected: 0,
+            rejection_sampling: false,
+            branch_and_bound: false,
+            branch_and_bound_interval: 100,
+            bnb_nodes_pruned: 0,
+            bnb_extensions_rejected: 0,
+            goal_connect_interval: 0,
+            goal_connect_attempts: 0,
+            max_path_cost: None,
+            cost_limit_rejections: 0,
+            max_nodes: None,
+            node_cap_policy: NodeCapPolicy::Saturate,
+            saturated: false,
+            nodes_pruned: 0,
+            last_step: None,
+            max_depth: 0,
+            max_cost: T::zero(),
+            longest_edge: T::zero(),
+            best_goal_distance: start.distance(&goal),
+            best_goal_node: 0,
+            best_goal_iteration: 0,
+            time_budget: None,
+            batch_size: 1,
+            heatmap: None,
+            coverage: None,
+            sample_source: None,
+            neighbor_index: NeighborIndex::Linear,
+            kd_tree_rebalance: T::cast(KD_TREE_REBALANCE_FRACTION),
+            goal_region: None,
+            sampling_region: None,
+            observer: None,
+            best_cost: None,
+            bnb_best_cost: T::infinity(),
+            rng: seeded_rng(None),
+        }
+    }
+
+    // En yakın düğüm aramasının indeksini değiştirir ve var olan düğümlerle yeniden kurar.
+    // `grid_cell_size` yalnızca ızgara indeksinde kullanılır.
+    pub fn set_neighbor_index(&mut self, kind: NeighborIndexKind, grid_cell_size: T) {
+        self.neighbor_index = match kind {
+            NeighborIndexKind::Linear => NeighborIndex::Linear,
+            NeighborIndexKind::Grid => NeighborIndex::Grid(SpatialGrid::new(self.bounds, grid_cell_size)),
+            NeighborIndexKind::KdTree => NeighborIndex::KdTree(KdTree::with_rebalance_fraction(self.kd_tree_rebalance)),
+        };
+        self.rebuild_neighbor_index();
+    }
+
+    // k-d ağacının yeniden dengeleme oranını değiştirir; sıfır yeniden dengelemeyi kapatır
+    pub fn set_kd_tree_rebalance(&mut self, fraction: T) {
+        self.kd_tree_rebalance = fraction;
+        if let NeighborIndex::KdTree(tree) = &mut self.neighbor_index {
+            tree.set_rebalance_fraction(fraction);
+        }
+    }
+
+    pub fn neighbor_index_kind(&self) -> NeighborIndexKind {
+        match self.neighbor_index {
+            NeighborIndex::Linear => NeighborIndexKind::Linear,
+            NeighborIndex::Grid(_) => NeighborIndexKind::Grid,
+            NeighborIndex::KdTree(_) => NeighborIndexKind::KdTree,
+        }
+    }
+
+    // Düğüm indeksleri değiştiğinde (budama, sıfırlama) veya alan değiştiğinde indeksi baştan kurar
+    fn rebuild_neighbor_index(&mut self) {
+        match &mut self.neighbor_index {
+            NeighborIndex::Linear => {}
+            NeighborIndex::Grid(grid) => {
+                *grid = SpatialGrid::new(self.bounds, grid.cell_size());
+                for (i, node) in self.nodes.iter().enumerate() {
+                    grid.insert(i, node.point);
+                }
+            }
+            NeighborIndex::KdTree(tree) => tree.rebuild(self.nodes.iter().enumerate().map(|(i, node)| (i, node.point)).collect()),
+        }
+    }
+
+    // Kapsama ızgarasını alanı eksen başına `cells` hücreye bölerek kurar; sıfır kapsamayı kapatır
+    pub fn set_coverage_resolution(&mut self, cells: usize) {
+        self.coverage = (cells > 0).then(|| CoverageGrid::new(CellFrame::fitted(self.bounds, cells, cells)));
+        self.rebuild_coverage();
+    }
+
+    // Serbest hücreleri şu anki alan ve engellerle yeniden işaretler ve bütün düğümleri yeniden
+    // sayar; ağaç sıfırlandığında, düğümler silindiğinde veya harita değiştiğinde kullanılır
+    fn rebuild_coverage(&mut self) {
+        let Some(old) = &self.coverage else {
+            return;
+        };
+        let mut coverage = CoverageGrid::new(CellFrame::fitted(self.bounds, old.frame.cols, old.frame.rows));
+        coverage.mark_free(&self.checker());
+        for node in &self.nodes {
+            coverage.record(&node.point);
+        }
+        self.coverage = Some(coverage);
+    }
+
+    // Kaplanan serbest hücrelerin oranı [0, 1]; kapsama ızgarası yoksa `None`
+    pub fn coverage_fraction(&self) -> Option<T> {
+        self.coverage.as_ref().map(CoverageGrid::coverage)
+    }
+
+    // Rastgele sayı üretecini verilen tohumla yeniden başlatır
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
+    }
+
+    pub fn step_size(&self) -> T {
+        self.step_size
+    }
+
+    pub fn goal_bias(&self) -> T {
+        self.goal_bias
+    }
+
+    pub fn goal_threshold(&self) -> T {
+        self.goal_threshold
+    }
+
+    // Adım boyutunu `STEP_SIZE_RANGE` aralığına kırparak değiştirir ve uygulanan değeri döndürür.
+    // Sayı olmayan değerler yok sayılır. Yalnızca bundan sonra eklenen düğümleri etkiler; daha uzun
+    // eski kenarlar `longest_edge` kaydı sayesinde yol doğrulamasından geçmeye devam eder.
+    pub fn set_step_size(&mut self, step_size: T) -> T {
+        if !step_size.is_nan() {
+            self.step_size = clamp(step_size, T::cast(STEP_SIZE_RANGE.0), T::cast(STEP_SIZE_RANGE.1));
+        }
+        self.step_size
+    }
+
+    pub fn sampler(&self) -> SamplerKind {
+        self.sampler
+    }
+
+    pub fn robot_radius(&self) -> T {
+        self.robot_radius
+    }
+
+    // Hedef yanlılığını [0, 1] aralığına kırparak değiştirir ve uygulanan değeri döndürür. Düzgün
+    // örnekleyici yanlılığı kullanmadığından sıfırdan büyük bir değer hedef yanlı örneklemeye geçirir.
+    pub fn set_goal_bias(&mut self, goal_bias: T) -> T {
+        if !goal_bias.is_nan() {
+            self.goal_bias = clamp(goal_bias, T::zero(), T::one());
+        }
+        if self.goal_bias > T::zero() && self.sampler == SamplerKind::Uniform {
+            self.sampler = SamplerKind::GoalBiased;
+        }
+        self.goal_bias
+    }
+
+    // Robot yarıçapını değiştirir (negatif ve sayı olmayan değerler sıfır olur) ve yeni yarıçapla
+    // çarpışan ağaç kenarlarının alt ağaçlarını `invalidate_edges_blocked_by` gibi siler; yarıçap
+    // küçüldüğünde kenarlar geçerli kaldığından ağaç korunur. Silinen düğüm sayısı döner.
+    pub fn set_robot_radius(&mut self, robot_radius: T) -> usize {
+        let grew = robot_radius > self.robot_radius;
+        self.robot_radius = if robot_radius > T::zero() { robot_radius } else { T::zero() };
+        if !grew {
+            return 0;
+        }
+        self.prune_edges_where(|rrt, a, b| !rrt.is_edge_collision_free(a, b))
+    }
+
+    // Hedef eşiğini `GOAL_THRESHOLD_RANGE` aralığına kırparak değiştirir ve uygulanan değeri
+    // döndürür. Hedef düğümü yeni eşiğin dışında kalırsa bölgedeki başka bir düğümle değiştirilir
+    // (yoksa temizlenir); eşik büyüdüğünde bölgeye giren ilk düğüm hedef düğümü olur.
+    pub fn set_goal_threshold(&mut self, goal_threshold: T) -> T {
+        if goal_threshold.is_nan() {
+            return self.goal_threshold;
+        }
+        self.goal_threshold =
+            clamp(goal_threshold, T::cast(GOAL_THRESHOLD_RANGE.0), T::cast(GOAL_THRESHOLD_RANGE.1));
+        let still_inside = self.goal_node.is_some_and(|g| self.in_goal_region(&self.nodes[g].point));
+        if !still_inside {
+            self.set_goal_node(self.first_in_goal_region());
+            self.bnb_best_cost = T::infinity();
+        }
+        self.goal_threshold
+    }
+
+    // Düğümlerin noktaları ve ebeveyn indeksleri ekleme sırasıyla; ağacı iç alanlara dokunmadan
+    // gezmek isteyen analiz ve çizim kodu için
+    pub fn nodes(&self) -> impl ExactSizeIterator<Item = (&Point<T>, Option<usize>)> + '_ {
+        self.nodes.iter().map(|node| (&node.point, node.parent))
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    // `index` numaralı düğüm; indeks ağacın dışındaysa panikler
+    pub fn node(&self, index: usize) -> &Node<T> {
+        &self.nodes[index]
+    }
+
+    // Birincil hedef; tek hedefli senaryolarda tek hedeftir
+    pub fn goal(&self) -> Point<T> {
+        self.goals[0]
+    }
+
+    // Noktanın en yakın hedefe uzaklığı
+    pub fn goal_distance(&self, p: &Point<T>) -> T {
+        self.goals.iter().map(|goal| p.distance(goal)).fold(T::infinity(), T::min)
+    }
+
+    // Hedefe en yakın düğümü bütün düğümleri tarayarak yeniden bulur; hedefler değiştiğinde veya
+    // o düğüm silindiğinde kullanılır. Eski düğümlerin ne zaman eklendiği bilinmediğinden
+    // iterasyon o anki sayaç olur.
+    fn refresh_best_goal_distance(&mut self) {
+        let (node, distance) = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, n)| (i, self.goal_distance(&n.point)))
+            .fold((0, T::infinity()), |best, next| if next.1 < best.1 { next } else { best });
+        self.best_goal_node = node;
+        self.best_goal_distance = distance;
+        self.best_goal_iteration = self.iteration;
+    }
+
+    // Hedef listesini değiştirir; boş liste yok sayılır. Hedef düğümü yeni hedeflere göre
+    // yeniden aranır.
+    pub fn set_goals(&mut self, goals: Vec<Point<T>>) {
+        if goals.is_empty() {
+            return;
+        }
+        self.goals = goals;
+        self.set_goal_node(self.first_in_goal_region());
+        self.bnb_best_cost = T::infinity();
+        self.refresh_best_goal_distance();
+    }
+
+    // Ağacı koruyarak birincil hedefi taşır; hedef sürüklendiğinde büyüme sıfırdan başlamak yerine
+    // kaldığı yerden sürer. Hedef örneklemesi hedefleri her örnekte okuduğundan yeni hedefe kendiliğinden
+    // yönelir. Ağaç alanı zaten kapladığından yeni eşiğin içinde çoğu zaman bir düğüm vardır; hedef
+    // düğümü tüm düğümleri taramak yerine hedefler çevresindeki yarıçap sorgusuyla, `add_node` ile
+    // aynı "ilk ulaşan" kuralına göre yeniden seçilir ve döndürülür.
+    pub fn move_goal(&mut self, goal: Point<T>) -> Option<usize> {
+        self.goals[0] = goal;
+        let goal_node = match self.goal_region {
+            Some(_) => self.first_in_goal_region(),
+            None => self
+                .goals
+                .iter()
+                .flat_map(|g| self.neighbors_within(g, self.goal_threshold))
+                .filter(|&i| self.in_goal_region(&self.nodes[i].point))
+                .min(),
+        };
+        self.set_goal_node(goal_node);
+        self.bnb_best_cost = T::infinity();
+        self.refresh_best_goal_distance();
+        self.goal_node
+    }
+
+    // Noktanın eşik içinde kaldığı en yakın hedefin indeksi
+    fn goal_index_near(&self, p: &Point<T>) -> Option<usize> {
+        self.goals
+            .iter()
+            .enumerate()
+            .map(|(i, goal)| (i, p.distance(goal)))
+            .filter(|&(_, d)| d < self.goal_threshold)
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(i, _)| i)
+    }
+
+    // Hedef bölgesini değiştirir; `None` hedef noktaları çevresindeki dairesel eşiğe döner.
+    // Hedef düğümü yeni bölgeye göre yeniden aranır.
+    pub fn set_goal_region(&mut self, region: Option<Box<dyn GoalRegion<T>>>) {
+        self.goal_region = region;
+        self.set_goal_node(self.first_in_goal_region());
+        self.bnb_best_cost = T::infinity();
+    }
+
+    // Hedef düğümünün ulaştığı hedef; hedefe henüz ulaşılmadıysa `None` döner. Hedef bölgesi
+    // verilmişse tek bir hedef noktası olmadığından hedef düğümünün kendisi döner.
+    pub fn reached_goal(&self) -> Option<Point<T>> {
+        let node = self.goal_node?;
+        if self.goal_region.is_some() {
+            return Some(self.nodes[node].point);
+        }
+        self.goal_index_near(&self.nodes[node].point).map(|i| self.goals[i])
+    }
+
+    fn in_goal_region(&self, p: &Point<T>) -> bool {
+        match &self.goal_region {
+            Some(region) => region.contains(p),
+            None => self.goals.iter().any(|goal| p.distance(goal) < self.goal_threshold),
+        }
+    }
+
+    // Hedef bölgesindeki en küçük indeksli düğüm; `add_node` ile aynı "ilk ulaşan" kuralı
+    fn first_in_goal_region(&self) -> Option<usize> {
+        self.nodes.iter().position(|n| self.in_goal_region(&n.point) && self.can_wait_at(&n.point, n.time) && self.within_cost_limit(n.cost))
+    }
+
+    fn within_cost_limit(&self, cost: T) -> bool {
+        self.max_path_cost.is_none_or(|limit| cost <= limit)
+    }
+
+    // `parent` düğümüne bağlanan `p` noktasının kökten maliyeti `max_path_cost` bütçesini aşıyor mu
+    fn exceeds_cost_limit(&self, parent: usize, p: &Point<T>) -> bool {
+        let Some(limit) = self.max_path_cost else { return false };
+        let node = &self.nodes[parent];
+        node.cost + self.edge_cost(&node.point, p) > limit
+    }
+
+    // Robot `t` anında vardığı `p` noktasında sonsuza dek bekleyebilir mi: hareketli robotların
+    // hiçbiri o andan sonra (kendi hedefinde beklerken dahil) `p` noktasına değecek kadar yaklaşmaz
+    fn can_wait_at(&self, p: &Point<T>, t: T) -> bool {
+        self.moving_robots.iter().all(|r| r.trajectory.distance_after(p, t) > self.robot_radius + r.radius)
+    }
+
+    // Örnekleme bölgesini değiştirir; `None` alanın dikdörtgenine döner. Bölge çarpışma
+    // kontrolünü değiştirmez: alan dışında kalan örneklere yönlendirilen noktalar yine reddedilir.
+    pub fn set_sampling_region(&mut self, region: Option<Box<dyn SamplingRegion<T>>>) {
+        self.sampling_region = region;
+    }
+
+    // Gözlemciyi değiştirir; `None` bildirimleri kapatır. Gözlemci verilmemişse olaylar için ek
+    // iş yapılmaz.
+    pub fn set_observer(&mut self, observer: Option<Box<dyn PlannerObserver<T>>>) {
+        self.observer = observer;
+    }
+
+    // Gözlemciyi geri alır (ör. planlama sonunda `flush` için); bildirimler kapanır
+    pub fn take_observer(&mut self) -> Option<Box<dyn PlannerObserver<T>>> {
+        self.observer.take()
+    }
+
+    // Örnekleme bölgesinden (verilmemişse alanın dikdörtgeninden) rastgele bir nokta seçer. Ret
+    // örneklemesi açıksa engel içine düşen örnekler boşuna en yakın düğüm araması ve yönlendirme
+    // yapılmasın diye yeniden çekilir; deneme sınırı aşılırsa son örnek döner ve olağan
+    // çarpışma kontrolü onu reddeder. Yoğunluk haritasına yalnızca dönen örnek sayılır.
+    pub fn random_point(&mut self) -> Point<T> {
+        let mut p = self.uniform_sample();
+        if self.rejection_sampling {
+            for _ in 1..REJECTION_SAMPLING_ATTEMPTS {
+                if self.is_collision_free(&p) {
+                    break;
+                }
+                p = self.uniform_sample();
+            }
+        }
+        self.record_sample(&p);
+        p
+    }
+
+    // Yoğunluk haritasına sayılmayan düzgün örnek; sınır örneklemesinin adayları için de kullanılır
+    fn uniform_sample(&mut self) -> Point<T> {
+        match &mut self.sampling_region {
+            Some(region) => region.sample(&mut self.rng),
+            None => RectRegion { bounds: self.bounds }.sample(&mut self.rng),
+        }
+    }
+
+    // Örnek yoğunluğu haritası f32 çalıştığından örnek kayıt için dönüştürülür
+    fn record_sample(&mut self, p: &Point<T>) {
+        if let Some(heatmap) = self.heatmap.as_mut() {
+            heatmap.record(&Point { x: p.x.as_f32(), y: p.y.as_f32() });
+        }
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_sample(p);
+        }
+    }
+
+    // Bu iterasyonda hedefin örneklenme olasılığı
+    pub fn effective_goal_bias(&self) -> T {
+        match self.bias_schedule.bias_at(self.iteration) {
+            Some(bias) => clamp(T::cast(bias), T::zero(), T::one()),
+            None => self.goal_bias,
+        }
+    }
+
+    // Seçili örnekleme stratejisine göre bir sonraki örneği üretir. Hedef yanlılığında hedef
+    // bölgesi verilmişse bölgeden örneklenir; birden çok hedef varsa biri rastgele seçilir, tek
+    // hedefte ek rastgele sayı çekilmez. Örnek kaynağı verilmişse (ör. kayıttan yeniden oynatma)
+    // strateji yerine kaynaktaki sıradaki örnek kullanılır.
+    pub fn sample(&mut self) -> Point<T> {
+        if let Some(p) = self.sample_source.as_mut().and_then(Iterator::next) {
+            self.record_sample(&p);
+            return p;
+        }
+        let goal_biased = matches!(self.sampler, SamplerKind::GoalBiased | SamplerKind::Frontier);
+        if goal_biased && T::unit(&mut self.rng) < self.effective_goal_bias() {
+            let goal = match (&mut self.goal_region, self.goals.len()) {
+                (Some(region), _) => region.sample(&mut self.rng),
+                (None, 1) => self.goals[0],
+                (None, n) => self.goals[self.rng.gen_range(0..n)],
+            };
+            self.record_sample(&goal);
+            return goal;
+        }
+        // Önbellek boşken rastgele sayı çekilmez; önceki sürümlerin sayı dizisi değişmez
+        if !self.waypoint_cache.is_empty() && T::unit(&mut self.rng) < self.waypoint_bias {
+            let p = self.sample_near_waypoint();
+            self.record_sample(&p);
+            return p;
+        }
+        if self.sampler == SamplerKind::Frontier && T::unit(&mut self.rng) < self.frontier_bias {
+            let p = self.sample_near_frontier();
+            self.record_sample(&p);
+            return p;
+        }
+        // Arazi örneklemesi örnekleme bölgesini dikkate almaz; kalan örnekler düzgün kalır ki
+        // pahalı bölgeler de zamanla keşfedilsin
+        if self.terrain.is_some() && self.terrain_bias > T::zero() && T::unit(&mut self.rng) < self.terrain_bias {
+            if let Some(p) = self.terrain.as_ref().and_then(|terrain| terrain.sample(&mut self.rng)) {
+                self.record_sample(&p);
+                return p;
+            }
+        }
+        self.random_point()
+    }
+
+    // Bir sonraki planlama döngüsünü önceki yolla tohumlar (ERRT): `waypoint_bias` olasılığıyla
+    // örnekler yolun ara noktalarının çevresinden çekilir, böylece yeni ağaç eski yolu hızla
+    // yeniden kurar. Boş yol önbelleği temizler.
+    pub fn seed_from_path(&mut self, path: &[Point<T>]) {
+        self.waypoint_cache = path.to_vec();
+    }
+
+    // Önbellekten rastgele seçilen ara noktanın çevresinde normal dağılımlı (Box-Muller) nokta.
+    // Örnekleme bölgesinin (verilmemişse alanın) dışına düşen noktalar yeniden çekilir.
+    fn sample_near_waypoint(&mut self) -> Point<T> {
+        for _ in 0..WAYPOINT_ATTEMPTS {
+            let waypoint = self.waypoint_cache[self.rng.gen_range(0..self.waypoint_cache.len())];
+            // 1 - u sıfır olamadığından logaritma sonludur
+            let radius = self.waypoint_sigma * (-T::cast(2.0) * (T::one() - T::unit(&mut self.rng)).ln()).sqrt();
+            let angle = T::cast(std::f64::consts::TAU) * T::unit(&mut self.rng);
+            let p = waypoint + Point { x: angle.cos(), y: angle.sin() } * radius;
+            let inside = match &self.sampling_region {
+                Some(region) => region.contains(&p),
+                None => self.bounds.contains(&p),
+            };
+            if inside {
+                return p;
+            }
+        }
+        self.uniform_sample()
+    }
+
+    // `FRONTIER_CANDIDATES` düzgün aday arasından en yakın düğümü en az seçilmiş olanı döndürür
+    // (eşitlikte ilk çekilen). Sık seçilen düğümlerin çevresi yoğun büyüdüğünden örnekler az
+    // seçilen, henüz genişlemeye fırsat bulmamış sınır düğümlerinin bölgelerine kayar.
+    fn sample_near_frontier(&mut self) -> Point<T> {
+        let mut best: Option<(usize, Point<T>)> = None;
+        for _ in 0..FRONTIER_CANDIDATES {
+            let p = self.uniform_sample();
+            let count = self.nodes[self.find_nearest(&p)].nearest_count;
+            if best.is_none_or(|(c, _)| count < c) {
+                best = Some((count, p));
+            }
+        }
+        best.unwrap().1
+    }
+
+    // En yakın düğümü bulur; uzaklığı eşit düğümlerden indeksi küçük olan döner
+    // Uzaklıklar NaN olabildiğinden (ör. NaN koordinatlı nokta) hiçbir karşılaştırma panik
+    // oluşturmaz; hiçbir uzaklık karşılaştırılamıyorsa kök döner.
+    pub fn find_nearest(&self, point: &Point<T>) -> usize {
+        match &self.neighbor_index {
+            NeighborIndex::Linear => self.find_nearest_linear(point),
+            NeighborIndex::Grid(grid) => grid.nearest(point).unwrap_or(0),
+            NeighborIndex::KdTree(tree) => tree.nearest(point).unwrap_or(0),
+        }
+    }
+
+    // `point` noktasına en fazla `r` uzaklıktaki düğümler, uzaklığa göre artan sırada; eşit
+    // uzaklıktakiler indekse göre sıralanır. Sonuç etkin indeksten bağımsızdır.
+    pub fn neighbors_within(&self, point: &Point<T>, r: T) -> Vec<usize> {
+        sorted_indices(match &self.neighbor_index {
+            NeighborIndex::Linear => self
+                .nodes
+                .iter()
+                .enumerate()
+                .map(|(i, node)| (node.point.distance(point), i))
+                .filter(|&(d, _)| d <= r)
+                .collect(),
+            NeighborIndex::Grid(grid) => grid.within(point, r),
+            NeighborIndex::KdTree(tree) => tree.within(point, r),
+        })
+    }
+
+    // `point` noktasına en yakın `k` düğüm (düğüm sayısı daha azsa hepsi), `neighbors_within` ile
+    // aynı sırada
+    pub fn k_nearest(&self, point: &Point<T>, k: usize) -> Vec<usize> {
+        let mut nearest = sorted_indices(match &self.neighbor_index {
+            NeighborIndex::Linear => self.nodes.iter().enumerate().map(|(i, node)| (node.point.distance(point), i)).collect(),
+            NeighborIndex::Grid(grid) => grid.k_nearest(point, k),
+            NeighborIndex::KdTree(tree) => tree.k_nearest(point, k),
+        });
+        nearest.truncate(k);
+        nearest
+    }
+
+    // Yalnızca `root` kökünden büyüyen ağacın düğümleri arasında en yakın düğüm; kökün kendisi her
+    // zaman aday olduğundan geçerli bir kök için sonuç o ağaçtadır
+    pub fn find_nearest_in_root(&self, point: &Point<T>, root: usize) -> usize {
+        let in_root = |i: usize| self.nodes[i].root == root;
+        let found = match &self.neighbor_index {
+            NeighborIndex::Linear => self
+                .nodes
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| in_root(i))
+                .map(|(i, node)| (node.point.distance(point), i))
+                .fold(None, |best: Option<(T, usize)>, next| if best.is_none_or(|b| next.0 < b.0) { Some(next) } else { best })
+                .map(|(_, i)| i),
+            NeighborIndex::Grid(grid) => grid.nearest_where(point, in_root),
+            NeighborIndex::KdTree(tree) => tree.nearest_where(point, in_root),
+        };
+        found.unwrap_or(root)
+    }
+
+    // İlk en küçük uzaklıklı düğüm; NaN uzaklıklar hiçbir zaman daha yakın sayılmaz
+    fn find_nearest_linear(&self, point: &Point<T>) -> usize {
+        let mut best = (0, self.nodes[0].point.distance(point));
+        for (index, node) in self.nodes.iter().enumerate().skip(1) {
+            let d = node.point.distance(point);
+            if d < best.1 || (best.1.is_nan() && !d.is_nan()) {
+                best = (index, d);
+            }
+        }
+        best.0
+    }
+
+    // Verilen noktadan hedefe doğru en fazla adım uzunluğu kadar ilerler; hedef daha yakınsa
+    // hedefin üzerinde durur, böylece hedef hiçbir zaman geçilmez. Potansiyel alan verilmişse yön
+    // alanın karışık vektörüdür ve adım yine örneğe olan uzaklığı aşmaz.
+    pub fn steer(&self, from: &Point<T>, to: &Point<T>) -> Point<T> {
+        let length = self.step_length_at(from);
+        match self.potential_field.and_then(|field| self.field_direction(&field, from, to)) {
+            Some(direction) => *from + direction * length.min(from.distance(to)),
+            None => State::steer(from, to, length),
+        }
+    }
+
+    // Potansiyel alanın `from` noktasındaki birim yönü: örneğe ve en yakın hedefe çekim ile etki
+    // yarıçapı içindeki en yakın engelden itme. Vektörler birbirini neredeyse sıfırlıyorsa (ör.
+    // örnek hedefin tam tersindeyken) veya örnek `from` üzerindeyse `None` döner.
+    fn field_direction(&self, field: &PotentialField, from: &Point<T>, to: &Point<T>) -> Option<Point<T>> {
+        let toward_sample = (*to - *from).normalize()?;
+        let zero = Point { x: T::zero(), y: T::zero() };
+        let goal = self.goals.iter().copied().fold(self.goals[0], |best, g| if g.distance(from) < best.distance(from) { g } else { best });
+        let toward_goal = (goal - *from).normalize().unwrap_or(zero);
+        let mut blended = toward_sample * T::cast(field.sample_gain) + toward_goal * T::cast(field.goal_gain);
+        if let Some((distance, away)) = self.checker().nearest_obstacle(from) {
+            let influence = T::cast(field.influence_radius);
+            let gap = (distance - self.robot_radius).max(T::zero());
+            if gap < influence {
+                blended = blended + away * (T::cast(field.obstacle_gain) * (T::one() - gap / influence));
+            }
+        }
+        let total_gain = T::cast(field.sample_gain + field.goal_gain + field.obstacle_gain);
+        if blended.length() <= total_gain * T::cast(FIELD_CANCEL_TOLERANCE) {
+            return None;
+        }
+        blended.normalize()
+    }
+
+    // `from` noktasından atılacak adımın uzunluğu; engel yoksa açıklık sonsuz olduğundan
+    // `ClearanceScaled` en büyük adımı atar
+    pub fn step_length_at(&self, from: &Point<T>) -> T {
+        match self.step_policy {
+            StepPolicy::Fixed => self.step_size,
+            StepPolicy::ClearanceScaled { min, max } => {
+                let (min, max) = (T::cast(min), T::cast(max));
+                clamp(self.clearance(from) - self.robot_radius, min, max.max(min))
+            }
+        }
+    }
+
+    // Ağacın alanı ve engelleri üzerinde çarpışma denetleyicisi
+    pub fn checker(&self) -> CollisionChecker<'_, T> {
+        CollisionChecker {
+            bounds: &self.bounds,
+            obstacles: &self.obstacles,
+            resolution: self.collision_resolution,
+            adaptive: self.adaptive_collision,
+            robot_radius: self.robot_radius,
+            robot_shape: &self.robot_shape,
+        }
+    }
+
+    // Çarpışma kontrolü: nokta alan içinde olmalı ve hiçbir engelin içine düşmemeli
+    pub fn is_collision_free(&self, point: &Point<T>) -> bool {
+        self.checker().is_point_free(point)
+    }
+
+    // En yakın engel sınırına işaretli uzaklık; maliyet fonksiyonlarında düşük açıklıklı yolları
+    // cezalandırmak için kullanılabilir
+    pub fn clearance(&self, p: &Point<T>) -> T {
+        self.checker().clearance(p)
+    }
+
+    // En yakın engele olan mesafe
+    pub fn distance_to_nearest_obstacle(&self, p: &Point<T>) -> T {
+        self.checker().distance_to_nearest_obstacle(p)
+    }
+
+    // İki nokta arasındaki doğru parçasını kontrol eder; `adaptive_collision` açıksa engel
+    // mesafesine göre, değilse `collision_resolution` aralıklarla örnekleyerek
+    pub fn is_edge_collision_free(&self, from: &Point<T>, to: &Point<T>) -> bool {
+        self.checker().is_edge_free(from, to)
+    }
+
+    // Robot `departure` anında `from` noktasından sabit hızla yola çıktığında `to` noktasına giden
+    // kenar çarpışmasız mı: sabit engellere ek olarak hareketli engeller, kenar boyunca
+    // `collision_resolution` aralıklarla örneklenen her noktada robotun oraya vardığı anki
+    // konumlarıyla kontrol edilir
+    pub fn is_edge_free_at(&self, from: &Point<T>, to: &Point<T>, departure: T) -> bool {
+        if !self.is_edge_collision_free(from, to) {
+            return false;
+        }
+        if self.dynamic_obstacles.is_empty() && self.moving_robots.is_empty() {
+            return true;
+        }
+        let length = from.distance(to);
+        let steps = (length / self.collision_resolution).ceil().max(T::one()).to_usize().unwrap_or(1);
+        (0..=steps).all(|i| {
+            let s = T::cast(i) / T::cast(steps);
+            self.is_free_of_dynamic(&Point::lerp(*from, *to, s), departure + length * s / self.speed)
+        })
+    }
+
+    // Nokta `t` anında hiçbir hareketli engelin (robot yarıçapı kadar şişirilmiş) içinde değil mi;
+    // çokgen robotta robotun o noktada kapladığı alan hiçbir engele değmemelidir. Önceden planlanmış
+    // robotlar `t` anındaki konumlarında kendi yarıçaplarıyla daire engel sayılır.
+    pub fn is_free_of_dynamic(&self, p: &Point<T>, t: T) -> bool {
+        let robots = self.moving_robots.iter().map(|r| Obstacle::Circle { center: r.trajectory.position_at(t), radius: r.radius });
+        let mut moving = self.dynamic_obstacles.iter().map(|o| o.at(t)).chain(robots);
+        match self.robot_shape.footprint(p) {
+            Some(footprint) => moving.all(|o| !o.intersects_convex(&footprint)),
+            None => moving.all(|o| o.signed_distance(p) > self.robot_radius),
+        }
+    }
+
+    // Düğümden yeni bir noktaya, düğümün varış anında yola çıkılarak giden kenarın kontrolü
+    fn is_edge_free_from(&self, node: usize, to: &Point<T>) -> bool {
+        self.is_edge_free_at(&self.nodes[node].point, to, self.nodes[node].time)
+    }
+
+    // Dışarıdan verilen bir yolu harita üzerinde doğrular; hata durumunda engele çarpan
+    // veya alan dışına çıkan ilk parçanın indeksini döndürür
+    pub fn is_path_valid(&self, path: &[Point<T>]) -> Result<(), usize> {
+        if let [only] = path {
+            return if self.is_collision_free(only) { Ok(()) } else { Err(0) };
+        }
+        match path
+            .windows(2)
+            .position(|segment| !self.is_edge_collision_free(&segment[0], &segment[1]))
+        {
+            Some(index) => Err(index),
+            None => Ok(()),
+        }
+    }
+
+    // Ağaçtan (veya çapraz kenarlı çizgeden) çıkan bir yolun geçerliliğini denetler: ilk nokta
+    // köklerden biri, son nokta hedef bölgesinde olmalı, her parça ağacın kurabileceği en uzun
+    // kenardan kısa ve çarpışmasız olmalıdır. Hareketli engeller zamana bağlı olduğundan hesaba
+    // katılmaz. Bulunan ilk kusur döner.
+    pub fn validate_path(&self, path: &[Point<T>]) -> Result<(), PathError<T>> {
+        let (Some(&first), Some(&last)) = (path.first(), path.last()) else { return Err(PathError::Empty) };
+        if !self.starts.contains(&first) {
+            return Err(PathError::NotAtStart(first));
+        }
+        if !self.in_goal_region(&last) {
+            return Err(PathError::GoalNotReached(last));
+        }
+        let max_length = self.edge_length_bound() * T::cast(1.0 + PATH_SEGMENT_TOLERANCE);
+        for (index, segment) in path.windows(2).enumerate() {
+            let length = segment[0].distance(&segment[1]);
+            if length > max_length {
+                return Err(PathError::SegmentTooLong { index, length });
+            }
+        }
+        self.is_path_valid(path).map_err(|index| PathError::Collision { index })
+    }
+
+    fn refresh_longest_edge(&mut self) {
+        self.longest_edge = self
+            .nodes
+            .iter()
+            .filter_map(|node| node.parent.map(|p| self.nodes[p].point.distance(&node.point)))
+            .fold(T::zero(), T::max);
+    }
+
+    // Bir kenarın olabileceği en büyük uzunluk: yönlendirme adım boyutu kadar ilerler, ebeveyn
+    // seçimi ve çapraz kenarlar daha uzak düğümlere bağlayabilir. Her açıda kısayol ve düz hedef
+    // bağlantısı açıkken uzaklık sınırsızdır. Ağaçta daha uzun bir kenar varsa (ör. adım boyu
+    // sonradan küçültüldüyse) sınır o kenardır.
+    fn edge_length_bound(&self) -> T {
+        let cap = self.max_edge_length.unwrap_or_else(T::infinity);
+        if self.any_angle || self.goal_connect_interval > 0 {
+            return cap;
+        }
+        let mut length = match self.step_policy {
+            StepPolicy::Fixed => self.step_size,
+            StepPolicy::ClearanceScaled { min, max } => T::cast(min.max(max)),
+        };
+        if self.chooses_cheapest_parent() {
+            length = length.max(self.step_size * T::cast(CHEAPEST_PARENT_RADIUS_STEPS));
+        }
+        if self.cross_edges {
+            length = length.max(self.cross_edge_radius);
+        }
+        length.min(cap).max(self.longest_edge)
+    }
+
+    // `point` noktasını `parent` düğümüne bağlar; kenar `max_edge_length` değerinden uzunsa eşit
+    // parçalara bölünür ve ara noktalar zincir halinde düğüm olarak eklenir. Kenarın tamamı
+    // çağıran tarafından denetlenmiş olmalıdır. Eklenen ilk düğümün indeksi döner; son düğüm
+    // `point` noktasıdır.
+    fn add_split_edge(&mut self, point: Point<T>, parent: usize) -> usize {
+        let first = self.nodes.len();
+        let from = self.nodes[parent].point;
+        let pieces = match self.max_edge_length {
+            Some(cap) if cap > T::zero() => (from.distance(&point) / cap).ceil().to_usize().unwrap_or(1).max(1),
+            _ => 1,
+        };
+        let mut previous = parent;
+        for k in 1..pieces {
+            self.add_node(Point::lerp(from, point, T::cast(k) / T::cast(pieces)), previous);
+            previous = self.nodes.len() - 1;
+        }
+        self.add_node(point, previous);
+        first
+    }
+
+    // Yeni düğüm ekler; hedef bölgesine ilk kez ulaşan düğüm `goal_node` olarak işaretlenir
+    pub fn add_node(&mut self, point: Point<T>, parent_index: usize) {
+        let mut new_node = Node::new(point, Some(parent_index));
+        let parent = &self.nodes[parent_index];
+        new_node.cost = parent.cost + self.edge_cost(&parent.point, &point);
+        new_node.depth = parent.depth + 1;
+        new_node.root = parent.root;
+        new_node.time = parent.time + parent.point.distance(&point) / self.speed;
+        // En büyük değerler ekleme sırasında güncellenir, böylece her karede tüm düğümler taranmaz
+        self.max_depth = self.max_depth.max(new_node.depth);
+        self.max_cost = self.max_cost.max(new_node.cost);
+        self.longest_edge = self.longest_edge.max(parent.point.distance(&point));
+        self.nodes.push(new_node);
+        let index = self.nodes.len() - 1;
+        // Yalnızca yeni düğüm karşılaştırılır; `extend` sayacı düğüm eklendikten sonra artırır
+        let goal_distance = self.goal_distance(&point);
+        if goal_distance < self.best_goal_distance {
+            self.best_goal_distance = goal_distance;
+            self.best_goal_node = index;
+            self.best_goal_iteration = self.iteration + 1;
+        }
+        match &mut self.neighbor_index {
+            NeighborIndex::Linear => {}
+            NeighborIndex::Grid(grid) => grid.insert(index, point),
+            NeighborIndex::KdTree(tree) => tree.insert(index, point),
+        }
+        if let Some(coverage) = self.coverage.as_mut() {
+            coverage.record(&point);
+        }
+        if let Some(observer) = self.observer.as_mut() {
+            observer.on_node_added(index, parent_index);
+        }
+        let reached = self.in_goal_region(&point) && self.can_wait_at(&point, self.nodes[index].time) && self.within_cost_limit(self.nodes[index].cost);
+        let root = self.nodes[index].root;
+        if reached && self.root_goal_iterations[root].is_none() {
+            self.root_goal_iterations[root] = Some(self.iteration + 1);
+        }
+        if self.goal_node.is_none() && reached {
+            self.set_goal_node(Some(index));
+            if let Some(observer) = self.observer.as_mut() {
+                observer.on_goal_reached(index);
+            }
+        }
+    }
+
+    // Bir iterasyon çalıştırır: örnek çeker, en yakın düğümden yönlendirir ve kenar
+    // çarpışmasızsa yeni düğümü ekler
+    pub fn extend(&mut self) -> Extension<T> {
+        let full = self.begin_iterations(1);
+        let (sample, nearest) = self.draw_sample();
+        let candidate = self.evaluate(sample, nearest, full);
+        self.commit(candidate, full)
+    }
+
+    // `count` iterasyonu toplu çalıştırır: örnekler sırayla çekilir, en yakın düğüm, yönlendirme
+    // ve çarpışma kontrolü tüm adaylar için ağacın toplu başlangıcındaki haliyle (`rayon`
+    // özelliğiyle paralel) yapılır, geçerli adaylar örnek sırasıyla eklenir. Aynı tohumla sonuç
+    // iş parçacığı sayısından bağımsızdır ve tek adaylık toplu `extend` ile aynı ağacı kurar.
+    // Düğüm sınırı varsa toplu, sınıra kalan düğüm sayısıyla kısaltılır.
+    pub fn extend_batch(&mut self, count: usize) -> Vec<Extension<T>> {
+        let full = self.begin_iterations(count.max(1));
+        let count = match self.max_nodes {
+            Some(cap) if !full => count.min(cap.saturating_sub(self.nodes.len())),
+            _ => count,
+        }
+        .max(1);
+        let draws: Vec<(Point<T>, usize)> = (0..count).map(|_| self.draw_sample()).collect();
+        #[cfg(feature = "rayon")]
+        let candidates: Vec<Candidate<T>> = {
+            use rayon::prelude::*;
+            draws.par_iter().map(|&(sample, nearest)| self.evaluate(sample, nearest, full)).collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let candidates: Vec<Candidate<T>> = draws.iter().map(|&(sample, nearest)| self.evaluate(sample, nearest, full)).collect();
+        candidates.into_iter().map(|candidate| self.commit(candidate, full)).collect()
+    }
+
+    // Sıradaki `count` iterasyondan önceki bakım: dal-sınır budaması bu iterasyonlardan biri
+    // geçiş aralığına denk geliyorsa yapılır, ardından düğüm sınırı denetlenir. Ağacın
+    // büyüyemeyeceği (doymuş) durumda `true` döner.
+    fn begin_iterations(&mut self, count: usize) -> bool {
+        // Budamalar indeksleri değiştirdiğinden örnek çekilmeden önce yapılır
+        let interval = self.branch_and_bound_interval.max(1);
+        if self.branch_and_bound && self.iteration > 0 && (self.iteration + count - 1) / interval > (self.iteration - 1) / interval {
+            self.prune_branch_and_bound();
+        }
+        let full = self.max_nodes.is_some_and(|cap| self.nodes.len() >= cap)
+            && (self.node_cap_policy == NodeCapPolicy::Saturate || self.prune_for_cap() == 0);
+        self.saturated |= full;
+        full
+    }
+
+    fn draw_sample(&mut self) -> (Point<T>, usize) {
+        let (sample, nearest) = self.sample_in_domain();
+        self.nodes[nearest].nearest_count += 1;
+        (sample, nearest)
+    }
+
+    // Örneği en yakın düğümden yönlendirir ve yönlendirilen noktayı denetler; ağacı değiştirmez
+    fn evaluate(&self, sample: Point<T>, nearest: usize, full: bool) -> Candidate<T> {
+        let from = self.nodes[nearest].point;
+        let new_point = self.steer(&from, &sample);
+        // Tembel modda yalnızca uç nokta kontrol edilir, kenar kontrolü yol doğrulamasına ertelenir
+        // Doymuş ağaçta ve en iyi yolu iyileştiremeyecek noktalarda kenar kontrolüne gerek yoktur
+        let out_of_bound = !full && self.violates_bound(&new_point);
+        let over_budget = !full && !out_of_bound && self.exceeds_cost_limit(nearest, &new_point);
+        let edge_ok = if full || out_of_bound || over_budget {
+            false
+        } else if self.lazy_collision {
+            let arrival = self.nodes[nearest].time + from.distance(&new_point) / self.speed;
+            self.is_collision_free(&new_point) && self.is_free_of_dynamic(&new_point, arrival)
+        } else {
+            self.is_edge_free_from(nearest, &new_point)
+        };
+        Candidate { sample, nearest, new_point, out_of_bound, over_budget, edge_ok }
+    }
+
+    // Denetlenmiş adayı sayaçlara işler ve geçerliyse ağaca ekler; iterasyon burada tamamlanır
+    fn commit(&mut self, candidate: Candidate<T>, full: bool) -> Extension<T> {
+        let Candidate { sample, nearest, new_point, out_of_bound, over_budget, edge_ok } = candidate;
+        let goal_before = self.goal_node;
+        let from = self.nodes[nearest].point;
+        let mut parent = nearest;
+        if out_of_bound {
+            self.bnb_extensions_rejected += 1;
+        } else if over_budget {
+            self.cost_limit_rejections += 1;
+        } else if !full && self.lazy_collision {
+            self.lazy_stats.checks_deferred += 1;
+        } else if !full {
+            self.lazy_stats.checks_performed += 1;
+        }
+        let clearance_ok = !edge_ok || self.passes_clearance_test(&from, &new_point);
+        let edge_ok = edge_ok && clearance_ok;
+        if !full && !out_of_bound && !over_budget {
+            self.samples_total += 1;
+            self.samples_rejected += usize::from(!edge_ok);
+        }
+        let mut nodes_added = 0;
+        let added = if edge_ok {
+            if self.any_angle {
+                parent = self.farthest_visible_ancestor(nearest, &new_point);
+            } else if self.chooses_cheapest_parent() {
+                parent = self.cheapest_parent(nearest, &new_point);
+            }
+            // Arazi veya açıklık cezasıyla seçilen ata daha pahalı olabilir; en yakın düğüm bütçe içindedir
+            if self.exceeds_cost_limit(parent, &new_point) {
+                parent = nearest;
+            }
+            nodes_added = self.attach(new_point, parent, !self.lazy_collision);
+            Some(self.nodes.len() - 1)
+        } else {
+            if self.dynamic_domain && !full && !out_of_bound && !over_budget {
+                self.shrink_domain(nearest);
+            }
+            if let Some(observer) = self.observer.as_mut() {
+                let reason = if full {
+                    RejectReason::NodeCap
+                } else if out_of_bound {
+                    RejectReason::BranchAndBound
+                } else if over_budget {
+                    RejectReason::CostLimit
+                } else if !clearance_ok {
+                    RejectReason::Clearance
+                } else {
+                    RejectReason::Collision
+                };
+                observer.on_rejected(&new_point, reason);
+            }
+            None
+        };
+        let interval = self.goal_connect_interval;
+        let connected = match added {
+            Some(from) if interval > 0 && (self.iteration + 1).is_multiple_of(interval) && self.goal_node.is_none() => self.connect_to_goal(from),
+            _ => 0,
+        };
+        let nodes_added = nodes_added + connected;
+        let added = if connected > 0 { Some(self.nodes.len() - 1) } else { added };
+        self.last_step = Some(LastStepInfo { sample, nearest, steered: new_point, accepted: added.is_some(), nodes_added });
+        self.iteration += 1;
+        let reached_goal = goal_before.is_none() && self.goal_node.is_some();
+        Extension { sample, nearest, parent, new_point, added, full, reached_goal }
+    }
+
+    // Noktayı (gerekirse bölünerek) `parent` altına ekler ve kenarları işaretler; çapraz kenarlar
+    // açıksa yeni düğümler görünür komşularına da bağlanır. Eklenen düğüm sayısı döner.
+    fn attach(&mut self, point: Point<T>, parent: usize, checked: bool) -> usize {
+        let first = self.add_split_edge(point, parent);
+        let index = self.nodes.len() - 1;
+        for node in &mut self.nodes[first..] {
+            node.edge_checked = checked;
+        }
+        if self.cross_edges && !self.lazy_collision && self.dynamic_obstacles.is_empty() && self.moving_robots.is_empty() {
+            for i in first..=index {
+                self.connect_cross_edges(i);
+            }
+        }
+        index + 1 - first
+    }
+
+    // Bu iterasyonda eklenen `from` düğümünden ona en yakın hedefe düz kenar dener. Kenar normal
+    // bir genişletmeyle aynı denetimlerden (düğüm sınırı, dal-sınır, maliyet sınırı, çarpışma ve
+    // açıklık) geçerse hedef noktası eklenir. Eklenen düğüm sayısı döner.
+    fn connect_to_goal(&mut self, from: usize) -> usize {
+        if self.max_nodes.is_some_and(|cap| self.nodes.len() >= cap) {
+            return 0;
+        }
+        self.goal_connect_attempts += 1;
+        let point = self.nodes[from].point;
+        let goal = self.goals.iter().copied().fold(self.goal(), |best, g| if point.distance(&g) < point.distance(&best) { g } else { best });
+        if self.violates_bound(&goal) {
+            self.bnb_extensions_rejected += 1;
+            return 0;
+        }
+        if self.exceeds_cost_limit(from, &goal) {
+            self.cost_limit_rejections += 1;
+            return 0;
+        }
+        self.lazy_stats.checks_performed += 1;
+        if !self.is_edge_free_from(from, &goal) || !self.passes_clearance_test(&point, &goal) {
+            return 0;
+        }
+        // Kenar burada tam denetlendiğinden tembel modda da işaretlenir
+        self.attach(goal, from, true)
+    }
+
+    // `extend` ile bir iterasyon çalıştırır ve sonucunu sınıflandırır; ara değerler `last_step`
+    // içinde kalır. Kenar bölündüyse hedefe ulaşan düğüm bir ara düğüm olabilir; `GoalReached`
+    // hedef düğümünü verir. Tembel modda hedefe giden yol çağıran tarafından `validate_goal_path`
+    // ile doğrulanmalıdır.
+    pub fn step(&mut self) -> StepResult {
+        let extension = self.extend();
+        self.classify(&extension)
+    }
+
+    // `extend_batch` ile `count` iterasyon çalıştırır; sonuçlar örnek sırasıyla `step` gibi
+    // sınıflandırılır. Hedefe ulaşan adaydan sonraki adaylar da eklenmiş olur.
+    pub fn step_batch(&mut self, count: usize) -> Vec<StepResult> {
+        let extensions = self.extend_batch(count);
+        extensions.iter().map(|extension| self.classify(extension)).collect()
+    }
+
+    fn classify(&self, extension: &Extension<T>) -> StepResult {
+        match (extension.added, self.goal_node) {
+            (Some(_), Some(node)) if extension.reached_goal => StepResult::GoalReached { node },
+            (Some(node), _) => StepResult::Extended { node },
+            (None, _) if extension.full => StepResult::Exhausted,
+            (None, _) => StepResult::Rejected,
+        }
+    }
+
+    // En fazla `max_iters` iterasyonu sırasıyla çalıştıran yineleyici; hedefe ulaşıldığında veya
+    // ağaç büyüyemez olduğunda durur
+    pub fn run(&mut self, max_iters: usize) -> Run<'_, T> {
+        Run { rrt: self, remaining: max_iters, finished: false }
+    }
+
+    // Örnek çeker ve en yakın düğümü bulur. Dinamik alan açıksa en yakın düğümün görünürlük
+    // yarıçapı dışında kalan örnekler reddedilip yeniden çekilir; sonsuz döngüye girmemek için
+    // `DYNAMIC_DOMAIN_MAX_RESAMPLES` denemeden sonra son örnek kabul edilir.
+    fn sample_in_domain(&mut self) -> (Point<T>, usize) {
+        let mut sample = self.sample();
+        let mut nearest = self.nearest_for_growth(&sample);
+        if !self.dynamic_domain {
+            return (sample, nearest);
+        }
+        for _ in 0..DYNAMIC_DOMAIN_MAX_RESAMPLES {
+            if self.nodes[nearest].point.distance(&sample) <= self.nodes[nearest].domain_radius {
+                break;
+            }
+            self.domain_resamples += 1;
+            sample = self.sample();
+            nearest = self.nearest_for_growth(&sample);
+        }
+        (sample, nearest)
+    }
+
+    // Genişletilecek düğüm: sıralı büyümede her çağrı sıradaki kökün ağacına düşer
+    fn nearest_for_growth(&mut self, sample: &Point<T>) -> usize {
+        if self.forest_growth == ForestGrowth::RoundRobin && self.starts.len() > 1 {
+            let root = self.next_root;
+            self.next_root = (root + 1) % self.starts.len();
+            self.find_nearest_in_root(sample, root)
+        } else {
+            self.find_nearest(sample)
+        }
+    }
+
+    // Başarısız genişlemeden sonra düğümün görünürlük yarıçapını daraltır. Yarıçap adım boyutunun
+    // altına inmez, böylece düğüm hiçbir zaman tamamen dışarıda kalmaz.
+    fn shrink_domain(&mut self, index: usize) {
+        let node = &mut self.nodes[index];
+        node.domain_radius = if node.domain_radius.is_finite() {
+            node.domain_radius * self.dynamic_domain_shrink
+        } else {
+            self.dynamic_domain_radius
+        }
+        .max(self.step_size);
+    }
+
+    // Aday yolun henüz kontrol edilmemiş kenarlarını kökten hedefe doğru doğrular. Geçersiz bir
+    // kenar bulunursa o kenarın çocuğuyla başlayan alt ağaç budanır ve hedef düğümü hedef
+    // bölgesinde kalan başka bir düğümle yeniden aranır. Budanan alt ağaçların kök indeksleri
+    // (budama anındaki indeksleriyle) sırasıyla döndürülür.
+    pub fn validate_goal_path(&mut self) -> Vec<usize> {
+        let mut pruned = Vec::new();
+        'candidates: while let Some(goal) = self.goal_node {
+            let mut chain = vec![goal];
+            let mut current = goal;
+            while let Some(parent) = self.nodes[current].parent {
+                chain.push(parent);
+                current = parent;
+            }
+            for &child in chain.iter().rev() {
+                if self.nodes[child].edge_checked {
+                    continue;
+                }
+                let Some(parent) = self.nodes[child].parent else { continue };
+                self.lazy_stats.checks_performed += 1;
+                self.lazy_stats.checks_validated_later += 1;
+                if self.is_edge_free_from(parent, &self.nodes[child].point) {
+                    self.nodes[child].edge_checked = true;
+                } else {
+                    self.remove_subtree(child);
+                    self.lazy_stats.subtrees_pruned += 1;
+                    pruned.push(child);
+                    self.set_goal_node(self.first_in_goal_region());
+                    continue 'candidates;
+                }
+            }
+            break;
+        }
+        pruned
+    }
+
+    // `root` düğümünü ve tüm torunlarını ağaçtan çıkarır. Kalan düğümler sıralarını koruyarak
+    // sıkıştırılır, ebeveyn indeksleri, çapraz kenarlar ve `goal_node` yeni indekslere göre güncellenir; böylece
+    // hiçbir düğüm geçersiz bir ebeveyne işaret etmez. Kökler silinemez. Silinen düğüm sayısını
+    // döndürür.
+    pub fn remove_subtree(&mut self, root: usize) -> usize {
+        if root < self.starts.len() || root >= self.nodes.len() {
+            return 0;
+        }
+        let children = self.adjacency();
+        let mut removed = vec![false; self.nodes.len()];
+        let mut stack = vec![root];
+        while let Some(i) = stack.pop() {
+            removed[i] = true;
+            stack.extend_from_slice(&children[i]);
+        }
+        self.remove_nodes(&removed)
+    }
+
+    // Son eklenen düğümü çıkarıp döndürür. Yalnızca yapraklar çıkarılır: düğümün çocuğu varsa,
+    // ağaçta yalnızca kökler kaldıysa veya ağaç boşsa hiçbir şey değişmez ve `None` döner. Düğüme
+    // bağlı çapraz kenarlar silinir; düğüm hedef düğümüyse hedef düğümü kalmaz.
+    pub fn pop_node(&mut self) -> Option<Node<T>> {
+        let last = self.nodes.len().checked_sub(1).filter(|&i| i >= self.starts.len())?;
+        if self.nodes.iter().any(|n| n.parent == Some(last)) {
+            return None;
+        }
+        let node = self.nodes[last].clone();
+        let mut removed = vec![false; self.nodes.len()];
+        removed[last] = true;
+        self.remove_nodes(&removed);
+        Some(node)
+    }
+
+    // İşaretli düğümleri siler ve kalanları sıkıştırır. Çağıran, işaretli düğümlerin tüm
+    // torunlarının da işaretli olmasını sağlamalıdır. Silinen düğüm sayısını döndürür.
+    fn remove_nodes(&mut self, removed: &[bool]) -> usize {
+        let mut new_index = vec![usize::MAX; self.nodes.len()];
+        let mut next = 0;
+        for (i, &gone) in removed.iter().enumerate() {
+            if !gone {
+                new_index[i] = next;
+                next += 1;
+            }
+        }
+        let before = self.nodes.len();
+        let mut i = 0;
+        self.nodes.retain(|_| {
+            let keep = !removed[i];
+            i += 1;
+            keep
+        });
+        for node in &mut self.nodes {
+            node.parent = node.parent.map(|p| new_index[p]);
+        }
+        let kept: Vec<bool> = self.graph_edges.iter().map(|&(a, b)| !removed[a] && !removed[b]).collect();
+        self.retain_graph_edges(&kept);
+        for edge in &mut self.graph_edges {
+            *edge = (new_index[edge.0], new_index[edge.1]);
+        }
+        if self.goal_node.is_some_and(|g| removed[g]) {
+            // Yeni hedef düğümünün yolu daha uzun olabilir; bir sonraki geçişe kadar reddetme yapılmaz
+            self.bnb_best_cost = T::infinity();
+        }
+        self.set_goal_node(self.goal_node.filter(|&g| !removed[g]).map(|g| new_index[g]));
+        if removed[self.best_goal_node] {
+            self.refresh_best_goal_distance();
+        } else {
+            self.best_goal_node = new_index[self.best_goal_node];
+        }
+        // Budama en büyük değerleri küçültebilir; seyrek olduğundan burada yeniden hesaplanır
+        self.max_depth = self.nodes.iter().map(|n| n.depth).max().unwrap_or(0);
+        self.max_cost = self.nodes.iter().map(|n| n.cost).fold(T::zero(), T::max);
+        self.refresh_longest_edge();
+        self.rebuild_neighbor_index();
+        self.rebuild_coverage();
+        before - self.nodes.len()
+    }
+
+    // Düğüm sınırına ulaşıldığında yaprakları siler: kökler ve hedefe giden yol üzerindeki düğümler
+    // hiç silinmez, aday yapraklar hedefe ve yola olan uzaklığın küçüğüne göre en uzaktan başlanarak
+    // seçilir. Sınırın `NODE_CAP_PRUNE_FRACTION` oranı kadar (en az bir) yaprak silinir; silinen
+    // sayı döner.
+    pub fn prune_for_cap(&mut self) -> usize {
+        let Some(cap) = self.max_nodes else { return 0 };
+        let mut protected = vec![false; self.nodes.len()];
+        protected[..self.starts.len()].fill(true);
+        let mut path = Vec::new();
+        let mut current = self.goal_node;
+        while let Some(i) = current {
+            protected[i] = true;
+            path.push(self.nodes[i].point);
+            current = self.nodes[i].parent;
+        }
+        let children = self.adjacency();
+        let goals = self.goals.clone();
+        let mut leaves: Vec<(T, usize)> = (0..self.nodes.len())
+            .filter(|&i| !protected[i] && children[i].is_empty())
+            .map(|i| {
+                let p = self.nodes[i].point;
+                let to_goal = goals.iter().map(|g| g.distance(&p)).fold(T::infinity(), T::min);
+                let to_path = path.iter().map(|q| q.distance(&p)).fold(T::infinity(), T::min);
+                (to_goal.min(to_path), i)
+            })
+            .collect();
+        leaves.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal).then(a.1.cmp(&b.1)));
+        let batch = ((T::cast(cap) * T::cast(NODE_CAP_PRUNE_FRACTION)).to_usize().unwrap_or(0)).max(1);
+        let mut removed = vec![false; self.nodes.len()];
+        for &(_, i) in leaves.iter().take(batch) {
+            removed[i] = true;
+        }
+        let count = self.remove_nodes(&removed);
+        self.nodes_pruned += count;
+        count
+    }
+
+    // Bulunan en iyi yolun maliyeti: çapraz kenarlar açıksa çizgedeki en kısa yolun uzunluğu,
+    // değilse hedef düğümünün kökten maliyeti. Yol yoksa `None` döner. Değer hedef düğümü veya
+    // çapraz kenarlar değiştiğinde hesaplanıp saklandığından yol yeniden izlenmez; çapraz
+    // kenarlar yalnızca eklendikçe (veya budamada silinip hedef düğümü değişmedikçe) artmaz.
+    pub fn best_cost(&self) -> Option<T> {
+        self.best_cost
+    }
+
+    // Hedef bölgesine ilk ulaşan düğüm; hedefe henüz ulaşılmadıysa `None` döner
+    pub fn goal_node(&self) -> Option<usize> {
+        self.goal_node
+    }
+
+    // Hedef düğümünü atar ve en iyi yol maliyetini yeniler
+    fn set_goal_node(&mut self, goal_node: Option<usize>) {
+        self.goal_node = goal_node;
+        self.refresh_best_cost();
+    }
+
+    fn refresh_best_cost(&mut self) {
+        self.best_cost = match self.goal_node {
+            Some(goal) if self.cross_edges => self.shortest_path_indices(goal).map(|path| self.chain_cost(&path)),
+            Some(goal) => Some(self.nodes[goal].cost),
+            None => None,
+        };
+    }
+
+    // Noktadan hedef bölgesine kalan maliyetin iyimser tahmini; hedef bölgesi verilmişse biçimi
+    // bilinmediğinden sıfırdır
+    fn cost_to_go_bound(&self, p: &Point<T>) -> T {
+        if self.goal_region.is_some() {
+            return T::zero();
+        }
+        let distance = self.goals
+            .iter()
+            .map(|goal| (p.distance(goal) - self.goal_threshold).max(T::zero()))
+            .fold(T::infinity(), T::min);
+        distance * self.cheapest_cost_per_length()
+    }
+
+    // Dal-sınır açıkken yeni noktadan geçen hiçbir yol en iyi yoldan kısa olamıyor mu. Ebeveyn
+    // henüz seçilmediğinden kökten gelen maliyet için düz çizgi uzaklığı kullanılır. En iyi
+    // maliyet her iterasyonda yeniden hesaplanmaz, son budama geçişindeki değer kullanılır; yol
+    // yalnızca kısaldığından eski değer hiçbir zaman fazla reddetmez.
+    fn violates_bound(&self, p: &Point<T>) -> bool {
+        self.branch_and_bound && self.root_distance(p) * self.cheapest_cost_per_length() + self.cost_to_go_bound(p) > self.bnb_best_cost
+    }
+
+    // Kökten gelen maliyeti artı hedefe kalan iyimser maliyeti en iyi yolun maliyetini aşan
+    // düğümleri torunlarıyla birlikte siler. Yalnızca ağaç varken kökten gelen maliyet düğümün
+    // maliyetidir ve torunların sınırı ebeveyninkinden küçük olamaz. Çapraz kenarlarla bir düğüme
+    // daha kısa yoldan ulaşılabileceğinden kökten düz çizgi uzaklığı kullanılır; bu durumda en iyi
+    // yol üzerindeki düğümler ve ataları ayrıca korunur. Silinen düğüm sayısını döndürür.
+    pub fn prune_branch_and_bound(&mut self) -> usize {
+        let Some(goal) = self.goal_node else { return 0 };
+        let best = self.best_cost().unwrap_or_else(T::infinity);
+        self.bnb_best_cost = best;
+        let mut protected = vec![false; self.nodes.len()];
+        let on_best_path = if self.cross_edges { self.shortest_path_indices(goal).unwrap_or_default() } else { vec![goal] };
+        for mut current in on_best_path {
+            while !protected[current] {
+                protected[current] = true;
+                let Some(parent) = self.nodes[current].parent else { break };
+                current = parent;
+            }
+        }
+        let bound = |i: usize| {
+            let node = &self.nodes[i];
+            let cost_to_come = if self.cross_edges { self.root_distance(&node.point) * self.cheapest_cost_per_length() } else { node.cost };
+            cost_to_come + self.cost_to_go_bound(&node.point)
+        };
+        // Ebeveyn her zaman çocuğundan küçük indekslidir; sıralı tarama silinen ebeveyni önce görür
+        let mut removed = vec![false; self.nodes.len()];
+        for i in self.starts.len()..self.nodes.len() {
+            let parent_removed = self.nodes[i].parent.is_some_and(|p| removed[p]);
+            removed[i] = parent_removed || (!protected[i] && bound(i) > best);
+        }
+        let count = self.remove_nodes(&removed);
+        self.bnb_nodes_pruned += count;
+        count
+    }
+
+    // Kenar maliyeti: arazi çarpanıyla ağırlıklı uzunluk ve ağırlıklı açıklık cezası. Arazi
+    // yoksa ve ağırlık sıfırsa yalnızca uzunluktur.
+    pub fn edge_cost(&self, from: &Point<T>, to: &Point<T>) -> T {
+        let mut length = from.distance(to);
+        if let Some(terrain) = &self.terrain {
+            length *= terrain.mean_cost(from, to, self.collision_resolution);
+        }
+        if self.clearance_weight <= T::zero() {
+            return length;
+        }
+        length + self.clearance_weight * self.clearance_penalty(from, to)
+    }
+
+    // Kenar boyunca çarpışma çözünürlüğü aralıklarıyla örneklenen noktalarda engele
+    // `clearance_distance` altında kalınan oranın karesinin ortalaması, kenar uzunluğuyla çarpılır.
+    // Engel yüzeyine değen kenarda metre başına 1, yeterince uzak kenarda 0'dır. `Inverse`
+    // biçiminde ceza uzunluğun orta noktanın açıklığına oranıdır; açıklık sonsuz maliyet vermemesi
+    // için çarpışma çözünürlüğünün altına inmez.
+    pub fn clearance_penalty(&self, from: &Point<T>, to: &Point<T>) -> T {
+        let length = from.distance(to);
+        if self.clearance_cost == ClearanceCost::Inverse {
+            let mid = Point::lerp(*from, *to, T::cast(0.5));
+            let clearance = (self.checker().clearance(&mid) - self.robot_radius).max(self.collision_resolution);
+            return length / clearance;
+        }
+        let steps = (length / self.collision_resolution).ceil().max(T::one()).to_usize().unwrap_or(1);
+        let checker = self.checker();
+        let total = (0..=steps).fold(T::zero(), |total, i| {
+            let p = Point::lerp(*from, *to, T::cast(i) / T::cast(steps));
+            let clearance = (checker.clearance(&p) - self.robot_radius).max(T::zero());
+            let shortfall = (T::one() - clearance / self.clearance_distance).max(T::zero());
+            total + shortfall * shortfall
+        });
+        total / T::cast(steps + 1) * length
+    }
+
+    // Açıklık ağırlığı verilmişse engellere yakın kenarlar `exp(-ağırlık · metre başına ceza)`
+    // olasılıkla kabul edilir (T-RRT'nin geçiş testine benzer). Ağaç duvar kenarlarında seyrek
+    // kalır, dar geçitler ise tamamen kapanmaz.
+    fn passes_clearance_test(&mut self, from: &Point<T>, to: &Point<T>) -> bool {
+        if self.clearance_weight <= T::zero() {
+            return true;
+        }
+        let length = from.distance(to);
+        if length <= T::zero() {
+            return true;
+        }
+        let penalty = self.clearance_penalty(from, to) / length;
+        T::unit(&mut self.rng) < (-self.clearance_weight * penalty).exp()
+    }
+
+    // Yeni düğümü ebeveyni dışında `cross_edge_radius` içindeki, kenarı çarpışmasız tüm düğümlere
+    // bağlar. Ebeveyn bağlantıları kapsayan ağaç olarak kalır; çapraz kenarlar yalnızca
+    // `shortest_path` tarafından kullanılır.
+    fn connect_cross_edges(&mut self, index: usize) {
+        let point = self.nodes[index].point;
+        let parent = self.nodes[index].parent;
+        let before = self.graph_edges.len();
+        let radius = self.max_edge_length.map_or(self.cross_edge_radius, |cap| self.cross_edge_radius.min(cap));
+        for i in self.neighbors_within(&point, radius) {
+            if i == index || Some(i) == parent {
+                continue;
+            }
+            if self.is_edge_collision_free(&self.nodes[i].point, &point) {
+                self.graph_edges.push((i, index));
+                self.graph_edge_costs.push(self.edge_cost(&self.nodes[i].point, &point));
+            }
+        }
+        // Yeni kenarlar hedefe daha kısa bir yol açmış olabilir
+        if self.goal_node.is_some() && self.graph_edges.len() > before {
+            self.refresh_best_cost();
+        }
+    }
+
+    // Kenar maliyeti uzunluktan farklıysa yeni nokta en ucuz ebeveyne bağlanır; tembel modda kenarlar
+    // eklenirken kontrol edilmediğinden ebeveyn seçilmez
+    fn chooses_cheapest_parent(&self) -> bool {
+        (self.clearance_weight > T::zero() || self.terrain.is_some()) && !self.lazy_collision
+    }
+
+    // Metre başına en ucuz maliyet; uzaklıktan türetilen alt sınırlar bununla çarpılır
+    fn cheapest_cost_per_length(&self) -> T {
+        self.terrain.as_ref().map_or_else(T::one, |terrain| terrain.min_cost().min(T::one()))
+    }
+
+    // Yakındaki düğümler arasından yeni noktaya kökten maliyeti en küçük olacak, kenarı
+    // çarpışmasız ebeveyn (RRT*'ın ebeveyn seçimi; yeniden bağlama yapılmaz)
+    fn cheapest_parent(&self, nearest: usize, point: &Point<T>) -> usize {
+        let radius = self.step_size * T::cast(CHEAPEST_PARENT_RADIUS_STEPS);
+        let cost_via = |i: usize| self.nodes[i].cost + self.edge_cost(&self.nodes[i].point, point);
+        let mut best = (nearest, cost_via(nearest));
+        for i in self.neighbors_within(point, radius) {
+            if i == nearest {
+                continue;
+            }
+            let cost = cost_via(i);
+            if cost < best.1 && self.is_edge_free_from(i, point) {
+                best = (i, cost);
+            }
+        }
+        best.0
+    }
+
+    // `node` düğümünün atalarını `any_angle_depth` kadar yukarı doğru gezer ve `point` noktasına
+    // çarpışmasız düz bir parçayla bağlanabilen en uzak atayı döndürür (Theta* benzeri kısayol).
+    // Hiçbir ata görünmüyorsa düğümün kendisi döner.
+    fn farthest_visible_ancestor(&self, node: usize, point: &Point<T>) -> usize {
+        let mut best = node;
+        let mut current = self.nodes[node].parent;
+        for _ in 0..self.any_angle_depth {
+            let Some(ancestor) = current else { break };
+            if self.is_edge_free_from(ancestor, point) {
+                best = ancestor;
+            }
+            current = self.nodes[ancestor].parent;
+        }
+        best
+    }
+
+    // Hedefe ulaşana, `max_iters` iterasyon dolana veya zaman bütçesi bitene kadar ekransız planlar.
+    // Hedef düğümü eklendiği iterasyonda döner; hedefe daha önce ulaşılmışsa ağaç büyütülmez.
+    pub fn plan(&mut self, max_iters: usize) -> PlanResult<T> {
+        self.plan_with_clock(max_iters, &SystemClock::new())
+    }
+
+    // Uç noktalar planlanabilir mi: en az bir başlangıç ve en az bir hedef, robot yarıçapı hesaba
+    // katılarak çarpışmasız olmalıdır. Hedef bölgesi verilmişse biçimi bilinmediğinden hedefler
+    // kontrol edilmez.
+    pub fn check_endpoints(&self) -> Result<(), PlanError> {
+        if !self.starts.iter().any(|s| self.is_collision_free(s)) {
+            return Err(PlanError::StartInObstacle);
+        }
+        if self.goal_region.is_none() && !self.goals.iter().any(|g| self.is_collision_free(g)) {
+            return Err(PlanError::GoalUnreachableInObstacle);
+        }
+        Ok(())
+    }
+
+    // `plan` ile aynıdır, ancak uç noktalar önce `check_endpoints` ile kontrol edilir; engel
+    // içindeki uç noktada hiçbir iterasyon çalıştırılmadan hata döner
+    pub fn try_plan(&mut self, max_iters: usize) -> Result<PlanResult<T>, PlanError> {
+        self.check_endpoints()?;
+        Ok(self.plan(max_iters))
+    }
+
+    // `plan` ile aynıdır, ancak zaman bütçesi verilen saatle ölçülür. Bütçe her iterasyondan önce
+    // kontrol edilir; sıfır bütçe hiçbir iterasyon çalıştırmadan döner.
+    pub fn plan_with_clock(&mut self, max_iters: usize, clock: &dyn Clock) -> PlanResult<T> {
+        let started = clock.now();
+        if self.goal_node.is_some() {
+            self.validate_goal_path();
+            if let Some(path) = self.best_path() {
+                return PlanResult::Found { path, stats: self.plan_stats(0, false) };
+            }
+        }
+        let mut iteration = 0;
+        while iteration < max_iters {
+            if self.time_budget.is_some_and(|budget| clock.now() - started >= budget.as_secs_f64()) {
+                return PlanResult::NotFound { stats: self.plan_stats(iteration, true) };
+            }
+            // Toplu modda hedefe ulaşılan toplunun tüm iterasyonları sayılır
+            let reached = if self.batch_size > 1 {
+                let results = self.step_batch(self.batch_size.min(max_iters - iteration));
+                iteration += results.len();
+                results.iter().any(|r| matches!(r, StepResult::GoalReached { .. }))
+            } else {
+                iteration += 1;
+                matches!(self.step(), StepResult::GoalReached { .. })
+            };
+            if reached {
+                self.validate_goal_path();
+                if let Some(path) = self.best_path() {
+                    return PlanResult::Found { path, stats: self.plan_stats(iteration, false) };
+                }
+            }
+        }
+        PlanResult::NotFound { stats: self.plan_stats(max_iters, false) }
+    }
+
+    fn plan_stats(&self, iterations: usize, budget_exhausted: bool) -> PlanStats {
+        PlanStats {
+            iterations,
+            nodes: self.nodes.len(),
+            budget_exhausted,
+            best_goal_distance: self.best_goal_distance.as_f32(),
+            best_goal_iteration: self.best_goal_iteration,
+            samples_total: self.samples_total,
+            samples_rejected: self.samples_rejected,
+        }
+    }
+
+    // Ağacı temizler ve verilen birincil başlangıç/hedef ile yeniden başlatır; ek kökler, ek hedefler,
+    // engeller ve parametreler korunur
+    pub fn reset(&mut self, start: Point<T>, goal: Point<T>) {
+        self.starts[0] = start;
+        self.nodes.clear();
+        self.nodes.extend(self.starts.iter().enumerate().map(|(i, &s)| Node::root(s, i)));
+        self.goals[0] = goal;
+        self.set_goal_node(None);
+        self.root_goal_iterations = vec![None; self.starts.len()];
+        self.next_root = 0;
+        self.graph_edges.clear();
+        self.graph_edge_costs.clear();
+        self.iteration = 0;
+        self.lazy_stats = LazyStats::default();
+        self.domain_resamples = 0;
+        self.samples_total = 0;
+        self.samples_rejected = 0;
+        self.saturated = false;
+        self.nodes_pruned = 0;
+        self.bnb_nodes_pruned = 0;
+        self.bnb_extensions_rejected = 0;
+        self.goal_connect_attempts = 0;
+        self.cost_limit_rejections = 0;
+        self.bnb_best_cost = T::infinity();
+        self.last_step = None;
+        self.max_depth = 0;
+        self.max_cost = T::zero();
+        self.longest_edge = T::zero();
+        self.refresh_best_goal_distance();
+        self.rebuild_neighbor_index();
+        self.rebuild_coverage();
+    }
+
+    // Kök listesini değiştirir ve ağacı bu köklerle yeniden başlatır; boş liste yok sayılır. Her kök
+    // ebeveynsiz bir düğümdür, en yakın düğüm araması tüm köklerden büyüyen ağaçları kapsar.
+    pub fn set_starts(&mut self, starts: Vec<Point<T>>) {
+        if starts.is_empty() {
+            return;
+        }
+        let (start, goal) = (starts[0], self.goal());
+        self.starts = starts;
+        self.reset(start, goal);
+    }
+
+    // Noktanın en yakın köke düz çizgi uzaklığı; kökten gelen maliyetin alt sınırı
+    fn root_distance(&self, p: &Point<T>) -> T {
+        self.starts.iter().map(|s| s.distance(p)).fold(T::infinity(), T::min)
+    }
+
+    // Haritayı koruyarak ağacı yeni uç noktalarla yeniden başlatır. Engele çarpan veya alan
+    // dışında kalan uç nokta rastgele çarpışmasız bir noktayla, hedef başlangıcın hedef eşiği
+    // içindeyse hedef yeniden çekilir. Kullanılan uç noktalar döner; serbest alanda yer
+    // bulunamazsa ağaç değiştirilmez.
+    pub fn reset_with(&mut self, start: Point<T>, goal: Point<T>) -> Result<(Point<T>, Point<T>), FreeSpaceError> {
+        let start = if self.is_collision_free(&start) { start } else { self.random_free_point()? };
+        let mut goal = goal;
+        let mut attempts = 0;
+        while !self.is_collision_free(&goal) || start.distance(&goal) < self.goal_threshold {
+            if attempts == FREE_POINT_ATTEMPTS {
+                return Err(FreeSpaceError { attempts });
+            }
+            goal = self.random_free_point()?;
+            attempts += 1;
+        }
+        self.reset(start, goal);
+        Ok((start, goal))
+    }
+
+    // Alan içinde düzgün örneklenen çarpışmasız nokta; ağacın rastgele sayı üretecini kullanır
+    pub fn random_free_point(&mut self) -> Result<Point<T>, FreeSpaceError> {
+        let checker = CollisionChecker {
+            bounds: &self.bounds,
+            obstacles: &self.obstacles,
+            resolution: self.collision_resolution,
+            adaptive: self.adaptive_collision,
+            robot_radius: self.robot_radius,
+            robot_shape: &self.robot_shape,
+        };
+        checker
+            .random_free_point(&mut self.rng, FREE_POINT_ATTEMPTS)
+            .ok_or(FreeSpaceError { attempts: FREE_POINT_ATTEMPTS })
+    }
+
+    // Haritaya yeni bir engel ekler
+    // Ağaç korunur; yalnızca yeni engelin kestiği kenarların alt ağaçları budanır ve silinen düğüm
+    // sayısı döner
+    pub fn add_obstacle(&mut self, obstacle: Obstacle<T>) -> usize {
+        self.obstacles.push(obstacle);
+        self.invalidate_edges_blocked_by(&obstacle)
+    }
+
+    // Var olan bir engeli yenisiyle değiştirir (taşıma veya yeniden boyutlandırma); engelin yeni
+    // halinin kestiği kenarların alt ağaçları budanır ve silinen düğüm sayısı döner
+    pub fn move_obstacle(&mut self, index: usize, obstacle: Obstacle<T>) -> usize {
+        match self.obstacles.get_mut(index) {
+            Some(slot) => {
+                *slot = obstacle;
+                self.invalidate_edges_blocked_by(&obstacle)
+            }
+            None => 0,
+        }
+    }
+
+    // Engelin (robot yarıçapı kadar şişirilmiş haline) değen ağaç kenarlarını bulur ve her birinin
+    // çocuğuyla başlayan alt ağacı siler; ağacın geri kalanı korunduğundan büyüme kaldığı yerden
+    // sürer. Ebeveynin indeksi çocuğunkinden küçük olduğundan silinen kenarların torunları tek
+    // ileri geçişte işaretlenir. Engele değen çapraz kenarlar da çıkarılır. Hedef düğümü silindiyse
+    // bölgede kalan ilk düğüm yeni hedef düğümü olur; tembel modda onun yolu çağıran tarafından
+    // `validate_goal_path` ile doğrulanmalıdır. Silinen düğüm sayısı döner.
+    pub fn invalidate_edges_blocked_by(&mut self, obstacle: &Obstacle<T>) -> usize {
+        self.prune_edges_where(|rrt, a, b| match rrt.robot_shape.swept(a, b) {
+            Some(swept) => obstacle.intersects_convex(&swept),
+            None => obstacle.segment_distance(a, b) <= rrt.robot_radius,
+        })
+    }
+
+    // `blocked` doğru dönen ağaç kenarlarının alt ağaçlarını ve çapraz kenarları siler
+    fn prune_edges_where(&mut self, blocked: impl Fn(&Self, &Point<T>, &Point<T>) -> bool) -> usize {
+        let mut removed = vec![false; self.nodes.len()];
+        for i in self.starts.len()..self.nodes.len() {
+            if let Some(parent) = self.nodes[i].parent {
+                removed[i] = removed[parent] || blocked(self, &self.nodes[parent].point, &self.nodes[i].point);
+            }
+        }
+        let kept: Vec<bool> = self.graph_edges.iter().map(|&(a, b)| !blocked(self, &self.nodes[a].point, &self.nodes[b].point)).collect();
+        self.retain_graph_edges(&kept);
+        if !removed.contains(&true) {
+            self.refresh_best_cost();
+            return 0;
+        }
+        let count = self.remove_nodes(&removed);
+        if self.goal_node.is_none() {
+            self.set_goal_node(self.first_in_goal_region());
+        }
+        count
+    }
+
+    // Ağaçtaki tüm düğümleri çevreleyen en küçük kutu; yalnızca kök varsa başlangıç noktasında
+    // sıfır alanlı bir kutudur
+    pub fn tree_bounds(&self) -> Bounds<T> {
+        let root = self.nodes[0].point;
+        self.nodes.iter().fold(Bounds::new(root.x, root.x, root.y, root.y), |b, node| {
+            Bounds::new(
+                b.min_x.min(node.point.x),
+                b.max_x.max(node.point.x),
+                b.min_y.min(node.point.y),
+                b.max_y.max(node.point.y),
+            )
+        })
+    }
+
+    // Ebeveyn bağlantılarının tersi: `i`. eleman `i` düğümünün çocuklarını artan indeks sırasıyla
+    // listeler. Düğüm konumlarıyla birlikte ağacı bir çizge olarak tam tanımlar.
+    pub fn adjacency(&self) -> Vec<Vec<usize>> {
+        let mut children = vec![Vec::new(); self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Some(parent) = node.parent {
+                children[parent].push(i);
+            }
+        }
+        children
+    }
+
+    // Derinlikler ve çocuk sayıları ebeveyn bağlantılarından hesaplanır; ebeveynin indeksi her
+    // zaman çocuğunkinden küçük olduğundan tek geçiş yeterlidir
+    pub fn stats(&self) -> TreeStats {
+        let n = self.nodes.len();
+        let depth = self.depths();
+        let mut children = vec![0; n];
+        for node in &self.nodes {
+            if let Some(parent) = node.parent {
+                children[parent] += 1;
+            }
+        }
+        let internal = children.iter().filter(|&&c| c > 0).count();
+        TreeStats {
+            max_depth: depth.iter().copied().max().unwrap_or(0),
+            mean_depth: if n == 0 { 0.0 } else { depth.iter().sum::<usize>() as f32 / n as f32 },
+            max_branching: children.iter().copied().max().unwrap_or(0),
+            mean_branching: if internal == 0 { 0.0 } else { children.iter().sum::<usize>() as f32 / internal as f32 },
+            leaves: n - internal,
+        }
+    }
+
+    fn depths(&self) -> Vec<usize> {
+        let mut depth = vec![0; self.nodes.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            if let Some(parent) = node.parent {
+                depth[i] = depth[parent] + 1;
+            }
+        }
+        depth
+    }
+
+    // `stats` özetine derinlik histogramını ve kenar uzunluklarının dağılımını ekler
+    pub fn statistics(&self) -> TreeStatistics<T> {
+        let shape = self.stats();
+        let mut depth_histogram = vec![0; shape.max_depth + 1];
+        for d in self.depths() {
+            depth_histogram[d] += 1;
+        }
+        let lengths: Vec<T> = self
+            .nodes
+            .iter()
+            .filter_map(|node| node.parent.map(|parent| self.nodes[parent].point.distance(&node.point)))
+            .collect();
+        let (mut min, mut max, mut sum) = (T::infinity(), T::zero(), T::zero());
+        for &length in &lengths {
+            min = min.min(length);
+            max = max.max(length);
+            sum += length;
+        }
+        let mut edge_length_histogram = Vec::new();
+        if !lengths.is_empty() {
+            edge_length_histogram = vec![0; EDGE_LENGTH_BINS];
+            for &length in &lengths {
+                // Bütün kenarlar sıfır uzunluktaysa hepsi ilk kutuya düşer
+                let bin = if max > T::zero() { (length / max * T::cast(EDGE_LENGTH_BINS)).to_usize().unwrap_or(0) } else { 0 };
+                edge_length_histogram[bin.min(EDGE_LENGTH_BINS - 1)] += 1;
+            }
+        }
+        TreeStatistics {
+            nodes: self.nodes.len(),
+            shape,
+            depth_histogram,
+            min_edge_length: if lengths.is_empty() { T::zero() } else { min },
+            mean_edge_length: if lengths.is_empty() { T::zero() } else { sum / T::cast(lengths.len()) },
+            max_edge_length: max,
+            edge_length_histogram,
+        }
+    }
+
+    // Kökten verilen düğüme, ağaç kenarları ve çapraz kenarlardan oluşan çizge üzerindeki en kısa
+    // yol; kenar ağırlıkları kenar maliyeti, sezgisel düz çizgi uzaklığının en ucuz metre
+    // maliyetiyle çarpımıdır (arazi yoksa uzaklığın kendisi). Çapraz kenar yoksa her düğüme tek
+    // yol olduğundan sonuç `trace_path_from` ile aynıdır. İndeks ağaçta yoksa `None` döner.
+    pub fn shortest_path(&self, goal_node: usize) -> Option<Path<T>> {
+        let indices = self.shortest_path_indices(goal_node)?;
+        Some(Path::new(indices.into_iter().map(|i| self.nodes[i].point).collect(), Some(goal_node)))
+    }
+
+    // Çapraz kenarlardan `kept` içinde işaretli olanları maliyetleriyle birlikte tutar. Kenarlar
+    // dışarıdan değiştirilip maliyetlerle hizası bozulduysa maliyetler atılır ve arama sırasında
+    // yeniden hesaplanır.
+    fn retain_graph_edges(&mut self, kept: &[bool]) {
+        let mut k = 0;
+        self.graph_edges.retain(|_| {
+            k += 1;
+            kept[k - 1]
+        });
+        if self.graph_edge_costs.len() == kept.len() {
+            let mut k = 0;
+            self.graph_edge_costs.retain(|_| {
+                k += 1;
+                kept[k - 1]
+            });
+        } else {
+            self.graph_edge_costs.clear();
+        }
+    }
+
+    // Çizgedeki `i` ile `j` düğümlerini bağlayan kenarın maliyeti: ağaç kenarında çocuğun ve
+    // ebeveynin maliyet farkı, çapraz kenarda eklenirken hesaplanan maliyet. Maliyet uzunluksa
+    // doğrudan uzunluk kullanılır.
+    fn graph_edge_cost(&self, i: usize, j: usize, cross: Option<usize>) -> T {
+        let (a, b) = (&self.nodes[i], &self.nodes[j]);
+        if self.terrain.is_none() && self.clearance_weight <= T::zero() {
+            return a.point.distance(&b.point);
+        }
+        match cross {
+            Some(k) => self.graph_edge_costs.get(k).copied().unwrap_or_else(|| self.edge_cost(&a.point, &b.point)),
+            None => (a.cost - b.cost).abs(),
+        }
+    }
+
+    // Ardışık düğümleri bağlayan kenarların toplam maliyeti
+    fn chain_cost(&self, chain: &[usize]) -> T {
+        chain.windows(2).fold(T::zero(), |total, w| total + self.edge_cost(&self.nodes[w[0]].point, &self.nodes[w[1]].point))
+    }
+
+    // `shortest_path` yolunun düğüm indeksleri
+    fn shortest_path_indices(&self, goal_node: usize) -> Option<Vec<usize>> {
+        // Komşular, çapraz kenarlarda kenarın `graph_edges` içindeki sırasıyla
+        let mut neighbors: Vec<Vec<(usize, Option<usize>)>> =
+            self.adjacency().into_iter().map(|children| children.into_iter().map(|c| (c, None)).collect()).collect();
+        for (k, &(a, b)) in self.graph_edges.iter().enumerate() {
+            neighbors[a].push((b, Some(k)));
+            neighbors[b].push((a, Some(k)));
+        }
+        let target = self.nodes.get(goal_node)?.point;
+        let per_length = self.cheapest_cost_per_length();
+        // Ormanda arama hedef düğümünün kendi ağacının kökünden başlar
+        let mut root = goal_node;
+        while let Some(parent) = self.nodes[root].parent {
+            root = parent;
+        }
+        astar(
+            self.nodes.len(),
+            root,
+            goal_node,
+            |i, out| {
+                let parent = self.nodes[i].parent.map(|p| (p, None));
+                for &(j, cross) in neighbors[i].iter().chain(parent.iter()) {
+                    out.push((j, self.graph_edge_cost(i, j, cross).as_f32()));
+                }
+            },
+            |i| (self.nodes[i].point.distance(&target) * per_length).as_f32(),
+        )
+    }
+
+    // Robotun ağaçta `node` düğümüne giden yol boyunca `t` anındaki konumu; `t` varış anını geçtiyse
+    // düğümün kendisi, kökten yola çıkmadan önceyse köktür
+    pub fn position_at(&self, node: usize, t: T) -> Point<T> {
+        let mut current = node;
+        while let Some(parent) = self.nodes[current].parent {
+            let (from, to) = (&self.nodes[parent], &self.nodes[current]);
+            if from.time <= t {
+                if t >= to.time || to.time <= from.time {
+                    return to.point;
+                }
+                return Point::lerp(from.point, to.point, (t - from.time) / (to.time - from.time));
+            }
+            current = parent;
+        }
+        self.nodes[current].point
+    }
+
+    // Ara noktalardan geçen düzgün Catmull-Rom eğrisi; her parça `samples_per_segment` eşit
+    // parametre aralığıyla örneklenir, uçlarda yansıtılmış hayali noktalar kullanılır. Örnekleri
+    // bir engele veya alan dışına taşan parça yerine ara noktaları birleştiren düz parça konur;
+    // böylece geçerli bir yoldan her zaman geçerli bir eğri çıkar. Eğri yolun hedef düğümünü taşır.
+    pub fn spline_path(&self, path: &Path<T>, samples_per_segment: usize) -> Path<T> {
+        let waypoints = path.points();
+        let n = waypoints.len();
+        if n < 2 {
+            return path.clone();
+        }
+        let samples = samples_per_segment.max(1);
+        let two = T::cast(2.0);
+        let at = |i: isize| match i {
+            -1 => waypoints[0] * two - waypoints[1],
+            i if i as usize >= n => waypoints[n - 1] * two - waypoints[n - 2],
+            i => waypoints[i as usize],
+        };
+        let mut points = vec![waypoints[0]];
+        for i in 0..n - 1 {
+            let (p0, p1, p2, p3) = (at(i as isize - 1), at(i as isize), at(i as isize + 1), at(i as isize + 2));
+            let segment: Vec<Point<T>> = (1..=samples)
+                .map(|k| {
+                    let t = T::cast(k) / T::cast(samples);
+                    let (t2, t3) = (t * t, t * t * t);
+                    let half = T::cast(0.5);
+                    (p1 * two
+                        + (p2 - p0) * t
+                        + (p0 * two - p1 * T::cast(5.0) + p2 * T::cast(4.0) - p3) * t2
+                        + (p1 * T::cast(3.0) - p0 - p2 * T::cast(3.0) + p3) * t3)
+                        * half
+                })
+                .collect();
+            let mut previous = p1;
+            let free = segment.iter().all(|&p| {
+                let ok = self.is_edge_collision_free(&previous, &p);
+                previous = p;
+                ok
+            });
+            if free {
+                points.extend(segment);
+            } else {
+                points.extend((1..=samples).map(|k| Point::lerp(p1, p2, T::cast(k) / T::cast(samples))));
+            }
+        }
+        Path::new(points, path.goal_node())
+    }
+
+    // Hedefe giden en iyi bilinen yol: çapraz kenarlar açıksa çizge üzerindeki en kısa yol, değilse
+    // ağaçtaki yol. Hedefe henüz ulaşılmadıysa `None` döner.
+    pub fn best_path(&self) -> Option<Path<T>> {
+        let goal = self.goal_node?;
+        if self.cross_edges {
+            self.shortest_path(goal)
+        } else {
+            self.trace_path_from(goal)
+        }
+    }
+
+    // Hedefe ulaşan düğümden başlayarak yolu çıkarır; hedefe henüz ulaşılmadıysa `None` döner
+    pub fn trace_path(&self) -> Option<Path<T>> {
+        let path = self.goal_node.and_then(|index| self.trace_path_from(index))?;
+        // Hata ayıklama derlemelerinde ağacın yalnızca geçerli yollar ürettiği denetlenir; tembel
+        // modda kenarlar henüz doğrulanmamış olabilir
+        if cfg!(debug_assertions) && !self.lazy_collision {
+            if let Err(e) = self.validate_path(&path) {
+                panic!("traced an invalid path: {}", e);
+            }
+        }
+        Some(path)
+    }
+
+    // `trace_path` yolunun her ara noktasındaki poz (bkz. `Path::poses`); hedefe henüz
+    // ulaşılmadıysa boştur
+    pub fn trace_path_poses(&self) -> Vec<Pose<T>> {
+        self.trace_path().map_or_else(Vec::new, |path| path.poses())
+    }
+
+    // Her kök için hedefe ulaşılıp ulaşılmadığı ve kökün kendi ağacındaki en ucuz yol. Yollar
+    // yalnızca ebeveyn kenarlarını izlediğinden çapraz kenarlar açık olsa da başka köke geçmez.
+    pub fn root_reports(&self) -> Vec<RootReport<T>> {
+        let mut best: Vec<Option<usize>> = vec![None; self.starts.len()];
+        for (i, node) in self.nodes.iter().enumerate() {
+            if !(self.in_goal_region(&node.point) && self.can_wait_at(&node.point, node.time)) {
+                continue;
+            }
+            let slot = &mut best[node.root];
+            if slot.is_none_or(|b| node.cost < self.nodes[b].cost) {
+                *slot = Some(i);
+            }
+        }
+        self.starts
+            .iter()
+            .enumerate()
+            .map(|(root, &start)| RootReport {
+                root,
+                start,
+                reached_at: self.root_goal_iterations.get(root).copied().flatten(),
+                goal_node: best[root],
+                cost: best[root].map(|i| self.nodes[i].cost),
+                path: best[root].and_then(|i| self.trace_path_from(i)),
+            })
+            .collect()
+    }
+
+    // Verilen düğümden köke doğru geriye giderek yolu çıkarır; indeks ağaçta yoksa `None` döner
+    pub fn trace_path_from(&self, node_index: usize) -> Option<Path<T>> {
+        if node_index >= self.nodes.len() {
+            return None;
+        }
+        let mut path = Vec::new();
+        let mut current_node_index = node_index;
+
+        while let Some(parent_index) = self.nodes[current_node_index].parent {
+            path.push(self.nodes[current_node_index].point);
+            current_node_index = parent_index;
+        }
+        path.push(self.nodes[current_node_index].point);
+        path.reverse();
+        Some(Path::new(path, Some(node_index)))
+    }
+}
+
+// Yapılandırma dosyaları ve kaydedilen haritalar f32 koordinatlıdır
+impl RRT {
+    // Yapılandırmadan ağaç oluşturur; başlangıç veya hedef verilmemişse alan içinde rastgele seçilir.
+    // Düğüm vektörü `node_capacity` verilmişse o kadar, verilmemişse `max_iters + 1` düğüm için ayrılır.
+    pub fn from_config(config: &RRTConfig) -> Self {
+        let mut rng = seeded_rng(config.seed);
+        let bounds = config.bounds;
+        let random_in_bounds = |rng: &mut ChaCha12Rng| Point {
+            x: rng.gen_range(bounds.min_x..bounds.max_x),
+            y: rng.gen_range(bounds.min_y..bounds.max_y),
+        };
+        let start = config.start.unwrap_or_else(|| random_in_bounds(&mut rng));
+        let goal = config.goal.unwrap_or_else(|| random_in_bounds(&mut rng));
+        let starts: Vec<Point> = std::iter::once(start).chain(config.extra_starts.iter().copied()).collect();
+        let mut nodes = Vec::with_capacity(config.node_capacity.unwrap_or(config.max_iters + 1).max(starts.len()));
+        nodes.extend(starts.iter().enumerate().map(|(i, &s)| Node::root(s, i)));
+
+        let mut rrt = RRT {
+            nodes,
+            goals: std::iter::once(goal).chain(config.extra_goals.iter().copied()).collect(),
+            root_goal_iterations: vec![None; starts.len()],
+            starts,
+            forest_growth: config.forest_growth,
+            next_root: 0,
+            step_size: config.step_size,
+            step_policy: config.step_policy,
+            potential_field: config.potential_field,
+            max_edge_length: config.max_edge_length,
+            goal_threshold: config.goal_threshold,
+            goal_bias: config.goal_bias,
+            bias_schedule: config.goal_bias_schedule.with_default_iterations(config.max_iters),
+            iteration: 0,
+            bounds,
+            sampler: config.sampler,
+            frontier_bias: config.frontier_bias,
+            waypoint_cache: Vec::new(),
+            waypoint_bias: config.waypoint_bias,
+            waypoint_sigma: config.waypoint_sigma,
+            obstacles: scenario_obstacles(config, &start, &goal),
+            dynamic_obstacles: config.dynamic_obstacles.clone(),
+            moving_robots: Vec::new(),
+            speed: config.speed,
+            collision_resolution: config.collision_resolution,
+            adaptive_collision: config.adaptive_collision,
+            robot_radius: config.robot_radius,
+            robot_shape: config.robot_shape.clone(),
+            clearance_weight: config.clearance_weight,
+            // `from_toml_str` görüntüyü zaten ızgaraya çevirir; kodla kurulan yapılandırmada
+            // okunamayan görüntü arazisiz planlanır
+            terrain: config.terrain.as_ref().and_then(|spec| spec.cost_map(bounds).ok()),
+            terrain_bias: config.terrain_bias,
+            clearance_distance: config.clearance_distance,
+            clearance_cost: config.clearance_cost,
+            goal_node: None,
+            any_angle: config.any_angle,
+            any_angle_depth: config.any_angle_depth,
+            cross_edges: config.cross_edges,
+            cross_edge_radius: config.cross_edge_radius,
+            graph_edges: Vec::new(),
+            graph_edge_costs: Vec::new(),
+            lazy_collision: config.lazy_collision,
+            lazy_stats: LazyStats::default(),
+            dynamic_domain: config.dynamic_domain,
+            dynamic_domain_radius: config.dynamic_domain_radius,
+            dynamic_domain_shrink: config.dynamic_domain_shrink,
+            domain_resamples: 0,
+            samples_total: 0,
+            samples_rejected: 0,
+            rejection_sampling: config.rejection_sampling,
+            branch_and_bound: config.branch_and_bound,
+            branch_and_bound_interval: config.branch_and_bound_interval,
+            bnb_nodes_pruned: 0,
+            bnb_extensions_rejected: 0,
+            goal_connect_interval: config.goal_connect_interval,
+            goal_connect_attempts: 0,
+            max_path_cost: config.max_path_cost,
+            cost_limit_rejections: 0,
+            max_nodes: config.max_nodes,
+            node_cap_policy: config.node_cap_policy,
+            saturated: false,
+            nodes_pruned: 0,
+            last_step: None,
+            max_depth: 0,
+            max_cost: 0.0,
+            longest_edge: 0.0,
+            best_goal_distance: f32::INFINITY,
+            best_goal_node: 0,
+            best_goal_iteration: 0,
+            time_budget: config.time_budget_ms.map(Duration::from_millis),
+            batch_size: config.batch_size,
+            heatmap: None,
+            coverage: None,
+            sample_source: None,
+            neighbor_index: NeighborIndex::Linear,
+            kd_tree_rebalance: config.kd_tree_rebalance,
+            goal_region: None,
+            sampling_region: None,
+            observer: None,
+            best_cost: None,
+            bnb_best_cost: f32::INFINITY,
+            rng,
+        };
+        rrt.set_neighbor_index(config.neighbor_index, config.grid_cell_size);
+        rrt.set_coverage_resolution(config.coverage_resolution);
+        rrt.refresh_best_goal_distance();
+        rrt
+    }
+
+    // Ağacın şu anki parametreleriyle yapılandırma: `from_config` ile aynı parametrelerle (boş bir)
+    // ağaç kurar. Üretilen engeller ve arazi görüntüsü somut engeller ve ızgara olarak yazılır;
+    // pencere ayarları ve yalnızca ekransız planlamayı sınırlayan `max_iters` varsayılan kalır.
+    pub fn to_config(&self) -> RRTConfig {
+        let grid_cell_size = match &self.neighbor_index {
+            NeighborIndex::Grid(grid) => grid.cell_size(),
+            _ => RRTConfig::default().grid_cell_size,
+        };
+        RRTConfig {
+            start: Some(self.starts[0]),
+            goal: Some(self.goal()),
+            extra_goals: self.goals[1..].to_vec(),
+            extra_starts: self.starts[1..].to_vec(),
+            forest_growth: self.forest_growth,
+            step_size: self.step_size,
+            step_policy: self.step_policy,
+            max_edge_length: self.max_edge_length,
+            potential_field: self.potential_field,
+            goal_threshold: self.goal_threshold,
+            goal_bias: self.goal_bias,
+            goal_bias_schedule: self.bias_schedule,
+            bounds: self.bounds,
+            sampler: self.sampler,
+            frontier_bias: self.frontier_bias,
+            waypoint_bias: self.waypoint_bias,
+            waypoint_sigma: self.waypoint_sigma,
+            obstacles: self.obstacles.clone(),
+            dynamic_obstacles: self.dynamic_obstacles.clone(),
+            speed: self.speed,
+            terrain: self.terrain.as_ref().map(|map| TerrainSpec::Grid { costs: map.rows() }),
+            terrain_bias: self.terrain_bias,
+            collision_resolution: self.collision_resolution,
+            adaptive_collision: self.adaptive_collision,
+            robot_radius: self.robot_radius,
+            robot_shape: self.robot_shape.clone(),
+            clearance_weight: self.clearance_weight,
+            clearance_distance: self.clearance_distance,
+            clearance_cost: self.clearance_cost,
+            time_budget_ms: self.time_budget.map(|budget| budget.as_millis() as u64),
+            batch_size: self.batch_size,
+            branch_and_bound: self.branch_and_bound,
+            branch_and_bound_interval: self.branch_and_bound_interval,
+            goal_connect_interval: self.goal_connect_interval,
+            max_path_cost: self.max_path_cost,
+            max_nodes: self.max_nodes,
+            node_cap_policy: self.node_cap_policy,
+            node_capacity: Some(self.nodes.capacity()),
+            neighbor_index: self.neighbor_index_kind(),
+            grid_cell_size,
+            kd_tree_rebalance: self.kd_tree_rebalance,
+            any_angle: self.any_angle,
+            any_angle_depth: self.any_angle_depth,
+            cross_edges: self.cross_edges,
+            cross_edge_radius: self.cross_edge_radius,
+            lazy_collision: self.lazy_collision,
+            dynamic_domain: self.dynamic_domain,
+            dynamic_domain_radius: self.dynamic_domain_radius,
+            dynamic_domain_shrink: self.dynamic_domain_shrink,
+            rejection_sampling: self.rejection_sampling,
+            coverage_resolution: self.coverage.as_ref().map_or(0, |coverage| coverage.frame.cols),
+            ..RRTConfig::default()
+        }
+    }
+
+    // Ağacın tamamını (parametreler, düğümler, sayaçlar, en iyi yol ve üretecin konumu) TOML
+    // oturum dosyasına yazar. Kodla atanan hedef ve örnekleme bölgeleri, gözlemci, örnek kaynağı,
+    // hareketli robotlar, ısı haritası ve son adımın ara verileri kaydedilmez.
+    pub fn save_session<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), ConfigError> {
+        let (word_pos, stream) = (self.rng.get_word_pos(), self.rng.get_stream());
+        let state = SessionState {
+            iteration: self.iteration,
+            nodes: self.nodes.clone(),
+            goal_node: self.goal_node,
+            best_cost: self.best_cost,
+            bnb_best_cost: self.bnb_best_cost,
+            graph_edges: self.graph_edges.clone(),
+            graph_edge_costs: self.graph_edge_costs.clone(),
+            waypoint_cache: self.waypoint_cache.clone(),
+            lazy_stats: self.lazy_stats,
+            samples_total: self.samples_total,
+            samples_rejected: self.samples_rejected,
+            domain_resamples: self.domain_resamples,
+            bnb_nodes_pruned: self.bnb_nodes_pruned,
+            bnb_extensions_rejected: self.bnb_extensions_rejected,
+            goal_connect_attempts: self.goal_connect_attempts,
+            cost_limit_rejections: self.cost_limit_rejections,
+            saturated: self.saturated,
+            nodes_pruned: self.nodes_pruned,
+            max_depth: self.max_depth,
+            max_cost: self.max_cost,
+            best_goal_distance: self.best_goal_distance,
+            best_goal_node: self.best_goal_node,
+            best_goal_iteration: self.best_goal_iteration,
+            roots_reached: self.root_goal_iterations.iter().enumerate().filter_map(|(root, at)| at.map(|at| (root, at))).collect(),
+            next_root: self.next_root,
+            rng_seed: self.rng.get_seed(),
+            rng_stream: stream.to_string(),
+            rng_word_pos: word_pos.to_string(),
+        };
+        let session = Session { config: self.to_config(), state };
+        std::fs::write(path, toml::to_string(&session)?)?;
+        Ok(())
+    }
+
+    // `save_session` ile yazılmış oturumu yükler; planlama kaydedildiği yerden aynı örnek dizisiyle sürer
+    pub fn load_session<P: AsRef<std::path::Path>>(path: P) -> Result<RRT, ConfigError> {
+        let session: Session = toml::from_str(&std::fs::read_to_string(path)?)?;
+        session.config.validate()?;
+        let state = session.state;
+        let mut rrt = RRT::from_config(&session.config);
+        let invalid = |reason: &str| ConfigError::Invalid(format!("session {}", reason));
+        let roots = rrt.starts.len();
+        let n = state.nodes.len();
+        if n < roots || state.nodes[..roots].iter().zip(&rrt.starts).any(|(node, start)| node.parent.is_some() || node.point != *start) {
+            return Err(invalid("nodes do not begin with the roots"));
+        }
+        if state.nodes.iter().any(|node| node.parent.is_some_and(|p| p >= n) || node.root >= roots)
+            || state.goal_node.is_some_and(|g| g >= n)
+            || state.best_goal_node >= n
+            || state.graph_edges.iter().any(|&(a, b)| a >= n || b >= n)
+            || state.roots_reached.iter().any(|&(root, _)| root >= roots)
+        {
+            return Err(invalid("refers to nodes or roots that do not exist"));
+        }
+        let (Ok(stream), Ok(word_pos)) = (state.rng_stream.parse::<u64>(), state.rng_word_pos.parse::<u128>()) else {
+            return Err(invalid("has an unreadable random number generator position"));
+        };
+        rrt.iteration = state.iteration;
+        rrt.nodes.clear();
+        rrt.nodes.extend(state.nodes);
+        rrt.goal_node = state.goal_node;
+        rrt.best_cost = state.best_cost;
+        rrt.bnb_best_cost = state.bnb_best_cost;
+        rrt.graph_edges = state.graph_edges;
+        rrt.graph_edge_costs = state.graph_edge_costs;
+        rrt.waypoint_cache = state.waypoint_cache;
+        rrt.lazy_stats = state.lazy_stats;
+        rrt.samples_total = state.samples_total;
+        rrt.samples_rejected = state.samples_rejected;
+        rrt.domain_resamples = state.domain_resamples;
+        rrt.bnb_nodes_pruned = state.bnb_nodes_pruned;
+        rrt.bnb_extensions_rejected = state.bnb_extensions_rejected;
+        rrt.goal_connect_attempts = state.goal_connect_attempts;
+        rrt.cost_limit_rejections = state.cost_limit_rejections;
+        rrt.saturated = state.saturated;
+        rrt.nodes_pruned = state.nodes_pruned;
+        rrt.max_depth = state.max_depth;
+        rrt.max_cost = state.max_cost;
+        rrt.refresh_longest_edge();
+        rrt.best_goal_distance = state.best_goal_distance;
+        rrt.best_goal_node = state.best_goal_node;
+        rrt.best_goal_iteration = state.best_goal_iteration;
+        for (root, at) in state.roots_reached {
+            rrt.root_goal_iterations[root] = Some(at);
+        }
+        rrt.next_root = state.next_root % roots;
+        rrt.rng = ChaCha12Rng::from_seed(state.rng_seed);
+        rrt.rng.set_stream(stream);
+        rrt.rng.set_word_pos(word_pos);
+        rrt.rebuild_neighbor_index();
+        rrt.rebuild_coverage();
+        Ok(rrt)
+    }
+
+    // Ağacı hızlı yeniden yükleme için düz ikili biçimde yazar: `BINARY_MAGIC`, düğüm sayısı (u64),
+    // yapılandırmanın TOML metninin bayt uzunluğu (u64) ve metnin kendisi, ardından düğüm başına
+    // `(x: f32, y: f32, parent: i32)` kaydı; kökün ebeveyni -1'dir. Sayılar küçük uçludur. Yalnızca
+    // düğümler ve ağaç kenarları yazılır; sayaçlar, çapraz kenarlar ve üretecin konumu için
+    // `save_session` kullanılır. Ebeveyn indeksleri i32'ye sığmayacak kadar büyük ağaçlar hiçbir
+    // şey yazılmadan reddedilir.
+    pub fn write_binary<W: Write>(&self, mut w: W) -> Result<(), ConfigError> {
+        if self.nodes.len() > i32::MAX as usize {
+            return Err(ConfigError::Invalid(format!("binary tree cannot hold {} nodes; parent indices are i32", self.nodes.len())));
+        }
+        let config = toml::to_string(&self.to_config())?;
+        w.write_all(BINARY_MAGIC)?;
+        w.write_all(&(self.nodes.len() as u64).to_le_bytes())?;
+        w.write_all(&(config.len() as u64).to_le_bytes())?;
+        w.write_all(config.as_bytes())?;
+        let mut records = Vec::with_capacity(self.nodes.len() * BINARY_RECORD_SIZE);
+        for node in &self.nodes {
+            records.extend_from_slice(&node.point.x.to_le_bytes());
+            records.extend_from_slice(&node.point.y.to_le_bytes());
+            records.extend_from_slice(&node.parent.map_or(-1, |p| p as i32).to_le_bytes());
+        }
+        w.write_all(&records)?;
+        w.flush()?;
+        Ok(())
+    }
+
+    // `write_binary` ile yazılmış ağacı yükler. Düğümler kayıt sırasıyla `add_node` ile eklendiğinden
+    // maliyet, derinlik, kök ve hedef düğümü yeniden hesaplanır; iterasyon sayacı sıfırdan başlar.
+    pub fn read_binary<R: Read>(mut r: R) -> Result<RRT, ConfigError> {
+        let invalid = |reason: &str| ConfigError::Invalid(format!("binary tree {}", reason));
+        let mut magic = [0; 4];
+        r.read_exact(&mut magic)?;
+        if &magic != BINARY_MAGIC {
+            return Err(invalid("has an unknown header"));
+        }
+        // Bozuk bir uzunluk koca bir tampon ayırtmasın diye baytlar okundukça biriktirilir
+        let mut read_block = |len: u64| -> Result<Vec<u8>, ConfigError> {
+            let mut block = Vec::new();
+            r.by_ref().take(len).read_to_end(&mut block)?;
+            if (block.len() as u64) < len {
+                return Err(invalid("is truncated"));
+            }
+            Ok(block)
+        };
+        let n = u64::from_le_bytes(read_block(8)?.try_into().expect("eight bytes"));
+        let config_len = u64::from_le_bytes(read_block(8)?.try_into().expect("eight bytes"));
+        let config = read_block(config_len)?;
+        let config: RRTConfig = toml::from_str(std::str::from_utf8(&config).map_err(|_| invalid("has a config that is not UTF-8"))?)?;
+        config.validate()?;
+        let records = read_block(n.saturating_mul(BINARY_RECORD_SIZE as u64))?;
+        let mut rrt = RRT::from_config(&config);
+        let roots = rrt.starts.len();
+        if records.len() < roots * BINARY_RECORD_SIZE {
+            return Err(invalid("does not begin with the roots"));
+        }
+        rrt.nodes.reserve(records.len() / BINARY_RECORD_SIZE - roots);
+        for (i, record) in records.chunks_exact(BINARY_RECORD_SIZE).enumerate() {
+            let field = |k: usize| [record[4 * k], record[4 * k + 1], record[4 * k + 2], record[4 * k + 3]];
+            let point = Point { x: f32::from_le_bytes(field(0)), y: f32::from_le_bytes(field(1)) };
+            let parent = i32::from_le_bytes(field(2));
+            if i < roots {
+                if parent != -1 || point != rrt.starts[i] {
+                    return Err(invalid("does not begin with the roots"));
+                }
+            } else if parent < 0 || parent as usize >= i {
+                return Err(invalid("has a node whose parent does not precede it"));
+            } else {
+                rrt.add_node(point, parent as usize);
+            }
+        }
+        Ok(rrt)
+    }
+
+    // Alanı, uç noktaları ve engelleri kaydedilebilir bir harita olarak döndürür
+    pub fn layout(&self) -> MapLayout {
+        MapLayout {
+            start: self.nodes[0].point,
+            goal: self.goal(),
+            extra_goals: self.goals[1..].to_vec(),
+            extra_starts: self.starts[1..].to_vec(),
+            bounds: self.bounds,
+            obstacles: self.obstacles.clone(),
+        }
+    }
+
+    // Kaydedilmiş haritayı yükler ve ağacı yeni uç noktalarla sıfırlar
+    pub fn load_layout(&mut self, layout: &MapLayout) {
+        self.bounds = layout.bounds;
+        self.obstacles = layout.obstacles.clone();
+        self.goals.truncate(1);
+        self.goals.extend_from_slice(&layout.extra_goals);
+        self.starts.truncate(1);
+        self.starts.extend_from_slice(&layout.extra_starts);
+        self.reset(layout.start, layout.goal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::goal::{CircleGoal, RectGoal};
+    use crate::region::CircleRegion;
+    use proptest::prelude::*;
+    use rand::rngs::StdRng;
+    use rand::Rng;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn distance_to_itself_is_zero() {
+        let p: Point = Point { x: 12.5, y: -3.0 };
+        assert_eq!(p.distance(&p), 0.0);
+    }
+
+    #[test]
+    fn distance_of_a_3_4_5_triangle() {
+        let a: Point = Point { x: 1.0, y: 2.0 };
+        let b = Point { x: 4.0, y: 6.0 };
+        assert_close(a.distance(&b), 5.0);
+        assert_close(b.distance(&a), 5.0);
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let a: Point = Point { x: 1.0, y: 2.0 };
+        let b = Point { x: 4.0, y: -6.0 };
+        assert_eq!(a + b, Point { x: 5.0, y: -4.0 });
+        assert_eq!(b - a, Point { x: 3.0, y: -8.0 });
+        assert_eq!(a * 3.0, Point { x: 3.0, y: 6.0 });
+        assert_eq!(-a, Point { x: -1.0, y: -2.0 });
+        assert_eq!(Point::<f32>::default(), Point { x: 0.0, y: 0.0 });
+    }
+
+    #[test]
+    fn point_dot_and_length() {
+        let a: Point = Point { x: 3.0, y: 4.0 };
+        assert_eq!(a.dot(&Point { x: 2.0, y: -1.0 }), 2.0);
+        assert_eq!(a.dot(&Point { x: -4.0, y: 3.0 }), 0.0);
+        assert_eq!(a.length_squared(), 25.0);
+        assert_close(a.length(), 5.0);
+        assert_close((a - Point { x: 1.0, y: 1.0 }).length(), a.distance(&Point { x: 1.0, y: 1.0 }));
+    }
+
+    #[test]
+    fn normalize_gives_a_unit_vector_and_rejects_zero() {
+        let n = Point::<f32> { x: 3.0, y: 4.0 }.normalize().unwrap();
+        assert_close(n.length(), 1.0);
+        assert_close(n.x, 0.6);
+        assert_close(n.y, 0.8);
+        assert_eq!(Point::<f32>::default().normalize(), None);
+    }
+
+    #[test]
+    fn lerp_endpoints_and_midpoint() {
+        let a: Point = Point { x: -2.0, y: 10.0 };
+        let b = Point { x: 6.0, y: 2.0 };
+        assert_eq!(Point::lerp(a, b, 0.0), a);
+        assert_eq!(Point::lerp(a, b, 1.0), b);
+        assert_eq!(Point::lerp(a, b, 0.5), Point { x: 2.0, y: 6.0 });
+    }
+
+    #[test]
+    fn point_converts_to_and_from_vec2() {
+        let p = Point { x: 1.5, y: -7.0 };
+        let v = Vec2::from(p);
+        assert_eq!((v.x, v.y), (1.5, -7.0));
+        assert_eq!(Point::from(v), p);
+    }
+
+    #[test]
+    fn steer_moves_step_size_along_the_bearing() {
+        let rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 100.0 }, 10.0, 1.0);
+        let from = Point { x: 0.0, y: 0.0 };
+        let to = Point { x: 30.0, y: 40.0 };
+        let p = rrt.steer(&from, &to);
+        assert_close(from.distance(&p), 10.0);
+        assert_close(p.x, 6.0);
+        assert_close(p.y, 8.0);
+    }
+
+    #[test]
+    fn steer_stops_at_a_target_closer_than_step_size() {
+        let rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 100.0 }, 10.0, 1.0);
+        let from = Point { x: 0.0, y: 0.0 };
+        let to = Point { x: 3.0, y: 4.0 };
+        let p = rrt.steer(&from, &to);
+        assert_close(p.x, 3.0);
+        assert_close(p.y, 4.0);
+    }
+
+    #[test]
+    fn steer_never_passes_the_target() {
+        let rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 100.0 }, 10.0, 1.0);
+        let from = Point { x: 5.0, y: -2.0 };
+        for i in 0..64 {
+            let angle = i as f32 * std::f32::consts::TAU / 64.0;
+            let reach = 0.25 * (i % 8) as f32 * rrt.step_size;
+            let to = Point { x: from.x + reach * angle.cos(), y: from.y + reach * angle.sin() };
+            let p = rrt.steer(&from, &to);
+            assert!(from.distance(&p) <= from.distance(&to) + 1e-4);
+            assert!(from.distance(&p) <= rrt.step_size + 1e-4);
+        }
+    }
+
+    #[test]
+    fn find_nearest_returns_the_closest_node() {
+        let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 100.0 }, 10.0, 1.0);
+        rrt.add_node(Point { x: 50.0, y: 0.0 }, 0);
+        rrt.add_node(Point { x: 50.0, y: 50.0 }, 1);
+        rrt.add_node(Point { x: 0.0, y: 50.0 }, 0);
+        assert_eq!(rrt.find_nearest(&Point { x: 1.0, y: 1.0 }), 0);
+        assert_eq!(rrt.find_nearest(&Point { x: 48.0, y: 3.0 }), 1);
+        assert_eq!(rrt.find_nearest(&Point { x: 45.0, y: 47.0 }), 2);
+        assert_eq!(rrt.find_nearest(&Point { x: -5.0, y: 60.0 }), 3);
+    }
+
+    #[test]
+    fn setters_clamp_to_valid_ranges() {
+        let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 100.0 }, 10.0, 5.0);
+        assert_eq!(rrt.set_step_size(0.0), STEP_SIZE_RANGE.0);
+        assert_eq!(rrt.set_step_size(1e9), STEP_SIZE_RANGE.1);
+        assert_eq!(rrt.set_step_size(12.0), 12.0);
+        assert_eq!(rrt.set_goal_bias(-0.5), 0.0);
+        assert_eq!(rrt.set_goal_bias(1.5), 1.0);
+        assert_eq!(rrt.set_goal_bias(0.25), 0.25);
+        // Düzgün örnekleyici yanlılığı kullanmadığından hedef yanlı örneklemeye geçilir
+        assert_eq!(rrt.sampler(), SamplerKind::GoalBiased);
+        assert_eq!(rrt.set_goal_threshold(-3.0), GOAL_THRESHOLD_RANGE.0);
+        assert_eq!(rrt.set_goal_threshold(1e9), GOAL_THRESHOLD_RANGE.1);
+        // Sayı olmayan değerler önceki değeri bozmaz
+        assert_eq!(rrt.set_step_size(f32::NAN), 12.0);
+        assert_eq!(rrt.set_goal_bias(f32::NAN), 0.25);
+        assert_eq!(rrt.set_goal_threshold(f32::NAN), GOAL_THRESHOLD_RANGE.1);
+        assert_eq!(rrt.step_size, 12.0);
+    }
+
+    #[test]
+    fn goal_bias_schedules_reach_their_end_value_on_the_last_iteration() {
+        let budget = 200;
+        for schedule in [
+            BiasSchedule::Linear { start: 0.3, end: 0.05, iterations: None },
+            BiasSchedule::Exponential { start: 0.3, end: 0.05, iterations: None },
+        ] {
+            let config = RRTConfig {
+                start: Some(Point { x: 20.0, y: 20.0 }),
+                goal: Some(Point { x: 1e6, y: 1e6 }), // Hedefe ulaşılıp planlama erken bitmesin
+                sampler: SamplerKind::GoalBiased,
+                goal_bias_schedule: schedule,
+                max_iters: budget,
+                seed: Some(1),
+                ..RRTConfig::default()
+            };
+            let mut rrt = RRT::from_config(&config);
+            let mut biases = Vec::new();
+            for _ in 0..budget {
+                biases.push(rrt.effective_goal_bias());
+                rrt.extend();
+            }
+            assert!((biases[0] - 0.3).abs() < 1e-6, "{:?}: {}", schedule, biases[0]);
+            assert!((biases[budget - 1] - 0.05).abs() < 1e-6, "{:?}: {}", schedule, biases[budget - 1]);
+            assert!(biases.windows(2).all(|w| w[1] <= w[0]));
+            // Bütçe bittikten sonra son değerde kalır
+            assert!((rrt.effective_goal_bias() - 0.05).abs() < 1e-6);
+            // Üç iterasyonluk geçişte ortadaki değer doğrusalda aritmetik, üstelde geometrik ortalamadır
+            let middle = schedule.with_default_iterations(3).bias_at(1).unwrap();
+            match schedule {
+                BiasSchedule::Linear { .. } => assert!((middle - 0.175).abs() < 1e-6),
+                _ => assert!((middle - (0.3f64 * 0.05).sqrt()).abs() < 1e-6),
+            }
+        }
+        // Sabit yanlılık `goal_bias` değerini kullanır
+        let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 10.0, y: 0.0 }, 1.0, 1.0);
+        rrt.goal_bias = 0.2;
+        assert_eq!(rrt.effective_goal_bias(), 0.2);
+    }
+
+    #[test]
+    fn path_clearance_matches_hand_computed_distances() {
+        let p = |x, y| Point { x, y };
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+        let circle = Obstacle::Circle { center: p(0.0, 0.0), radius: 1.0 };
+        let rect = Obstacle::Rect { min: p(0.0, 0.0), max: p(2.0, 2.0) };
+
+        let path = [p(-2.0, 3.0), p(4.0, 3.0), p(4.0, -1.0)];
+        let obstacles = [circle, rect];
+        let segments = segment_clearances(&path, &obstacles);
+        assert_eq!(segments.len(), 2);
+        assert!(close(segments[0], 1.0) && close(segments[1], 2.0), "{:?}", segments);
+        assert!(close(path_clearance(&path, &obstacles), 1.0));
+        assert_eq!(path_clearance(&path, &[]), f32::INFINITY);
+    }
+
+    #[test]
+    fn rejection_sampling_returns_free_samples_on_a_mostly_blocked_map() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 20.0 }, 10.0, 5.0);
+        rrt.reseed(6);
+        // Alanın yalnızca alttaki %20'lik şeridi serbesttir
+        rrt.add_obstacle(Obstacle::Rect { min: Point { x: -10.0, y: 80.0 }, max: Point { x: 410.0, y: 410.0 } });
+        let sample_is_free = |rrt: &mut RRT| {
+            let p = rrt.random_point();
+            rrt.is_collision_free(&p)
+        };
+        let blocked = (0..500).filter(|_| !sample_is_free(&mut rrt)).count();
+        assert!(blocked > 300, "{}", blocked);
+        rrt.rejection_sampling = true;
+        assert!((0..500).all(|_| sample_is_free(&mut rrt)));
+
+        // Serbest alan hiç yoksa deneme sınırında durulur ve son örnek döner
+        rrt.add_obstacle(Obstacle::Rect { min: Point { x: -10.0, y: -10.0 }, max: Point { x: 410.0, y: 90.0 } });
+        let p = rrt.random_point();
+        assert!(rrt.bounds.contains(&p) && !rrt.is_collision_free(&p));
+    }
+
+    #[test]
+    fn waypoint_samples_stay_inside_the_bounds_and_sampling_region() {
+        let mut rrt: RRT = RRT::new(Point { x: 200.0, y: 200.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+        rrt.reseed(5);
+        // Köşedeki ara noktanın geniş çevresinin çoğu alanın dışında kalır
+        rrt.seed_from_path(&[Point { x: 2.0, y: 398.0 }, Point { x: 200.0, y: 200.0 }]);
+        rrt.waypoint_bias = 1.0;
+        rrt.waypoint_sigma = 60.0;
+        let samples: Vec<Point> = (0..2000).map(|_| rrt.sample()).collect();
+        assert!(samples.iter().all(|p| rrt.bounds.contains(p)));
+        // Örnekler ara noktaların çevresinde yoğunlaşır
+        let near = samples.iter().filter(|p| p.distance(&Point { x: 200.0, y: 200.0 }) < 60.0).count();
+        assert!(near > 500, "{}", near);
+
+        let region = CircleRegion { center: Point { x: 50.0, y: 350.0 }, radius: 30.0 };
+        rrt.set_sampling_region(Some(Box::new(region)));
+        assert!((0..2000).all(|_| region.contains(&rrt.sample())));
+    }
+
+    #[test]
+    fn seeding_a_replanning_cycle_with_the_old_path_needs_fewer_iterations() {
+        let config = RRTConfig::from_toml_file("scenarios/maze.toml").expect("scenario loads");
+        let (mut cold, mut seeded) = (0, 0);
+        for seed in 0..3 {
+            let mut rrt = RRT::from_config(&RRTConfig { seed: Some(seed), ..config.clone() });
+            let path = rrt.plan(config.max_iters).path().expect("path found").clone();
+            // Robot yolun bir kısmını ilerlemişken yeniden planlanır
+            let (start, goal) = (path[path.len() / 4], rrt.goal());
+            for (waypoint_bias, total) in [(0.0, &mut cold), (0.5, &mut seeded)] {
+                rrt.reseed(seed + 100);
+                rrt.seed_from_path(&path);
+                rrt.waypoint_bias = waypoint_bias;
+                rrt.waypoint_sigma = rrt.step_size;
+                rrt.reset(start, goal);
+                let result = rrt.plan(config.max_iters);
+                assert!(result.path().is_some());
+                *total += result.stats().iterations;
+            }
+        }
+        assert!(seeded * 4 < cold * 3, "seeded {} vs cold {}", seeded, cold);
+    }
+
+    #[test]
+    fn clearance_weight_keeps_paths_away_from_walls() {
+        let mut config = RRTConfig::from_toml_file("scenarios/corridor.toml").expect("scenario loads");
+        let mut rrt = RRT::from_config(&config);
+        // Ağırlık sıfırken maliyet yalnızca uzunluktur; duvar boyunca giden kenarın cezası uzunluğudur
+        let (a, b) = (Point { x: 120.0, y: 50.0 }, Point { x: 120.0, y: 80.0 });
+        assert_eq!(rrt.edge_cost(&a, &b), 30.0);
+        assert!((rrt.clearance_penalty(&a, &b) - 30.0).abs() < 1e-4);
+        rrt.clearance_weight = 2.0;
+        assert!((rrt.edge_cost(&a, &b) - 90.0).abs() < 1e-3);
+
+        let mean_clearance = |config: &RRTConfig| {
+            let mut rrt = RRT::from_config(config);
+            let (start, goal) = (rrt.nodes[0].point, rrt.goal());
+            let mut total = 0.0;
+            for seed in 0..5 {
+                rrt.reseed(seed);
+                rrt.reset(start, goal);
+                let path = rrt.plan(config.max_iters).path().expect("path found").clone();
+                total += path_clearance(&path, &rrt.obstacles);
+            }
+            total / 5.0
+        };
+        let hugging = mean_clearance(&config);
+        config.clearance_weight = 20.0;
+        let centered = mean_clearance(&config);
+        assert!(centered > 10.0 && centered > 5.0 * hugging, "{} vs {}", centered, hugging);
+    }
+
+    #[test]
+    fn shortest_path_matches_trace_path_on_a_tree() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+        rrt.reseed(2);
+        rrt.plan(20_000).path().expect("path found");
+        let goal = rrt.goal_node.unwrap();
+        assert_eq!(rrt.shortest_path(goal), rrt.trace_path());
+        for index in [0, 1, rrt.nodes.len() / 2, rrt.nodes.len() - 1] {
+            assert_eq!(rrt.shortest_path(index), rrt.trace_path_from(index));
+        }
+        assert_eq!(rrt.shortest_path(rrt.nodes.len()), None);
+    }
+
+    #[test]
+    fn time_budget_stops_planning_when_the_clock_runs_out() {
+        // Her okumada 1/8 saniye ilerleyen sahte saat
+        struct SteppingClock(std::cell::Cell<f64>);
+        impl Clock for SteppingClock {
+            fn now(&self) -> f64 {
+                let now = self.0.get();
+                self.0.set(now + 0.125);
+                now
+            }
+        }
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 10.0);
+        rrt.time_budget = Some(Duration::from_secs(1));
+        let result = rrt.plan_with_clock(20_000, &SteppingClock(std::cell::Cell::new(0.0)));
+        assert!(result.path().is_none());
+        assert_eq!(result.stats().iterations, 7);
+        assert!(result.stats().budget_exhausted);
+
+        // Sıfır bütçe hiç iterasyon çalıştırmaz; bol bütçe kolay haritada sonucu değiştirmez
+        rrt.reset(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 });
+        rrt.time_budget = Some(Duration::ZERO);
+        assert_eq!(rrt.plan(20_000).stats().iterations, 0);
+        rrt.time_budget = Some(Duration::from_secs(60));
+        let result = rrt.plan(20_000);
+        assert!(result.path().is_some() && !result.stats().budget_exhausted);
+    }
+
+    #[test]
+    fn plan_returns_at_the_iteration_the_goal_is_first_reached() {
+        let build = || {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+            rrt.reseed(5);
+            rrt
+        };
+        let mut stepped = build();
+        let mut reached_at = 0;
+        while stepped.goal_node.is_none() {
+            stepped.extend();
+            reached_at += 1;
+        }
+        let mut rrt = build();
+        let result = rrt.plan(20_000);
+        assert_eq!(result.stats().iterations, reached_at);
+        assert_eq!(rrt.iteration, reached_at);
+        assert_eq!(rrt.goal_node, Some(rrt.nodes.len() - 1));
+        assert_eq!(rrt.nodes.len(), stepped.nodes.len());
+
+        // Hedefe ulaşılmış ağaçta yeniden planlama ağacı büyütmez
+        let again = rrt.plan(20_000);
+        assert_eq!(again.stats().iterations, 0);
+        assert_eq!(again.path(), result.path());
+        assert_eq!(rrt.iteration, reached_at);
+    }
+
+    #[test]
+    fn reset_with_rerolls_endpoints_inside_obstacles() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        let wall = Obstacle::Rect { min: Point { x: 150.0, y: 0.0 }, max: Point { x: 250.0, y: 400.0 } };
+        rrt.obstacles.push(wall);
+        rrt.reseed(1);
+        rrt.plan(200);
+        // Geçerli başlangıç korunur, duvarın içindeki hedef yeniden çekilir
+        let start = Point { x: 30.0, y: 300.0 };
+        let (used_start, goal) = rrt.reset_with(start, Point { x: 200.0, y: 200.0 }).unwrap();
+        assert_eq!(used_start, start);
+        assert!(rrt.is_collision_free(&goal) && goal.distance(&start) >= rrt.goal_threshold);
+        assert_eq!((rrt.nodes.len(), rrt.nodes[0].point, rrt.goal()), (1, start, goal));
+        assert_eq!(rrt.obstacles.len(), 1);
+
+        // Alanın tamamını kaplayan engelde yer bulunamaz ve ağaç değişmez
+        rrt.obstacles.push(Obstacle::Rect { min: Point { x: -1.0, y: -1.0 }, max: Point { x: 401.0, y: 401.0 } });
+        let error = rrt.reset_with(Point { x: 10.0, y: 10.0 }, Point { x: 390.0, y: 390.0 }).unwrap_err();
+        assert_eq!(error.attempts, FREE_POINT_ATTEMPTS);
+        assert_eq!((rrt.nodes[0].point, rrt.goal()), (start, goal));
+    }
+
+    #[test]
+    fn forest_path_traces_back_to_the_root_nearer_the_goal() {
+        let far = Point { x: 20.0, y: 20.0 };
+        let near = Point { x: 300.0, y: 60.0 };
+        let goal = Point { x: 360.0, y: 360.0 };
+        let mut rrt: RRT = RRT::new(far, goal, 10.0, 10.0);
+        rrt.set_starts(vec![far, near]);
+        assert_eq!((rrt.nodes.len(), rrt.nodes[1].parent), (2, None));
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 0.1;
+        rrt.reseed(2);
+        let path = rrt.plan(20_000).path().expect("path found").clone();
+        assert_eq!(path[0], near);
+        assert_eq!(rrt.trace_path(), Some(path));
+        // Sıfırlama ve budama ek kökleri korur
+        assert_eq!(rrt.remove_subtree(1), 0);
+        rrt.reset(far, goal);
+        assert_eq!(rrt.nodes.iter().map(|n| (n.point, n.parent)).collect::<Vec<_>>(), vec![(far, None), (near, None)]);
+    }
+
+    #[test]
+    fn cross_edges_shorten_paths_on_the_same_tree() {
+        let build = |cross_edges: bool, seed: u64| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+            rrt.cross_edges = cross_edges;
+            rrt.reseed(seed);
+            let path = rrt.plan(20_000).path().expect("path found").clone();
+            (rrt, path)
+        };
+        let (mut tree_total, mut graph_total) = (0.0, 0.0);
+        for seed in 0..5 {
+            let (tree, tree_path) = build(false, seed);
+            let (graph, graph_path) = build(true, seed);
+            // Çapraz kenarlar rastgele sayı çekmez; iki modda da aynı ağaç büyür
+            assert_eq!(tree.nodes.len(), graph.nodes.len());
+            assert!(tree.graph_edges.is_empty() && !graph.graph_edges.is_empty());
+            assert_eq!(graph.trace_path(), Some(tree_path.clone()));
+            assert_eq!(graph.is_path_valid(&graph_path), Ok(()));
+            assert!(path_length(&graph_path) <= path_length(&tree_path) + 1e-3);
+            tree_total += path_length(&tree_path);
+            graph_total += path_length(&graph_path);
+        }
+        assert!(graph_total < 0.95 * tree_total, "{} vs {}", graph_total, tree_total);
+    }
+
+    #[test]
+    fn plans_behind_an_obstacle_sweeping_across_the_map() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 10.0, 10.0);
+        // Düz yolun ortasını 5 saniye boyunca kapatan, aşağı doğru kayan duvar
+        let wall = DynamicObstacle {
+            shape: Obstacle::Rect { min: Point { x: 170.0, y: 0.0 }, max: Point { x: 230.0, y: 260.0 } },
+            velocity: Point { x: 0.0, y: 40.0 },
+        };
+        rrt.dynamic_obstacles.push(wall);
+        rrt.goal_bias = 0.1;
+        rrt.reseed(4);
+        rrt.plan(20_000).path().expect("path found");
+
+        let mut chain = vec![rrt.goal_node.unwrap()];
+        while let Some(parent) = rrt.nodes[*chain.last().unwrap()].parent {
+            chain.push(parent);
+        }
+        for edge in chain.windows(2) {
+            let (to, from) = (&rrt.nodes[edge[0]], &rrt.nodes[edge[1]]);
+            assert_close(to.time, from.time + from.point.distance(&to.point) / rrt.speed);
+            for i in 0..=20 {
+                let s = i as f32 / 20.0;
+                let t = from.time + (to.time - from.time) * s;
+                assert!(!wall.occupied(&Point::lerp(from.point, to.point, s), t), "hit at t = {}", t);
+            }
+        }
+        // Duvar yerinde dursaydı yol onun içinden geçerdi: robot duvarın arkasından geçmiştir
+        let path = rrt.trace_path().unwrap();
+        rrt.obstacles.push(wall.at(0.0));
+        assert!(rrt.is_path_valid(&path).is_err());
+    }
+
+    #[test]
+    fn node_cap_prunes_leaves_but_keeps_the_goal_path() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+        rrt.max_nodes = Some(300);
+        rrt.node_cap_policy = NodeCapPolicy::Prune;
+        rrt.reseed(2);
+        let path = rrt.plan(20_000).path().expect("path found").clone();
+        for _ in 0..2_000 {
+            rrt.extend();
+            assert!(rrt.nodes.len() <= 300);
+        }
+        assert!(rrt.nodes_pruned > 0 && !rrt.saturated);
+        assert_rooted_tree(&rrt);
+        assert_eq!(rrt.trace_path().as_deref(), Some(path.points()));
+        assert_eq!(rrt.is_path_valid(&path), Ok(()));
+
+        rrt.node_cap_policy = NodeCapPolicy::Saturate;
+        let pruned = rrt.nodes_pruned;
+        for _ in 0..2_000 {
+            rrt.extend();
+        }
+        assert!(rrt.saturated);
+        assert_eq!((rrt.nodes.len(), rrt.nodes_pruned), (300, pruned));
+    }
+
+    #[test]
+    fn branch_and_bound_prunes_without_touching_the_best_path() {
+        let build = |cross_edges: bool| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+            rrt.branch_and_bound = true;
+            rrt.cross_edges = cross_edges;
+            rrt.reseed(2);
+            rrt
+        };
+        // Yol bulunmadan en iyi maliyet sonsuzdur ve hiçbir düğüm budanmaz
+        let mut rrt = build(false);
+        for _ in 0..300 {
+            rrt.extend();
+        }
+        assert_eq!((rrt.goal_node, rrt.best_cost()), (None, None));
+        let nodes = rrt.nodes.len();
+        assert_eq!(rrt.prune_branch_and_bound(), 0);
+        assert_eq!((rrt.nodes.len(), rrt.bnb_extensions_rejected), (nodes, 0));
+
+        for cross_edges in [false, true] {
+            let mut rrt = build(cross_edges);
+            rrt.plan(20_000).path().expect("path found");
+            let (tree_path, best) = (rrt.trace_path().map(Path::into_points), rrt.best_cost());
+            for _ in 0..30 {
+                for _ in 0..100 {
+                    rrt.extend();
+                }
+                assert!(rrt.best_cost().unwrap() <= best.unwrap());
+            }
+            assert!(rrt.bnb_nodes_pruned > 0 && rrt.bnb_extensions_rejected > 0, "cross edges: {}", cross_edges);
+            assert_rooted_tree(&rrt);
+            let path = rrt.best_path().unwrap();
+            assert_eq!(rrt.is_path_valid(&path), Ok(()));
+            if !cross_edges {
+                assert_eq!(rrt.trace_path().map(Path::into_points), tree_path);
+            }
+        }
+    }
+
+    #[test]
+    fn spline_through_collinear_waypoints_stays_on_the_line() {
+        let rrt = empty_tree();
+        let waypoints = Path::from([(10.0, 10.0), (40.0, 25.0), (50.0, 30.0), (130.0, 70.0)].map(|(x, y)| Point { x, y }).to_vec());
+        let spline = rrt.spline_path(&waypoints, 8);
+        assert_eq!(spline.len(), 3 * 8 + 1);
+        assert_eq!((spline[0], *spline.last().unwrap()), (waypoints[0], waypoints[3]));
+        for p in &spline {
+            assert!((p.y - (5.0 + 0.5 * p.x)).abs() < 1e-3, "{:?} is off the line", p);
+        }
+    }
+
+    #[test]
+    fn spline_falls_back_to_straight_segments_near_obstacles() {
+        let mut rrt = empty_tree();
+        // Köşeye yaklaşırken eğri dışa doğru şişer ve dikey parçanın yanındaki engele taşar
+        let wall = Obstacle::Rect { min: Point { x: 80.0, y: 120.0 }, max: Point { x: 99.0, y: 250.0 } };
+        let waypoints = Path::from([(100.0, 300.0), (100.0, 100.0), (300.0, 100.0)].map(|(x, y)| Point { x, y }).to_vec());
+        assert!(rrt.spline_path(&waypoints, 8).iter().any(|p| wall.contains(p)));
+        rrt.obstacles.push(wall);
+        assert_eq!(rrt.is_path_valid(&waypoints), Ok(()));
+        let spline = rrt.spline_path(&waypoints, 8);
+        assert_eq!(rrt.is_path_valid(&spline), Ok(()));
+        // Engelden uzak ikinci parça eğri olarak kalır
+        assert!(spline[9..].iter().any(|p| p.y < 99.0));
+    }
+
+    #[test]
+    fn frontier_sampling_covers_open_space_faster_than_uniform() {
+        // Köşeden büyüyen 300 düğümlü ağacın düğüm içeren 20x20 hücrelerinin oranı
+        let coverage = |sampler: SamplerKind| {
+            let mut total = 0.0;
+            for seed in 0..10 {
+                let mut rrt = empty_tree();
+                rrt.reset(Point { x: 20.0, y: 20.0 }, rrt.goal());
+                rrt.sampler = sampler;
+                rrt.reseed(seed);
+                while rrt.nodes.len() < 300 {
+                    rrt.extend();
+                }
+                let mut cells = [false; 400];
+                for node in &rrt.nodes {
+                    let (col, row) = ((node.point.x / 20.0) as usize, (node.point.y / 20.0) as usize);
+                    cells[row.min(19) * 20 + col.min(19)] = true;
+                }
+                total += cells.iter().filter(|&&c| c).count() as f32 / 400.0;
+            }
+            total / 10.0
+        };
+        let (uniform, frontier) = (coverage(SamplerKind::Uniform), coverage(SamplerKind::Frontier));
+        assert!(frontier > 1.03 * uniform, "{} vs {}", frontier, uniform);
+    }
+
+    #[test]
+    fn stats_describe_the_shape_of_a_hand_built_tree() {
+        // 0 ─┬─ 1 ── 3 ── 5
+        //    ├─ 2
+        //    └─ 4
+        let mut rrt = empty_tree();
+        for (x, parent) in [(1.0, 0), (2.0, 0), (3.0, 1), (4.0, 0), (5.0, 3)] {
+            rrt.add_node(Point { x, y: 0.0 }, parent);
+        }
+        let stats = rrt.stats();
+        assert_eq!((stats.max_depth, stats.max_branching, stats.leaves), (3, 3, 3));
+        assert_close(stats.mean_depth, 8.0 / 6.0);
+        // Yaprak olmayan 0, 1 ve 3 düğümlerinin 3 + 1 + 1 çocuğu
+        assert_close(stats.mean_branching, 5.0 / 3.0);
+
+        let stats = empty_tree().stats();
+        assert_eq!(stats, TreeStats { leaves: 1, ..TreeStats::default() });
+    }
+
+    #[test]
+    fn adjacency_lists_the_children_of_each_node() {
+        let mut rrt = empty_tree();
+        //      0
+        //     / \
+        //    1   2
+        //   / \   \
+        //  3   5   4
+        for (parent, x) in [(0, 1.0), (0, 2.0), (1, 3.0), (2, 4.0), (1, 5.0)] {
+            rrt.add_node(Point { x, y: 0.0 }, parent);
+        }
+        assert_eq!(rrt.adjacency(), vec![vec![1, 2], vec![3, 5], vec![4], vec![], vec![], vec![]]);
+        for (parent, children) in rrt.adjacency().iter().enumerate() {
+            for &child in children {
+                assert_eq!(rrt.nodes[child].parent, Some(parent));
+            }
+        }
+    }
+
+    #[test]
+    fn shrinking_the_goal_threshold_re_evaluates_the_goal_node() {
+        let goal = Point { x: 100.0, y: 0.0 };
+        let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, goal, 10.0, 20.0);
+        rrt.add_node(Point { x: 85.0, y: 0.0 }, 0); // hedefe 15
+        rrt.add_node(Point { x: 96.0, y: 0.0 }, 1); // hedefe 4
+        assert_eq!(rrt.goal_node, Some(1));
+
+        // İlk düğüm dışarıda kalır, bölgede kalan ikinci düğüm hedef düğümü olur
+        rrt.set_goal_threshold(10.0);
+        assert_eq!(rrt.goal_node, Some(2));
+        // Hiçbir düğüm bölgede kalmazsa hedefe ulaşılmamış sayılır
+        rrt.set_goal_threshold(2.0);
+        assert_eq!(rrt.goal_node, None);
+        // Eşik yeniden büyüyünce bölgeye giren ilk düğüm seçilir
+        rrt.set_goal_threshold(20.0);
+        assert_eq!(rrt.goal_node, Some(1));
+        // Hedef düğümü hâlâ bölgedeyse daha küçük eşikte de korunur
+        rrt.set_goal_threshold(16.0);
+        assert_eq!(rrt.goal_node, Some(1));
+    }
+
+    #[test]
+    fn moving_the_goal_keeps_the_tree_and_picks_up_nodes_already_inside() {
+        for kind in [NeighborIndexKind::Linear, NeighborIndexKind::KdTree] {
+            let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 100.0, y: 100.0 }, 10.0, 5.0);
+            rrt.set_neighbor_index(kind, 10.0);
+            for (x, parent) in [(10.0, 0), (20.0, 1), (30.0, 2)] {
+                rrt.add_node(Point { x, y: 0.0 }, parent);
+            }
+            // Yeni eşiğin içindeki düğümlerden ilk eklenen seçilir
+            assert_eq!(rrt.move_goal(Point { x: 23.0, y: 0.0 }), Some(2), "{:?}", kind);
+            assert_eq!(rrt.nodes.len(), 4);
+            assert_eq!((rrt.best_goal_node, rrt.best_goal_distance), (2, 3.0));
+            assert_eq!(rrt.move_goal(Point { x: 25.0, y: 40.0 }), None, "{:?}", kind);
+        }
+
+        // Ağaç alanı kapladıktan sonra yakına taşınan hedefe birkaç iterasyonda ulaşılır
+        let mut rrt = RRT::from_config(&RRTConfig {
+            start: Some(Point { x: 20.0, y: 20.0 }),
+            goal: Some(Point { x: 380.0, y: 380.0 }),
+            seed: Some(4),
+            ..RRTConfig::default()
+        });
+        let first = rrt.plan(20_000);
+        assert!(first.path().is_some());
+        let nodes = rrt.nodes.len();
+        rrt.move_goal(Point { x: 360.0, y: 300.0 });
+        let second = rrt.plan(20_000);
+        assert!(second.path().is_some());
+        assert!(rrt.nodes.len() >= nodes);
+        assert!(second.stats().iterations < first.stats().iterations, "{} >= {}", second.stats().iterations, first.stats().iterations);
+    }
+
+    #[test]
+    fn moving_a_wall_across_the_path_prunes_only_the_nodes_beyond_it() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 20.0, 5.0);
+        rrt.reseed(2);
+        // Kökten hedefe düz bir zincir ve yukarı doğru kısa bir yan dal
+        for (i, x) in (40..=380).step_by(20).enumerate() {
+            rrt.add_node(Point { x: x as f32, y: 200.0 }, i);
+        }
+        let branch = rrt.nodes.len();
+        rrt.add_node(Point { x: 20.0, y: 220.0 }, 0);
+        rrt.add_node(Point { x: 20.0, y: 240.0 }, branch);
+        assert_eq!(rrt.goal_node, Some(18));
+
+        // Uzaktaki engel hiçbir kenara değmez
+        assert_eq!(rrt.add_obstacle(Obstacle::Rect { min: Point { x: 300.0, y: 0.0 }, max: Point { x: 310.0, y: 50.0 } }), 0);
+        // Duvar yolun ortasına taşınınca 180 → 200 kenarından sonraki on düğüm silinir
+        let wall = Obstacle::Rect { min: Point { x: 195.0, y: 100.0 }, max: Point { x: 205.0, y: 300.0 } };
+        assert_eq!(rrt.move_obstacle(0, wall), 10);
+        assert_eq!(rrt.nodes.len(), 11);
+        assert_eq!(rrt.goal_node, None);
+        assert!(rrt.nodes.iter().all(|n| n.point.x < 195.0));
+        assert_rooted_tree(&rrt);
+
+        // Kalan ağaçtan büyümeyi sürdüren planlayıcı duvarın çevresinden hedefe yeniden bağlanır
+        let path = rrt.plan(20_000).path().expect("path found").clone();
+        assert_eq!(rrt.is_path_valid(&path), Ok(()));
+        assert!(path.iter().any(|p| p.y < 100.0 || p.y > 300.0));
+    }
+
+    #[test]
+    fn circle_region_only_samples_inside_its_radius() {
+        let mut region = CircleRegion { center: Point { x: 200.0, y: 150.0 }, radius: 40.0 };
+        let mut rng = StdRng::seed_from_u64(9);
+        let samples: Vec<Point> = (0..2000).map(|_| region.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|p| region.contains(p) && p.distance(&region.center) <= 40.0));
+        // Dairenin dört çeyreğine de örnek düşer
+        assert!(samples.iter().any(|p| p.x < 180.0 && p.y < 130.0));
+        assert!(samples.iter().any(|p| p.x > 220.0 && p.y > 170.0));
+
+        // Planlayıcı da düzgün örneklerini bölgeden çeker
+        let mut rrt: RRT = RRT::new(Point { x: 200.0, y: 150.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+        rrt.reseed(3);
+        rrt.set_sampling_region(Some(Box::new(region)));
+        for _ in 0..500 {
+            let extension = rrt.extend();
+            assert!(region.contains(&extension.sample));
+        }
+    }
+
+    #[test]
+    fn a_node_inside_a_rectangular_goal_region_reaches_the_goal() {
+        let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 300.0, y: 0.0 }, 10.0, 10.0);
+        rrt.set_goal_region(Some(Box::new(RectGoal { min: Point { x: 100.0, y: -20.0 }, max: Point { x: 140.0, y: 20.0 } })));
+        rrt.add_node(Point { x: 90.0, y: 0.0 }, 0);
+        assert_eq!(rrt.goal_node, None);
+        // Hedef noktasından uzak ama dikdörtgenin içinde
+        rrt.add_node(Point { x: 100.0, y: 15.0 }, 1);
+        assert_eq!(rrt.goal_node, Some(2));
+        assert_eq!(rrt.reached_goal(), Some(Point { x: 100.0, y: 15.0 }));
+
+        // Hedef yanlı örnekler dikdörtgenin içinden çekilir
+        let region = RectGoal { min: Point { x: 100.0, y: -20.0 }, max: Point { x: 140.0, y: 20.0 } };
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 1.0;
+        for _ in 0..50 {
+            assert!(region.contains(&rrt.sample()));
+        }
+    }
+
+    #[test]
+    fn circle_goal_matches_the_goal_threshold() {
+        let goal = Point { x: 200.0, y: 200.0 };
+        let grow = |region: Option<Box<dyn GoalRegion>>| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, goal, 10.0, 10.0);
+            rrt.set_goal_region(region);
+            rrt.sampler = SamplerKind::GoalBiased;
+            rrt.goal_bias = 0.1;
+            rrt.reseed(9);
+            let path = rrt.plan(5_000).path().map(|p| p.to_vec());
+            (path, rrt.nodes.len(), rrt.goal_node)
+        };
+        let threshold = grow(None);
+        assert!(threshold.0.is_some());
+        assert_eq!(grow(Some(Box::new(CircleGoal { center: goal, radius: 10.0 }))), threshold);
+    }
+
+    #[test]
+    fn plans_to_the_reachable_goal_when_another_is_walled_off() {
+        let walled = Point { x: 50.0, y: 50.0 };
+        let open = Point { x: 350.0, y: 350.0 };
+        let mut rrt: RRT = RRT::new(Point { x: 200.0, y: 200.0 }, walled, 10.0, 10.0);
+        rrt.set_goals(vec![walled, open]);
+        // Birinci hedefin çevresi kapalı bir kutu
+        let rect = |x1, y1, x2, y2| Obstacle::Rect { min: Point { x: x1, y: y1 }, max: Point { x: x2, y: y2 } };
+        rrt.obstacles.extend([
+            rect(20.0, 20.0, 80.0, 25.0),
+            rect(20.0, 75.0, 80.0, 80.0),
+            rect(20.0, 20.0, 25.0, 80.0),
+            rect(75.0, 20.0, 80.0, 80.0),
+        ]);
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 0.2;
+        rrt.reseed(7);
+        let path = rrt.plan(20_000).path().expect("path found").clone();
+        assert!(rrt.is_path_valid(&path).is_ok());
+        assert!(path.last().unwrap().distance(&open) < rrt.goal_threshold);
+        assert_eq!(rrt.reached_goal(), Some(open));
+    }
+
+    #[test]
+    fn not_found_reports_how_close_the_tree_came_to_the_goal() {
+        let goal = Point { x: 50.0, y: 50.0 };
+        let mut rrt: RRT = RRT::new(Point { x: 200.0, y: 200.0 }, goal, 10.0, 10.0);
+        // Hedefin çevresi kapalı bir kutu; ağaç en fazla duvarın dışına kadar yaklaşabilir
+        let rect = |x1, y1, x2, y2| Obstacle::Rect { min: Point { x: x1, y: y1 }, max: Point { x: x2, y: y2 } };
+        rrt.obstacles.extend([
+            rect(20.0, 20.0, 80.0, 25.0),
+            rect(20.0, 75.0, 80.0, 80.0),
+            rect(20.0, 20.0, 25.0, 80.0),
+            rect(75.0, 20.0, 80.0, 80.0),
+        ]);
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 0.2;
+        rrt.reseed(7);
+        let result = rrt.plan(3_000);
+        let stats = *result.stats();
+        assert!(result.path().is_none());
+        let nearest = rrt.nodes.iter().map(|n| n.point.distance(&goal)).fold(f32::INFINITY, f32::min);
+        assert_eq!(stats.best_goal_distance, nearest);
+        assert_eq!(rrt.nodes[rrt.best_goal_node].point.distance(&goal), nearest);
+        // Duvarın dışından hedefe en az 25 birim vardır ve ağaç duvara birkaç adım yaklaşmıştır
+        assert!(nearest > 25.0 && nearest < 40.0, "{}", nearest);
+        assert!(stats.best_goal_iteration > 0 && stats.best_goal_iteration <= stats.iterations);
+    }
+
+    // Aynı örnek dizisiyle büyütülen ağacın ebeveynlerini ve düğüm konumlarını döndürür. Örnekler
+    // 1/8'in katları olduğundan f32 ve f64'te tam olarak aynı değerlerdir.
+    fn grow_with_samples<T: Scalar>(samples: &[(f64, f64)]) -> (Vec<Option<usize>>, Vec<(f64, f64)>) {
+        let p = |x: f64, y: f64| Point { x: T::cast(x), y: T::cast(y) };
+        let mut rrt = RRT::new(p(20.0, 20.0), p(380.0, 380.0), T::cast(10.0), T::cast(10.0));
+        rrt.obstacles.push(Obstacle::Rect { min: p(100.0, 0.0), max: p(120.0, 300.0) });
+        for &(x, y) in samples {
+            let sample = p(x, y);
+            let nearest = rrt.find_nearest(&sample);
+            let new_point = rrt.steer(&rrt.nodes[nearest].point, &sample);
+            if rrt.is_edge_collision_free(&rrt.nodes[nearest].point, &new_point) {
+                rrt.add_node(new_point, nearest);
+            }
+        }
+        let parents = rrt.nodes.iter().map(|n| n.parent).collect();
+        let points = rrt.nodes.iter().map(|n| (n.point.x.to_f64().unwrap(), n.point.y.to_f64().unwrap())).collect();
+        (parents, points)
+    }
+
+    #[test]
+    fn f32_and_f64_trees_grow_the_same_way() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let samples: Vec<(f64, f64)> =
+            (0..1500).map(|_| (rng.gen_range(0..3200) as f64 / 8.0, rng.gen_range(0..3200) as f64 / 8.0)).collect();
+        let (parents32, points32) = grow_with_samples::<f32>(&samples);
+        let (parents64, points64) = grow_with_samples::<f64>(&samples);
+        assert!(parents32.len() > 100);
+        assert_eq!(parents32, parents64);
+        for (a, b) in points32.iter().zip(&points64) {
+            assert!((a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3, "{:?} != {:?}", a, b);
+        }
+
+        // Ekransız planlama her iki tipte de hedefe giden geçerli bir yol bulur
+        fn plan<T: Scalar>() -> Path<T> {
+            let mut rrt = RRT::new(
+                Point { x: T::cast(20.0), y: T::cast(20.0) },
+                Point { x: T::cast(380.0), y: T::cast(380.0) },
+                T::cast(10.0),
+                T::cast(10.0),
+            );
+            rrt.reseed(3);
+            let path = rrt.plan(20_000).path().expect("path found").clone();
+            assert!(rrt.is_path_valid(&path).is_ok());
+            assert!(path.last().unwrap().distance(&rrt.goal()) < rrt.goal_threshold);
+            path
+        }
+        assert!(plan::<f32>().len() > 1);
+        assert!(plan::<f64>().len() > 1);
+    }
+
+    #[test]
+    fn f64_keeps_short_steps_far_from_the_origin() {
+        let base = 1e7;
+        let p = |x: f64, y: f64| Point { x: base + x, y: base + y };
+        let mut rrt = RRT::new(p(1.0, 1.0), p(9.0, 9.0), 0.25, 0.5);
+        rrt.bounds = Bounds::new(base, base + 10.0, base, base + 10.0);
+        rrt.collision_resolution = 0.05;
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 0.2;
+        rrt.reseed(5);
+        let path = rrt.plan(50_000).path().expect("path found").clone();
+        for segment in path.windows(2) {
+            let length = segment[0].distance(&segment[1]);
+            assert!(length > 0.0 && length <= 0.25 + 1e-6, "segment length {}", length);
+        }
+        assert!(path.last().unwrap().distance(&rrt.goal()) < 0.5);
+
+        // Aynı konumda f32'nin aralığı 1 birimdir; 0.25'lik adım noktayı hiç hareket ettirmez
+        let rrt32: RRT = RRT::new(Point { x: 1e7, y: 1e7 }, Point { x: 1e7 + 8.0, y: 1e7 }, 0.25, 0.5);
+        let from = rrt32.nodes[0].point;
+        assert_eq!(rrt32.steer(&from, &rrt32.goal()).distance(&from), 0.0);
+    }
+
+    // Her düğümden ebeveyn bağlantıları izlendiğinde hiçbir düğüme ikinci kez uğramadan köke
+    // (0) ulaşılmalıdır: ağaç tek parça ve döngüsüzdür
+    fn assert_rooted_tree(rrt: &RRT) {
+        assert!(rrt.nodes[0].parent.is_none());
+        for start in 0..rrt.nodes.len() {
+            let mut visited = vec![false; rrt.nodes.len()];
+            let mut current = start;
+            while let Some(parent) = rrt.nodes[current].parent {
+                assert!(parent < rrt.nodes.len(), "node {} has dangling parent {}", current, parent);
+                assert!(!visited[current], "cycle through node {}", current);
+                visited[current] = true;
+                current = parent;
+            }
+            assert_eq!(current, 0, "node {} is not connected to the root", start);
+        }
+    }
+
+    // Üretilen her adım var olan düğümlerden birini ebeveyn seçer; `Index` küçültme sırasında
+    // daha küçük indekslere indiğinden hatalar kısa örneklere indirgenir
+    fn add_node_steps() -> impl Strategy<Value = Vec<(prop::sample::Index, f32, f32)>> {
+        prop::collection::vec((any::<prop::sample::Index>(), 0.0f32..400.0, 0.0f32..400.0), 0..200)
+    }
+
+    fn empty_tree() -> RRT {
+        // Hedef ağacın hiçbir zaman ulaşamayacağı yerde tutulur
+        RRT::new(Point { x: 200.0, y: 200.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0)
+    }
+
+    proptest! {
+        #[test]
+        fn add_node_keeps_a_single_rooted_tree(steps in add_node_steps()) {
+            let mut rrt = empty_tree();
+            for (parent, x, y) in steps {
+                let parent = parent.index(rrt.nodes.len());
+                rrt.add_node(Point { x, y }, parent);
+            }
+            assert_rooted_tree(&rrt);
+        }
+
+        #[test]
+        fn neighbor_indexes_agree_with_linear_scan(
+            steps in add_node_steps(),
+            queries in prop::collection::vec((-50.0f32..450.0, -50.0f32..450.0), 1..20),
+            removal in any::<prop::sample::Index>(),
+            cell_size in 3.0f32..80.0,
+        ) {
+            let mut trees: Vec<RRT> = [NeighborIndexKind::Linear, NeighborIndexKind::Grid, NeighborIndexKind::KdTree]
+                .into_iter()
+                .map(|kind| {
+                    let mut rrt = empty_tree();
+                    rrt.set_neighbor_index(kind, cell_size);
+                    rrt
+                })
+                .collect();
+            for (parent, x, y) in steps {
+                for rrt in &mut trees {
+                    let parent = parent.index(rrt.nodes.len());
+                    rrt.add_node(Point { x, y }, parent);
+                }
+            }
+            // Budamadan sonra yeniden kurulan indeksler de aynı sonucu vermelidir
+            for round in 0..2 {
+                for &(x, y) in &queries {
+                    let q = Point { x, y };
+                    let expected = trees[0].find_nearest(&q);
+                    prop_assert_eq!(trees[1].find_nearest(&q), expected, "grid, round {}", round);
+                    prop_assert_eq!(trees[2].find_nearest(&q), expected, "k-d tree, round {}", round);
+                }
+                for rrt in &mut trees {
+                    let root = removal.index(rrt.nodes.len());
+                    rrt.remove_subtree(root);
+                }
+            }
+        }
+
+        #[test]
+        fn radius_and_k_nearest_queries_agree_with_brute_force(
+            steps in add_node_steps(),
+            queries in prop::collection::vec((-50.0f32..450.0, -50.0f32..450.0), 1..10),
+            radius in prop_oneof![Just(0.0f32), 0.0f32..150.0],
+            k in prop_oneof![Just(0usize), 1usize..20, Just(1000usize)],
+            cell_size in 3.0f32..80.0,
+        ) {
+            let mut trees: Vec<RRT> = [NeighborIndexKind::Linear, NeighborIndexKind::Grid, NeighborIndexKind::KdTree]
+                .into_iter()
+                .map(|kind| {
+                    let mut rrt = empty_tree();
+                    rrt.set_neighbor_index(kind, cell_size);
+                    rrt
+                })
+                .collect();
+            for (parent, x, y) in steps {
+                for rrt in &mut trees {
+                    let parent = parent.index(rrt.nodes.len());
+                    rrt.add_node(Point { x, y }, parent);
+                }
+            }
+            // Düğümlerin üzerindeki sorgular sıfır yarıçapta da sonuç döndürür
+            let on_nodes = trees[0].nodes.iter().take(3).map(|n| n.point);
+            for q in queries.iter().map(|&(x, y)| Point { x, y }).chain(on_nodes) {
+                let mut by_distance: Vec<(f32, usize)> =
+                    trees[0].nodes.iter().enumerate().map(|(i, n)| (n.point.distance(&q), i)).collect();
+                by_distance.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap().then(a.1.cmp(&b.1)));
+                let within: Vec<usize> = by_distance.iter().filter(|(d, _)| *d <= radius).map(|&(_, i)| i).collect();
+                let nearest: Vec<usize> = by_distance.iter().take(k).map(|&(_, i)| i).collect();
+                for rrt in &trees {
+                    prop_assert_eq!(&rrt.neighbors_within(&q, radius), &within, "{:?}", rrt.neighbor_index_kind());
+                    prop_assert_eq!(&rrt.k_nearest(&q, k), &nearest, "{:?}", rrt.neighbor_index_kind());
+                }
+            }
+        }
+
+        #[test]
+        fn remove_subtree_keeps_a_single_rooted_tree(
+            steps in add_node_steps(),
+            removals in prop::collection::vec(any::<prop::sample::Index>(), 0..5),
+        ) {
+            let mut rrt = empty_tree();
+            for (parent, x, y) in steps {
+                let parent = parent.index(rrt.nodes.len());
+                rrt.add_node(Point { x, y }, parent);
+            }
+            for root in removals {
+                let root = root.index(rrt.nodes.len());
+                rrt.remove_subtree(root);
+                assert_rooted_tree(&rrt);
+            }
+        }
+
+        #[test]
+        fn arbitrary_coordinate_bits_never_panic(
+            coords in prop::collection::vec((any::<u32>(), any::<u32>()), 2..30),
+            cell_size in 3.0f32..80.0,
+        ) {
+            // NaN, sonsuz ve alt-normal değerler dahil her bit deseni koordinat olabilir
+            let points: Vec<Point> = coords.iter().map(|&(x, y)| Point { x: f32::from_bits(x), y: f32::from_bits(y) }).collect();
+            for kind in [NeighborIndexKind::Linear, NeighborIndexKind::Grid, NeighborIndexKind::KdTree] {
+                let mut rrt: RRT = RRT::new(points[0], points[1], 10.0, 5.0);
+                rrt.set_neighbor_index(kind, cell_size);
+                rrt.reseed(1);
+                for (i, p) in points.iter().enumerate() {
+                    rrt.add_node(*p, i % rrt.nodes.len());
+                }
+                for p in &points {
+                    let nearest = rrt.find_nearest(p);
+                    prop_assert!(nearest < rrt.nodes.len());
+                    rrt.neighbors_within(p, 20.0);
+                    rrt.steer(&rrt.nodes[nearest].point, p);
+                }
+                rrt.extend();
+                rrt.plan(5);
+            }
+        }
+    }
+
+    #[test]
+    fn best_cost_tracks_the_length_of_the_best_path_as_it_improves() {
+        for cross_edges in [false, true] {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 60.0 });
+            rrt.cross_edges = cross_edges;
+            rrt.reseed(4);
+            let mut previous = f32::INFINITY;
+            for i in 0..2_000 {
+                rrt.extend();
+                let Some(cost) = rrt.best_cost() else { continue };
+                // Ağaç modunda en iyi yol ağaç yolu, çapraz kenarlarla çizgedeki en kısa yoldur
+                if i % 10 == 0 {
+                    let path = if cross_edges { rrt.best_path() } else { rrt.trace_path() };
+                    assert_close(cost, path_length(&path.unwrap()));
+                }
+                assert!(cost <= previous + 1e-4, "cost grew from {} to {}", previous, cost);
+                previous = cost;
+            }
+            assert!(previous.is_finite());
+        }
+    }
+
+    #[test]
+    fn seeded_plans_on_simple_maps_produce_valid_paths() {
+        let rect = |min: (f32, f32), max: (f32, f32)| Obstacle::Rect {
+            min: Point { x: min.0, y: min.1 },
+            max: Point { x: max.0, y: max.1 },
+        };
+        let maps = [
+            ("empty", vec![]),
+            ("single wall", vec![rect((190.0, 0.0), (210.0, 300.0))]),
+            ("narrow gap", vec![rect((190.0, 0.0), (210.0, 185.0)), rect((190.0, 215.0), (210.0, 400.0))]),
+        ];
+        for (name, obstacles) in maps {
+            for seed in 0..3 {
+                let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 10.0, 10.0);
+                rrt.obstacles = obstacles.clone();
+                rrt.sampler = SamplerKind::GoalBiased;
+                rrt.goal_bias = 0.1;
+                rrt.reseed(seed);
+                let path = rrt.plan(20_000).path().cloned();
+                let path = path.unwrap_or_else(|| panic!("{} (seed {}): no path", name, seed));
+                assert_eq!(rrt.validate_path(&path), Ok(()), "{} (seed {})", name, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn validate_path_reports_the_first_defect() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        let (start, goal) = (rrt.nodes[0].point, rrt.goal());
+        let straight: Vec<Point> = (0..=100).map(|i| Point::lerp(start, goal, i as f32 / 100.0)).collect();
+        assert!(start.distance(&goal) / 100.0 < rrt.step_size);
+        assert_eq!(rrt.validate_path(&straight), Ok(()));
+        assert_eq!(rrt.validate_path(&[]), Err(PathError::Empty));
+        assert_eq!(rrt.validate_path(&straight[1..]), Err(PathError::NotAtStart(straight[1])));
+        assert_eq!(rrt.validate_path(&straight[..99]), Err(PathError::GoalNotReached(straight[98])));
+        let jump = [start, straight[50], goal];
+        assert!(matches!(rrt.validate_path(&jump), Err(PathError::SegmentTooLong { index: 0, .. })));
+        rrt.obstacles.push(Obstacle::Circle { center: straight[30], radius: 5.0 });
+        assert!(matches!(rrt.validate_path(&straight), Err(PathError::Collision { index }) if (24..30).contains(&index)));
+    }
+
+    #[test]
+    fn scripted_samples_build_an_exact_tree() {
+        let p = |x: f32, y: f32| Point { x, y };
+        for kind in [NeighborIndexKind::Linear, NeighborIndexKind::Grid, NeighborIndexKind::KdTree] {
+            let mut rrt: RRT = RRT::new(p(100.0, 100.0), p(125.0, 100.0), 10.0, 2.0);
+            rrt.set_neighbor_index(kind, 20.0);
+            rrt.obstacles.push(Obstacle::Circle { center: p(100.0, 120.0), radius: 4.0 });
+            let script = vec![
+                p(100.0, 140.0), // Düğüm 1, kökün üstünde
+                p(100.0, 121.0), // Engelin içinde; yönlendirilen nokta da engelde kalır ve reddedilir
+                p(125.0, 100.0), // Tam hedefin üzerinde, ama kökten bir adım ötede kalır: düğüm 2
+                p(120.0, 120.0), // Düğüm 1 ve 2'ye eşit uzaklıkta; indeksi küçük olan seçilir
+                p(125.0, 100.0), // Düğüm 2'den bir adım
+                p(125.0, 100.0), // Adımdan yakın; tam hedefin üzerinde durur
+            ];
+            rrt.sample_source = Some(Box::new(script.into_iter()));
+            let added: Vec<_> = (0..6).map(|_| rrt.extend().added).collect();
+            assert_eq!(added, vec![Some(1), None, Some(2), Some(3), Some(4), Some(5)], "{:?}", kind);
+            let parents: Vec<_> = rrt.nodes.iter().map(|n| n.parent).collect();
+            assert_eq!(parents, vec![None, Some(0), Some(0), Some(1), Some(2), Some(4)], "{:?}", kind);
+            let expected = [p(100.0, 100.0), p(100.0, 110.0), p(110.0, 100.0), p(108.944_27, 114.472_14), p(120.0, 100.0)];
+            for (node, expected) in rrt.nodes.iter().zip(expected) {
+                assert!(node.point.distance(&expected) < 1e-4, "{:?}: {:?} != {:?}", kind, node.point, expected);
+            }
+            assert_eq!((rrt.nodes[5].point, rrt.goal_node), (rrt.goal(), Some(5)), "{:?}", kind);
+        }
+    }
+
+    #[test]
+    fn long_robot_passes_a_narrow_gap_lengthwise_but_not_sideways() {
+        let p = |x: f32, y: f32| Point { x, y };
+        let rect = |w: f32, h: f32| RobotShape::Polygon(vec![p(-w / 2.0, -h / 2.0), p(w / 2.0, -h / 2.0), p(w / 2.0, h / 2.0), p(-w / 2.0, h / 2.0)]);
+        // Dikey duvarda 20 birimlik boşluk; robot 40 uzunluğunda ve 6 genişliğinde
+        let build = |shape: RobotShape| {
+            let mut rrt: RRT = RRT::new(p(50.0, 200.0), p(350.0, 200.0), 10.0, 10.0);
+            rrt.obstacles = vec![
+                Obstacle::Rect { min: p(190.0, 0.0), max: p(210.0, 190.0) },
+                Obstacle::Rect { min: p(190.0, 210.0), max: p(210.0, 400.0) },
+            ];
+            rrt.robot_shape = shape;
+            rrt.sampler = SamplerKind::GoalBiased;
+            rrt.goal_bias = 0.2;
+            rrt.reseed(1);
+            rrt
+        };
+        let lengthwise = build(rect(40.0, 6.0));
+        let sideways = build(rect(6.0, 40.0));
+        let (before, after) = (p(150.0, 200.0), p(250.0, 200.0));
+        assert!(lengthwise.is_edge_collision_free(&before, &after));
+        assert!(!sideways.is_edge_collision_free(&before, &after));
+        assert!(sideways.is_collision_free(&before) && sideways.is_collision_free(&after));
+        // Robotu kapsayan disk boşluğa sığmaz
+        let mut disk = build(RobotShape::Disk);
+        disk.robot_radius = 20.0;
+        assert!(!disk.is_edge_collision_free(&before, &after));
+        // Boşluğa çapraz giren robotun köşesi duvara değer, boşluğun ortasında uç uca geçen değmez
+        assert!(!lengthwise.is_edge_collision_free(&p(200.0, 170.0), &p(200.0, 200.0)));
+        assert!(!lengthwise.is_edge_collision_free(&p(200.0, 200.0), &p(200.0, 208.0)));
+
+        let mut lengthwise = lengthwise;
+        let path = lengthwise.plan(5_000).path().cloned().expect("path through the gap");
+        assert_eq!(lengthwise.validate_path(&path), Ok(()));
+        let mut sideways = sideways;
+        assert!(sideways.plan(2_000).path().is_none());
+        // Ağacın hiçbir kenarı duvarın öbür yanına geçmez
+        assert!(sideways.nodes.iter().all(|n| n.point.x < 190.0));
+    }
+
+    #[test]
+    fn clearance_scaled_steps_shrink_towards_an_obstacle() {
+        let p = |x: f32, y: f32| Point { x, y };
+        let mut rrt: RRT = RRT::new(p(20.0, 200.0), p(380.0, 200.0), 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: p(300.0, 200.0), radius: 20.0 });
+        rrt.step_policy = StepPolicy::ClearanceScaled { min: 2.0, max: 30.0 };
+        let target = p(300.0, 200.0);
+        let steps: Vec<f32> = [20.0, 255.0, 265.0, 275.0, 279.0].iter().map(|&x| rrt.steer(&p(x, 200.0), &target).x - x).collect();
+        assert_eq!((steps[0], steps[4]), (30.0, 2.0));
+        assert!(steps.windows(2).all(|w| w[1] < w[0]), "{:?}", steps);
+        // Aradaki adımlar açıklık kadardır; böyle bir adım engele girmez
+        assert_close(steps[1], 25.0);
+        assert_close(steps[3], 5.0);
+        rrt.step_policy = StepPolicy::Fixed;
+        assert_eq!(rrt.steer(&p(279.0, 200.0), &target), p(289.0, 200.0));
+
+        rrt.step_policy = StepPolicy::ClearanceScaled { min: 2.0, max: 30.0 };
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 0.1;
+        rrt.reseed(3);
+        let path = rrt.plan(20_000).path().cloned().expect("path found");
+        assert_eq!(rrt.validate_path(&path), Ok(()));
+        let lengths = path.segment_lengths();
+        assert!(lengths.iter().any(|&l| l > 20.0) && lengths.iter().all(|&l| l <= 30.0 + 1e-3));
+
+        let config = RRTConfig::from_toml_str("step_policy = { type = \"clearance_scaled\", min = 1.0, max = 25.0 }").expect("config parses");
+        assert_eq!(RRT::from_config(&config).step_policy, StepPolicy::ClearanceScaled { min: 1.0, max: 25.0 });
+    }
+
+    #[test]
+    fn step_reports_each_outcome_for_scripted_samples() {
+        let p = |x: f32, y: f32| Point { x, y };
+        let scripted = |samples: Vec<Point>| -> RRT {
+            let mut rrt: RRT = RRT::new(p(100.0, 100.0), p(115.0, 100.0), 10.0, 2.0);
+            rrt.obstacles.push(Obstacle::Circle { center: p(100.0, 110.0), radius: 4.0 });
+            rrt.sample_source = Some(Box::new(samples.into_iter()));
+            rrt
+        };
+        let mut rrt = scripted(vec![p(100.0, 50.0), p(100.0, 121.0), p(115.0, 100.0), p(115.0, 100.0)]);
+        let results: Vec<_> = (0..4).map(|_| rrt.step()).collect();
+        assert_eq!(
+            results,
+            vec![
+                StepResult::Extended { node: 1 },
+                StepResult::Rejected,
+                StepResult::Extended { node: 2 },
+                StepResult::GoalReached { node: 3 },
+            ]
+        );
+        // Hedefe ulaşıldıktan sonra eklenen düğümler yalnızca genişlemedir
+        rrt.sample_source = Some(Box::new(std::iter::once(p(100.0, 60.0))));
+        assert_eq!(rrt.step(), StepResult::Extended { node: 4 });
+
+        let mut rrt = scripted(vec![p(100.0, 50.0), p(100.0, 40.0), p(115.0, 100.0)]);
+        rrt.max_nodes = Some(2);
+        let results: Vec<_> = (0..3).map(|_| rrt.step()).collect();
+        assert_eq!(results, vec![StepResult::Extended { node: 1 }, StepResult::Exhausted, StepResult::Exhausted]);
+    }
+
+    #[test]
+    fn run_stops_at_the_goal_or_after_max_iters() {
+        let p = |x: f32, y: f32| Point { x, y };
+        let mut rrt: RRT = RRT::new(p(100.0, 100.0), p(130.0, 100.0), 10.0, 2.0);
+        rrt.sample_source = Some(Box::new(std::iter::repeat(p(130.0, 100.0))));
+        let results: Vec<_> = rrt.run(10).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results.last(), Some(&StepResult::GoalReached { node: 3 }));
+        // Önceden ulaşılmış hedef yinelemeyi durdurmaz
+        assert_eq!(rrt.run(2).collect::<Vec<_>>(), vec![StepResult::Extended { node: 4 }, StepResult::Extended { node: 5 }]);
+
+        let mut rrt: RRT = RRT::new(p(100.0, 100.0), p(300.0, 100.0), 10.0, 2.0);
+        rrt.sample_source = Some(Box::new(std::iter::repeat(p(100.0, 300.0))));
+        assert_eq!(rrt.run(5).count(), 5);
+        assert_eq!((rrt.iteration, rrt.nodes.len()), (5, 6));
+    }
+
+    // Gözlemci olaylarını paylaşılan listeye yazar; planlayıcı gözlemciyi sahiplendiğinden test
+    // olayları bu liste üzerinden okur
+    #[derive(Clone, Debug, PartialEq)]
+    enum Observed {
+        Sample(Point),
+        NodeAdded(usize, usize),
+        Rejected(Point, RejectReason),
+        GoalReached(usize),
+    }
+
+    struct RecordingObserver(std::sync::Arc<std::sync::Mutex<Vec<Observed>>>);
+
+    impl PlannerObserver for RecordingObserver {
+        fn on_sample(&mut self, sample: &Point) {
+            self.0.lock().unwrap().push(Observed::Sample(*sample));
+        }
+
+        fn on_node_added(&mut self, index: usize, parent: usize) {
+            self.0.lock().unwrap().push(Observed::NodeAdded(index, parent));
+        }
+
+        fn on_rejected(&mut self, point: &Point, reason: RejectReason) {
+            self.0.lock().unwrap().push(Observed::Rejected(*point, reason));
+        }
+
+        fn on_goal_reached(&mut self, index: usize) {
+            self.0.lock().unwrap().push(Observed::GoalReached(index));
+        }
+    }
+
+    #[test]
+    fn observer_events_describe_the_tree_that_is_built() {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 15.0, 15.0);
+        rrt.obstacles = vec![Obstacle::Rect { min: Point { x: 100.0, y: 0.0 }, max: Point { x: 140.0, y: 300.0 } }];
+        rrt.reseed(11);
+        rrt.set_observer(Some(Box::new(RecordingObserver(events.clone()))));
+        assert!(rrt.plan(20_000).path().is_some());
+
+        let recorded = events.lock().unwrap();
+        let added: Vec<(usize, usize)> = recorded
+            .iter()
+            .filter_map(|e| match e {
+                Observed::NodeAdded(index, parent) => Some((*index, *parent)),
+                _ => None,
+            })
+            .collect();
+        let expected: Vec<(usize, usize)> = rrt.nodes.iter().enumerate().skip(1).map(|(i, n)| (i, n.parent.unwrap())).collect();
+        assert_eq!(added, expected);
+        let count = |f: fn(&Observed) -> bool| recorded.iter().filter(|e| f(e)).count();
+        assert_eq!(count(|e| matches!(e, Observed::Sample(_))), rrt.iteration);
+        assert_eq!(count(|e| matches!(e, Observed::Rejected(_, RejectReason::Collision))), rrt.iteration - added.len());
+        assert_eq!(recorded.last(), Some(&Observed::GoalReached(rrt.goal_node.unwrap())));
+        // Her iterasyon bir örnekle başlar ve tek bir ekleme veya ret ile biter
+        for chunk in recorded.split(|e| matches!(e, Observed::Sample(_))).skip(1) {
+            assert!(matches!(chunk, [Observed::NodeAdded(..)] | [Observed::Rejected(..)] | [Observed::NodeAdded(..), Observed::GoalReached(_)]), "{:?}", chunk);
+        }
+
+        // Gözlemci alındıktan sonra planlama bildirim yapmadan sürer
+        let before = recorded.len();
+        drop(recorded);
+        assert!(rrt.take_observer().is_some());
+        rrt.run(10).for_each(drop);
+        assert_eq!(events.lock().unwrap().len(), before);
+    }
+
+    #[test]
+    fn pop_node_removes_only_a_trailing_leaf() {
+        let p = |x: f32, y: f32| Point { x, y };
+        let mut rrt: RRT = RRT::new(p(100.0, 100.0), p(130.0, 100.0), 10.0, 2.0);
+        assert!(rrt.pop_node().is_none(), "roots are never popped");
+        rrt.sample_source = Some(Box::new(std::iter::repeat(p(130.0, 100.0))));
+        assert_eq!(rrt.run(10).last(), Some(StepResult::GoalReached { node: 3 }));
+        let before = rrt.best_cost();
+
+        let popped = rrt.pop_node().expect("the goal node is a leaf");
+        assert_eq!((popped.point, popped.parent), (p(130.0, 100.0), Some(2)));
+        assert_eq!((rrt.nodes.len(), rrt.goal_node, rrt.best_cost()), (3, None, None));
+        assert_eq!((rrt.max_depth, rrt.best_goal_node), (2, 2));
+        assert_rooted_tree(&rrt);
+        assert_eq!(rrt.find_nearest(&p(130.0, 100.0)), 2);
+        // Aynı örnekle hedef yeniden bulunur
+        assert_eq!(rrt.step(), StepResult::GoalReached { node: 3 });
+        assert_eq!(rrt.best_cost(), before);
+
+        // Ebeveyn indeksleri dışarıdan değiştirilip son düğüme çocuk bağlandıysa düğüm çıkarılmaz
+        rrt.nodes[1].parent = Some(3);
+        assert!(rrt.pop_node().is_none());
+        assert_eq!(rrt.nodes.len(), 4);
+    }
+
+    #[test]
+    fn sample_counters_reconcile_with_the_nodes_added() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 15.0, 15.0);
+        rrt.obstacles = vec![
+            Obstacle::Rect { min: Point { x: 100.0, y: 0.0 }, max: Point { x: 140.0, y: 300.0 } },
+            Obstacle::Circle { center: Point { x: 260.0, y: 200.0 }, radius: 60.0 },
+        ];
+        rrt.reseed(5);
+        let stats = *rrt.plan(20_000).stats();
+        assert!(stats.samples_rejected > 0);
+        // Doymuş ağaç ve dal-sınır olmadan her iterasyon bir örneği kontrol eder
+        assert_eq!(stats.samples_total, stats.iterations);
+        assert_eq!(stats.samples_total - stats.samples_rejected, rrt.nodes.len() - 1);
+        assert_close(stats.rejection_rate(), stats.samples_rejected as f32 / stats.samples_total as f32);
+
+        // Düğüm sınırında denenmeyen örnekler toplamda sayılmaz
+        let accepted = rrt.nodes.len() - 1;
+        rrt.max_nodes = Some(rrt.nodes.len());
+        rrt.node_cap_policy = NodeCapPolicy::Saturate;
+        rrt.run(50).for_each(drop);
+        assert_eq!((rrt.samples_total, rrt.samples_total - rrt.samples_rejected), (stats.samples_total, accepted));
+
+        // Daha büyük robotta daha çok örnek reddedilir
+        let rate = |radius: f32| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 15.0, 15.0);
+            rrt.obstacles = vec![Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 50.0 }];
+            rrt.robot_radius = radius;
+            rrt.reseed(5);
+            rrt.run(500).for_each(drop);
+            rrt.plan_stats(rrt.iteration, false).rejection_rate()
+        };
+        assert!(rate(40.0) > rate(0.0), "{} vs {}", rate(40.0), rate(0.0));
+        rrt.reset(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 });
+        assert_eq!((rrt.samples_total, rrt.samples_rejected), (0, 0));
+    }
+
+    // Toplu ve tek tek büyütmeyi karşılaştırmak için engelli, budamalı bir ağaç
+    fn batch_test_tree() -> RRT {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 15.0, 10.0);
+        rrt.obstacles = vec![
+            Obstacle::Rect { min: Point { x: 100.0, y: 0.0 }, max: Point { x: 140.0, y: 300.0 } },
+            Obstacle::Circle { center: Point { x: 260.0, y: 200.0 }, radius: 60.0 },
+        ];
+        rrt.branch_and_bound = true;
+        rrt.branch_and_bound_interval = 7;
+        rrt.max_nodes = Some(150);
+        rrt.node_cap_policy = NodeCapPolicy::Prune;
+        rrt.sampler = SamplerKind::GoalBiased;
+        rrt.goal_bias = 0.1;
+        rrt.reseed(9);
+        rrt
+    }
+
+    fn tree_shape(rrt: &RRT) -> Vec<(Point, Option<usize>)> {
+        rrt.nodes.iter().map(|n| (n.point, n.parent)).collect()
+    }
+
+    #[test]
+    fn batches_of_one_grow_the_same_tree_as_extend() {
+        let mut single = batch_test_tree();
+        let mut batched = batch_test_tree();
+        for _ in 0..3000 {
+            let a = single.extend();
+            let b = batched.extend_batch(1);
+            assert_eq!(b.len(), 1);
+            assert_eq!((a.added, a.new_point, a.full), (b[0].added, b[0].new_point, b[0].full));
+        }
+        assert!(single.goal_node.is_some() && single.bnb_nodes_pruned > 0 && single.nodes_pruned > 0);
+        assert_eq!(tree_shape(&single), tree_shape(&batched));
+        assert_eq!((single.iteration, single.samples_rejected), (batched.iteration, batched.samples_rejected));
+    }
+
+    #[test]
+    fn larger_batches_are_reproducible_and_keep_the_tree_valid() {
+        let grow = || {
+            let mut rrt = batch_test_tree();
+            rrt.max_nodes = None;
+            let results: Vec<StepResult> = (0..50).flat_map(|_| rrt.step_batch(16)).collect();
+            (rrt, results)
+        };
+        let (rrt, results) = grow();
+        let (again, results_again) = grow();
+        assert_eq!(results, results_again);
+        assert_eq!(tree_shape(&rrt), tree_shape(&again));
+        assert_eq!(rrt.iteration, 800);
+        assert_rooted_tree(&rrt);
+        for (i, node) in rrt.nodes.iter().enumerate().skip(1) {
+            let parent = node.parent.unwrap();
+            assert!(parent < i);
+            assert!(rrt.is_edge_collision_free(&rrt.nodes[parent].point, &node.point));
+        }
+        let added = results.iter().filter(|r| matches!(r, StepResult::Extended { .. } | StepResult::GoalReached { .. })).count();
+        assert_eq!(rrt.samples_total - rrt.samples_rejected, added);
+
+        // Düğüm sınırı toplunun ortasında aşılmaz
+        let mut capped = batch_test_tree();
+        capped.max_nodes = Some(20);
+        capped.node_cap_policy = NodeCapPolicy::Saturate;
+        for _ in 0..10 {
+            capped.extend_batch(16);
+            assert!(capped.nodes.len() <= 20);
+        }
+        assert!(capped.saturated);
+
+        let mut planner = batch_test_tree();
+        planner.max_nodes = None;
+        planner.batch_size = 16;
+        let result = planner.plan(20_000);
+        let path = result.path().expect("batched planning finds a path");
+        assert_eq!(planner.validate_path(path), Ok(()));
+        assert_eq!(result.stats().iterations % 16, 0);
+        let config = RRTConfig::from_toml_str("batch_size = 32").expect("config parses");
+        assert_eq!(RRT::from_config(&config).batch_size, 32);
+    }
+
+    #[test]
+    fn max_edge_length_splits_long_edges_into_intermediate_nodes() {
+        let longest_edge = |rrt: &RRT| {
+            let tree = rrt.nodes.iter().filter_map(|n| n.parent.map(|p| rrt.nodes[p].point.distance(&n.point)));
+            let cross = rrt.graph_edges.iter().map(|&(a, b)| rrt.nodes[a].point.distance(&rrt.nodes[b].point));
+            tree.chain(cross).fold(0.0, f32::max)
+        };
+        let grow = |max_edge_length: Option<f32>| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 15.0, 10.0);
+            rrt.obstacles = vec![Obstacle::Rect { min: Point { x: 100.0, y: 0.0 }, max: Point { x: 140.0, y: 300.0 } }];
+            rrt.any_angle = true;
+            rrt.step_policy = StepPolicy::ClearanceScaled { min: 5.0, max: 40.0 };
+            rrt.max_edge_length = max_edge_length;
+            rrt.reseed(4);
+            let path = rrt.plan(20_000).path().cloned().expect("path found");
+            (rrt, path)
+        };
+        let (unbounded, _) = grow(None);
+        assert!(longest_edge(&unbounded) > 40.0);
+
+        let (rrt, path) = grow(Some(12.0));
+        assert!(longest_edge(&rrt) <= 12.0 + 1e-3, "{}", longest_edge(&rrt));
+        assert_rooted_tree(&rrt);
+        assert_eq!(rrt.validate_path(&path), Ok(()));
+
+        // Çapraz kenarlar da sınırı aşmaz
+        let mut rrt: RRT = RRT::new(Point { x: 200.0, y: 200.0 }, Point { x: 1e6, y: 1e6 }, 15.0, 1.0);
+        rrt.cross_edges = true;
+        rrt.cross_edge_radius = 40.0;
+        rrt.max_edge_length = Some(12.0);
+        rrt.reseed(4);
+        rrt.run(300).for_each(drop);
+        assert!(!rrt.graph_edges.is_empty());
+        assert!(longest_edge(&rrt) <= 12.0 + 1e-3, "{}", longest_edge(&rrt));
+
+        // Ara düğümler kenarın üzerinde eşit aralıklıdır
+        let mut rrt: RRT = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 1e6, y: 1e6 }, 100.0, 1.0);
+        rrt.max_edge_length = Some(30.0);
+        rrt.sample_source = Some(Box::new(std::iter::once(Point { x: 100.0, y: 0.0 })));
+        assert_eq!(rrt.extend().added, Some(4));
+        let xs: Vec<f32> = rrt.nodes.iter().map(|n| n.point.x).collect();
+        assert_eq!(xs, vec![0.0, 25.0, 50.0, 75.0, 100.0]);
+        assert_eq!(rrt.nodes[4].depth, 4);
+        assert_eq!(rrt.last_step.map(|step| step.nodes_added), Some(4));
+
+        let config = RRTConfig::from_toml_str("max_edge_length = 8.0").expect("config parses");
+        assert_eq!(RRT::from_config(&config).max_edge_length, Some(8.0));
+        assert!(matches!(RRTConfig::from_toml_str("max_edge_length = 0.0"), Err(ConfigError::Invalid(_))));
+    }
+
+    #[test]
+    fn planners_are_send_and_sync() {
+        fn assert_send_sync<P: Send + Sync>() {}
+        assert_send_sync::<RRT>();
+        assert_send_sync::<RRT<f64>>();
+        assert_send_sync::<PlanResult>();
+        assert_send_sync::<crate::prm::Prm>();
+        assert_send_sync::<crate::grid::GridAstar>();
+    }
+
+    #[test]
+    fn scenarios_plan_concurrently_on_worker_threads() {
+        let scenarios = ["scenarios/cluttered.toml", "scenarios/corridor.toml", "scenarios/example.toml", "scenarios/maze.toml"];
+        let planner = |file: &str| {
+            let mut config = RRTConfig::from_toml_file(file).expect("scenario loads");
+            config.seed = config.seed.or(Some(1));
+            (RRT::from_config(&config), config.max_iters)
+        };
+        let summary = |result: &PlanResult| (result.stats().iterations, result.stats().nodes, result.path().map(Path::length));
+        // Planlayıcılar ana iş parçacığında kurulup işçilere taşınır
+        let handles: Vec<_> = scenarios
+            .iter()
+            .map(|file| {
+                let (mut rrt, max_iters) = planner(file);
+                std::thread::spawn(move || rrt.plan(max_iters))
+            })
+            .collect();
+        let concurrent: Vec<_> = handles.into_iter().map(|h| summary(&h.join().expect("planning thread finishes"))).collect();
+        for (file, result) in scenarios.iter().zip(&concurrent) {
+            assert!(result.2.is_some(), "{} finds a path", file);
+            let (mut rrt, max_iters) = planner(file);
+            assert_eq!(summary(&rrt.plan(max_iters)), *result, "{} plans the same on any thread", file);
+        }
+    }
+
+    #[test]
+    fn path_pose_headings_follow_successive_segments() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 300.0 }, 10.0, 10.0);
+        rrt.reseed(11);
+        assert!(rrt.trace_path_poses().is_empty());
+        rrt.plan(5_000).path().expect("open map is solvable");
+        let path = rrt.trace_path().unwrap();
+        let poses = rrt.trace_path_poses();
+        assert_eq!(poses.len(), path.len());
+        for (i, pose) in poses.iter().enumerate() {
+            assert_eq!(pose.point, path[i]);
+            let (a, b) = if i == 0 { (path[0], path[1]) } else { (path[i - 1], path[i]) };
+            assert_close(pose.heading, (b.y - a.y).atan2(b.x - a.x));
+        }
+
+        // Tekrarlanan ara nokta önceki yönü korur
+        let repeated = Path::from(vec![Point { x: 0.0, y: 0.0 }, Point { x: 0.0, y: 0.0 }, Point { x: 0.0, y: 10.0 }, Point { x: 0.0, y: 10.0 }]);
+        let headings: Vec<f32> = repeated.poses().iter().map(|p| p.heading).collect();
+        assert_eq!(headings, vec![std::f32::consts::FRAC_PI_2; 4]);
+    }
+
+    #[test]
+    fn terrain_weighted_sampling_avoids_expensive_cells() {
+        let mut rrt = empty_tree();
+        rrt.reseed(5);
+        rrt.terrain = Some(CostMap::from_rows(rrt.bounds, &[vec![f32::INFINITY, 1.0]]));
+        rrt.terrain_bias = 1.0;
+        assert!((0..2_000).all(|_| rrt.sample().x >= 200.0));
+        // Karışımda düzgün örneklerin yarısı sol yarıya düşer
+        rrt.terrain_bias = 0.9;
+        let left = (0..10_000).filter(|_| rrt.sample().x < 200.0).count();
+        assert!((400..600).contains(&left), "{} samples on the left", left);
+    }
+
+    #[test]
+    fn uniform_terrain_sampling_matches_the_uniform_distribution() {
+        let mut rrt = empty_tree();
+        rrt.reseed(6);
+        rrt.terrain = Some(CostMap::from_rows(rrt.bounds, &vec![vec![2.0; 4]; 4]));
+        rrt.terrain_bias = 1.0;
+        // Örnekler 8 x 8 hücreye sayılır; ki-kare istatistiği 63 serbestlik derecesinde %99.9
+        // eşiğinin (~103) altında kalmalıdır
+        let (n, cells) = (32_000, 8);
+        let mut counts = vec![0usize; cells * cells];
+        for _ in 0..n {
+            let p = rrt.sample();
+            assert!(rrt.bounds.contains(&p));
+            let col = ((p.x / 400.0 * cells as f32) as usize).min(cells - 1);
+            let row = ((p.y / 400.0 * cells as f32) as usize).min(cells - 1);
+            counts[row * cells + col] += 1;
+        }
+        let expected = n as f32 / (cells * cells) as f32;
+        let chi_square: f32 = counts.iter().map(|&c| (c as f32 - expected).powi(2) / expected).sum();
+        assert!(chi_square < 103.0, "chi-square {}", chi_square);
+    }
+
+    #[test]
+    fn endpoints_inside_obstacles_fail_before_any_iteration() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 300.0, y: 300.0 }, 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 300.0, y: 300.0 }, radius: 30.0 });
+        assert_eq!(rrt.try_plan(10_000).err(), Some(PlanError::GoalUnreachableInObstacle));
+        assert_eq!((rrt.iteration, rrt.nodes.len()), (0, 1));
+
+        // Robot yarıçapı engeli şişirir: engelin hemen dışındaki hedef de ulaşılamaz
+        rrt.reset(Point { x: 20.0, y: 20.0 }, Point { x: 335.0, y: 300.0 });
+        assert!(rrt.check_endpoints().is_ok());
+        rrt.robot_radius = 10.0;
+        assert_eq!(rrt.check_endpoints(), Err(PlanError::GoalUnreachableInObstacle));
+
+        rrt.robot_radius = 0.0;
+        rrt.reset(Point { x: 290.0, y: 310.0 }, Point { x: 20.0, y: 20.0 });
+        assert_eq!(rrt.try_plan(10_000).err(), Some(PlanError::StartInObstacle));
+        assert_eq!(rrt.iteration, 0);
+        rrt.reset(Point { x: 100.0, y: 100.0 }, Point { x: 20.0, y: 20.0 });
+        assert!(rrt.try_plan(10_000).unwrap().path().is_some());
+    }
+
+    #[test]
+    fn potential_field_steers_around_obstacles_and_falls_back_when_the_blend_cancels() {
+        let mut rrt: RRT = empty_tree();
+        rrt.goals = vec![Point { x: 300.0, y: 200.0 }];
+        rrt.potential_field = Some(PotentialField { sample_gain: 1.0, goal_gain: 1.0, obstacle_gain: 0.0, influence_radius: 20.0 });
+        let from = Point { x: 200.0, y: 200.0 };
+        // Örnek yukarıda, hedef sağda: adım iki yönün açıortayındadır ve adım boyundadır
+        let p = rrt.steer(&from, &Point { x: 200.0, y: 300.0 });
+        assert_close(from.distance(&p), rrt.step_size);
+        assert_close(p.x - from.x, p.y - from.y);
+        // Örnek hedefin tam tersindeyken vektörler birbirini sıfırlar; düz yönlendirmeye dönülür
+        assert_eq!(rrt.steer(&from, &Point { x: 100.0, y: 200.0 }), Point { x: 190.0, y: 200.0 });
+        // Yakındaki örnek geçilmez
+        assert!(from.distance(&rrt.steer(&from, &Point { x: 200.0, y: 204.0 })) <= 4.0 + 1e-4);
+        assert_eq!(rrt.steer(&from, &from), from);
+
+        // Hedefe doğru giderken önündeki duvara yaklaşan adım duvardan uzağa saptırılır
+        rrt.potential_field = Some(PotentialField { sample_gain: 1.0, goal_gain: 0.0, obstacle_gain: 1.0, influence_radius: 20.0 });
+        rrt.obstacles = vec![Obstacle::Rect { min: Point { x: 205.0, y: 150.0 }, max: Point { x: 215.0, y: 195.0 } }];
+        let p = rrt.steer(&from, &Point { x: 300.0, y: 180.0 });
+        let plain = State::steer(&from, &Point { x: 300.0, y: 180.0 }, rrt.step_size);
+        assert!(p.y > plain.y, "{:?} should turn away from the wall below {:?}", p, plain);
+        // Etki yarıçapının dışında alan yalnızca örneğe çeker
+        rrt.potential_field = Some(PotentialField { influence_radius: 1.0, ..rrt.potential_field.unwrap() });
+        let p = rrt.steer(&from, &Point { x: 300.0, y: 180.0 });
+        assert_close(p.x, plain.x);
+        assert_close(p.y, plain.y);
+    }
+
+    #[test]
+    fn forest_reports_each_root_and_restricts_nearest_queries_to_a_root() {
+        let starts = vec![Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 20.0 }, Point { x: 20.0, y: 380.0 }];
+        let goal = Point { x: 300.0, y: 300.0 };
+        for kind in [NeighborIndexKind::Linear, NeighborIndexKind::Grid, NeighborIndexKind::KdTree] {
+            let mut rrt: RRT = RRT::new(starts[0], goal, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Rect { min: Point { x: 100.0, y: 180.0 }, max: Point { x: 400.0, y: 220.0 } });
+            rrt.set_neighbor_index(kind, 20.0);
+            rrt.set_starts(starts.clone());
+            rrt.forest_growth = ForestGrowth::RoundRobin;
+            rrt.sampler = SamplerKind::GoalBiased;
+            rrt.goal_bias = 0.1;
+            rrt.reseed(8);
+            for _ in 0..3000 {
+                rrt.extend();
+            }
+            // Her düğüm, ebeveyn zincirinin bittiği kökün işaretini taşır
+            for (i, node) in rrt.nodes.iter().enumerate() {
+                let mut current = i;
+                while let Some(parent) = rrt.nodes[current].parent {
+                    current = parent;
+                }
+                assert_eq!(current, node.root, "node {} under {:?}", i, kind);
+            }
+            // Sıralı büyümede her ağaç aynı sayıda örnek alır; engelsiz köşelerdeki ağaçlar dengeli büyür
+            let sizes: Vec<usize> = (0..3).map(|root| rrt.nodes.iter().filter(|n| n.root == root).count()).collect();
+            assert!(sizes.iter().all(|&n| n > 500), "{:?}", sizes);
+
+            let mut rng = StdRng::seed_from_u64(1);
+            for _ in 0..100 {
+                let q = Point { x: rng.gen_range(0.0..400.0), y: rng.gen_range(0.0..400.0) };
+                for root in 0..3 {
+                    let nearest = rrt.find_nearest_in_root(&q, root);
+                    assert_eq!(rrt.nodes[nearest].root, root);
+                    let best = rrt.nodes.iter().filter(|n| n.root == root).map(|n| n.point.distance(&q)).fold(f32::INFINITY, f32::min);
+                    assert_eq!(rrt.nodes[nearest].point.distance(&q), best);
+                }
+            }
+
+            let reports = rrt.root_reports();
+            assert_eq!(reports.iter().map(|r| (r.root, r.start)).collect::<Vec<_>>(), vec![(0, starts[0]), (1, starts[1]), (2, starts[2])]);
+            // Üç kök de büyük ağaçlarla hedefe ulaşır; her yol kendi kökünden başlar
+            for report in &reports {
+                let path = report.path.as_ref().expect("every root reaches the goal");
+                assert_eq!(path[0], report.start);
+                assert!(rrt.in_goal_region(&path[path.len() - 1]));
+                assert!(report.reached_at.is_some_and(|at| at <= rrt.iteration));
+                let cheapest = rrt
+                    .nodes
+                    .iter()
+                    .filter(|n| n.root == report.root && rrt.in_goal_region(&n.point))
+                    .map(|n| n.cost)
+                    .fold(f32::INFINITY, f32::min);
+                assert_eq!(report.cost, Some(cheapest));
+            }
+            // Duvarın sol ucundan dolanmak zorunda olan sağ alt köşe en pahalıdır
+            assert!(reports[1].cost > reports[2].cost);
+
+            rrt.reset(starts[0], goal);
+            assert!(rrt.root_reports().iter().all(|r| r.reached_at.is_none() && r.path.is_none()));
+        }
+
+        let config = RRTConfig::from_toml_file("scenarios/depots.toml").expect("scenario loads");
+        let rrt = RRT::from_config(&config);
+        assert_eq!((rrt.starts, rrt.forest_growth), (starts, ForestGrowth::RoundRobin));
+        assert_eq!(rrt.nodes.iter().map(|n| n.root).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn resumed_sessions_continue_exactly_like_an_uninterrupted_run() {
+        let mut config = RRTConfig::from_toml_file("scenarios/example.toml").expect("scenario loads");
+        config.extra_starts = vec![Point { x: 380.0, y: 20.0 }];
+        config.forest_growth = ForestGrowth::RoundRobin;
+        config.cross_edges = true;
+        config.dynamic_domain = true;
+        config.neighbor_index = NeighborIndexKind::KdTree;
+        config.terrain = Some(TerrainSpec::Grid { costs: vec![vec![1.0, 4.0], vec![2.0, 1.0]] });
+        let snapshot = |rrt: &RRT| {
+            (format!("{:?}", rrt.nodes), rrt.iteration, rrt.goal_node, rrt.best_cost(), rrt.graph_edges.clone(), rrt.samples_total, rrt.domain_resamples, rrt.root_goal_iterations.clone())
+        };
+
+        let mut straight = RRT::from_config(&config);
+        for _ in 0..200 {
+            straight.extend();
+        }
+        let mut first = RRT::from_config(&config);
+        for _ in 0..100 {
+            first.extend();
+        }
+        let path = std::env::temp_dir().join(format!("rrt_session_{}.toml", std::process::id()));
+        first.save_session(&path).expect("session saves");
+        let mut resumed = RRT::load_session(&path).expect("session loads");
+        std::fs::remove_file(&path).ok();
+        assert_eq!(snapshot(&resumed), snapshot(&first));
+        for _ in 0..100 {
+            resumed.extend();
+        }
+        assert_eq!(snapshot(&resumed), snapshot(&straight));
+        assert_eq!(resumed.trace_path(), straight.trace_path());
+        // Üretecin dizisi de aynı yerden sürer
+        assert_eq!(resumed.sample(), straight.sample());
+
+        // Parametreler yapılandırmaya geri yazılır
+        let restored = resumed.to_config();
+        assert_eq!((restored.start, restored.extra_starts, restored.neighbor_index), (config.start, config.extra_starts, NeighborIndexKind::KdTree));
+        assert_eq!(restored.obstacles.len(), config.obstacles.len());
+    }
+
+    #[test]
+    fn inverse_clearance_cost_centers_the_path_in_a_corridor() {
+        let mut config = RRTConfig::from_toml_file("scenarios/corridor.toml").expect("scenario loads");
+        config.clearance_cost = ClearanceCost::Inverse;
+        let mut rrt = RRT::from_config(&config);
+        // Orta noktası duvardan 20 uzakta olan 30 uzunluğundaki kenar: ceza 30 / 20
+        let (a, b) = (Point { x: 100.0, y: 50.0 }, Point { x: 100.0, y: 80.0 });
+        assert!((rrt.clearance_penalty(&a, &b) - 1.5).abs() < 1e-4);
+        rrt.clearance_weight = 10.0;
+        assert!((rrt.edge_cost(&a, &b) - 30.0 * (1.0 + 10.0 / 20.0)).abs() < 1e-3);
+
+        let mean_clearance = |config: &RRTConfig| {
+            let mut rrt = RRT::from_config(config);
+            let (start, goal) = (rrt.nodes[0].point, rrt.goal());
+            let mut total = 0.0;
+            for seed in 0..5 {
+                rrt.reseed(seed);
+                rrt.reset(start, goal);
+                let path = rrt.plan(config.max_iters).path().expect("path found").clone();
+                total += path_clearance(&path, &rrt.obstacles);
+            }
+            total / 5.0
+        };
+        let hugging = mean_clearance(&config);
+        config.clearance_weight = 20.0;
+        let centered = mean_clearance(&config);
+        assert!(centered > 10.0 && centered > 5.0 * hugging, "{} vs {}", centered, hugging);
+    }
+
+    #[test]
+    fn node_iterator_yields_points_and_parents_in_insertion_order() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        rrt.reseed(5);
+        rrt.plan(300);
+        let walked: Vec<_> = rrt.nodes().collect();
+        assert_eq!(rrt.nodes().len(), rrt.nodes.len());
+        assert_eq!(walked.len(), rrt.nodes.len());
+        for (i, (point, parent)) in walked.into_iter().enumerate() {
+            assert_eq!((*point, parent), (rrt.nodes[i].point, rrt.nodes[i].parent));
+            assert!(parent.is_none_or(|p| p < i));
+        }
+        assert_eq!(rrt.nodes().next(), Some((&Point { x: 20.0, y: 20.0 }, None)));
+    }
+
+    #[test]
+    fn coverage_counts_free_cells_incrementally_and_approaches_full_coverage() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        rrt.bounds = Bounds { min_x: 0.0, max_x: 400.0, min_y: 0.0, max_y: 400.0 };
+        assert_eq!(rrt.coverage_fraction(), None);
+        // Engel 40 birimlik hücrelerden tam dördünün merkezini örter
+        rrt.obstacles.push(Obstacle::Rect { min: Point { x: 160.0, y: 160.0 }, max: Point { x: 240.0, y: 240.0 } });
+        rrt.set_coverage_resolution(10);
+        let grid = rrt.coverage.as_ref().unwrap();
+        assert_eq!((grid.free_cells(), grid.covered_free_cells()), (96, 1));
+        assert!(!grid.is_free(4, 4) && grid.is_free(3, 4));
+
+        rrt.reseed(2);
+        let mut previous = 1;
+        for _ in 0..4000 {
+            rrt.extend();
+            let covered = rrt.coverage.as_ref().unwrap().covered_free_cells();
+            assert!(covered == previous || covered == previous + 1);
+            previous = covered;
+        }
+        let grid = rrt.coverage.as_ref().unwrap();
+        let recount = (0..10).flat_map(|r| (0..10).map(move |c| (c, r))).filter(|&(c, r)| grid.is_free(c, r) && grid.is_covered(c, r)).count();
+        assert_eq!(recount, previous);
+        assert!(rrt.coverage_fraction().unwrap() > 0.95, "{:?}", rrt.coverage_fraction());
+
+        rrt.reset(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 });
+        assert_close(rrt.coverage_fraction().unwrap(), 1.0 / 96.0);
+    }
+
+    #[test]
+    fn goal_connection_is_attempted_only_every_interval_iterations() {
+        // Duvar hedefe giden düz kenarları kapatır; denemeler her seferinde başarısız olur
+        let blocked = || {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Rect { min: Point { x: 190.0, y: 0.0 }, max: Point { x: 210.0, y: 390.0 } });
+            rrt.reseed(4);
+            rrt
+        };
+        let mut rrt = blocked();
+        rrt.goal_connect_interval = 3;
+        let mut fired = Vec::new();
+        for iteration in 1..=12 {
+            let before = (rrt.goal_connect_attempts, rrt.lazy_stats.checks_performed);
+            rrt.extend();
+            if rrt.goal_connect_attempts > before.0 {
+                fired.push(iteration);
+                assert!(rrt.lazy_stats.checks_performed > before.1);
+            }
+        }
+        assert_eq!(fired, vec![3, 6, 9, 12]);
+        assert!(rrt.goal_node.is_none());
+
+        // Aralık 1 her iterasyonda dener, sıfır hiç denemez ve ağacı değiştirmez
+        let mut every = blocked();
+        every.goal_connect_interval = 1;
+        let mut never = blocked();
+        for _ in 0..10 {
+            every.extend();
+            never.extend();
+        }
+        assert_eq!((every.goal_connect_attempts, never.goal_connect_attempts), (10, 0));
+        let mut plain = blocked();
+        plain.plan(10);
+        assert_eq!(never.nodes.len(), plain.nodes.len());
+
+        // Açık haritada ilk denemede kökten hedefe bağlanılır
+        let mut open: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        open.goal_connect_interval = 1;
+        open.reseed(1);
+        assert!(matches!(open.step(), StepResult::GoalReached { .. }));
+        let goal = open.goal_node.unwrap();
+        assert_eq!(open.nodes[goal].point, Point { x: 380.0, y: 380.0 });
+        assert_eq!(open.goal_connect_attempts, 1);
+        let mut reset = open;
+        reset.reset(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 });
+        assert_eq!(reset.goal_connect_attempts, 0);
+    }
+
+    #[test]
+    fn statistics_report_depth_and_edge_length_distributions_of_a_hand_built_tree() {
+        // 0 ─ 1 ─ 3 ─ 4
+        //  └─ 2
+        // Kenar uzunlukları 10, 4, 5 ve 3
+        let p = |x: f32, y: f32| Point { x, y };
+        let mut rrt = empty_tree();
+        for (point, parent) in [(p(210.0, 200.0), 0), (p(200.0, 204.0), 0), (p(210.0, 205.0), 1), (p(210.0, 208.0), 3)] {
+            rrt.add_node(point, parent);
+        }
+        let stats = rrt.statistics();
+        assert_eq!(stats.nodes, 5);
+        assert_eq!(stats.shape, rrt.stats());
+        assert_eq!(stats.depth_histogram, vec![1, 2, 1, 1]);
+        assert_eq!(stats.depth_histogram.iter().sum::<usize>(), stats.nodes);
+        assert_close(stats.min_edge_length, 3.0);
+        assert_close(stats.mean_edge_length, 5.5);
+        assert_close(stats.max_edge_length, 10.0);
+        // Kutular 1 birim genişliğinde; en uzun kenar son kutuya düşer
+        assert_eq!(stats.edge_length_histogram, vec![0, 0, 0, 1, 1, 1, 0, 0, 0, 1]);
+
+        // Tek köklü ağaçta kenar yoktur
+        let stats = empty_tree().statistics();
+        assert_eq!((stats.nodes, stats.depth_histogram, stats.edge_length_histogram), (1, vec![1], vec![]));
+        assert_eq!((stats.min_edge_length, stats.mean_edge_length, stats.max_edge_length), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn cost_limit_rejects_extensions_over_budget_and_requires_goal_within_budget() {
+        // Duvarın üstündeki tek geçitten geçen en kısa yol ~414, hedef eşiği düşülünce ~404 uzunluğundadır
+        let walled = |limit: f32| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 10.0, 10.0);
+            rrt.obstacles.push(Obstacle::Rect { min: Point { x: 190.0, y: 0.0 }, max: Point { x: 210.0, y: 300.0 } });
+            rrt.max_path_cost = Some(limit);
+            rrt.goal_connect_interval = 10;
+            rrt.reseed(3);
+            rrt
+        };
+        let mut tight = walled(400.0);
+        assert!(matches!(tight.plan(5000), PlanResult::NotFound { .. }));
+        assert!(tight.cost_limit_rejections > 0);
+        assert!(tight.nodes.iter().all(|n| n.cost <= 400.0));
+
+        let mut generous = walled(1000.0);
+        let PlanResult::Found { path, .. } = generous.plan(20_000) else { panic!("no path within a generous budget") };
+        assert!(generous.best_cost().unwrap() <= 1000.0 && path.length() > 404.0);
+
+        // Bütçeyi aşan düğüm hedefe değse bile hedefe ulaşılmış sayılmaz
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 100.0, y: 20.0 }, 10.0, 10.0);
+        rrt.max_path_cost = Some(50.0);
+        rrt.add_node(Point { x: 100.0, y: 20.0 }, 0);
+        assert!(rrt.goal_node.is_none());
+        rrt.add_node(Point { x: 60.0, y: 20.0 }, 0);
+        rrt.max_path_cost = Some(90.0);
+        rrt.add_node(Point { x: 95.0, y: 20.0 }, 2);
+        assert_eq!(rrt.goal_node, Some(3));
+    }
+
+    #[test]
+    fn binary_tree_round_trip_reconstructs_a_large_tree() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        let mut rng = StdRng::seed_from_u64(8);
+        for i in 1..100_000 {
+            rrt.add_node(Point { x: rng.gen_range(0.0..400.0), y: rng.gen_range(0.0..400.0) }, rng.gen_range(0..i));
+        }
+        let mut bytes = Vec::new();
+        rrt.write_binary(&mut bytes).unwrap();
+        let loaded = RRT::read_binary(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.nodes.len(), rrt.nodes.len());
+        for (a, b) in loaded.nodes.iter().zip(&rrt.nodes) {
+            assert_eq!((a.point, a.parent, a.cost.to_bits(), a.depth, a.root), (b.point, b.parent, b.cost.to_bits(), b.depth, b.root));
+        }
+        assert_eq!((loaded.goal_node, loaded.best_cost()), (rrt.goal_node, rrt.best_cost()));
+        assert_eq!((loaded.goal(), loaded.step_size, loaded.obstacles.len()), (rrt.goal(), rrt.step_size, rrt.obstacles.len()));
+
+        // Bozuk başlık ve kesik kayıtlar açık bir hatayla reddedilir
+        assert!(matches!(RRT::read_binary(&b"JSON"[..]), Err(ConfigError::Invalid(_))));
+        assert!(matches!(RRT::read_binary(&bytes[..bytes.len() - 5]), Err(ConfigError::Invalid(_))));
+        let mut orphan = bytes.clone();
+        let last = orphan.len() - 4;
+        orphan[last..].copy_from_slice(&i32::MAX.to_le_bytes());
+        assert!(matches!(RRT::read_binary(orphan.as_slice()), Err(ConfigError::Invalid(_))));
+    }
+
+    #[test]
+    fn traced_paths_stay_valid_after_goal_connections_and_a_smaller_step() {
+        // Düz hedef bağlantısı adım boyundan uzun bir kenar ekler
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        rrt.goal_connect_interval = 1;
+        rrt.reseed(1);
+        let PlanResult::Found { path, .. } = rrt.plan(100) else { panic!("no path") };
+        assert!(path.windows(2).any(|s| s[0].distance(&s[1]) > 10.0));
+        assert_eq!(rrt.validate_path(&path), Ok(()));
+        assert!(rrt.trace_path().is_some());
+
+        // Adım boyu küçültülünce var olan uzun kenarlar geçerli kalır
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 20.0, 10.0);
+        rrt.reseed(2);
+        assert!(matches!(rrt.plan(20_000), PlanResult::Found { .. }));
+        rrt.set_step_size(5.0);
+        let path = rrt.trace_path().expect("goal was reached");
+        assert_eq!(rrt.validate_path(&path), Ok(()));
+        // Ağacın kuramayacağı kadar uzun dış parçalar yine reddedilir
+        let far = [rrt.starts[0], Point { x: 375.0, y: 375.0 }];
+        assert!(matches!(rrt.validate_path(&far), Err(PathError::SegmentTooLong { index: 0, .. })));
+    }
+
+    #[test]
+    fn goal_connections_follow_the_same_admission_as_extensions() {
+        // Düğüm sınırı dolunca hedef bağlantısı denenmez
+        let mut capped: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+        capped.goal_connect_interval = 1;
+        capped.max_nodes = Some(2);
+        capped.reseed(1);
+        capped.plan(5);
+        assert_eq!((capped.nodes.len(), capped.goal_connect_attempts), (2, 0));
+        assert!(capped.goal_node.is_none());
+
+        // Reddedilen genişlemeden sonra bağlantı denenmez
+        let mut walled: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 10.0, 10.0);
+        walled.obstacles.push(Obstacle::Rect { min: Point { x: 190.0, y: 0.0 }, max: Point { x: 210.0, y: 390.0 } });
+        walled.goal_connect_interval = 1;
+        walled.reseed(4);
+        for _ in 0..200 {
+            let (nodes, attempts) = (walled.nodes.len(), walled.goal_connect_attempts);
+            walled.extend();
+            assert_eq!(walled.goal_connect_attempts > attempts, walled.nodes.len() > nodes);
+        }
+        assert!(walled.samples_rejected > 0);
+
+        // Hedef düğümü de çapraz kenarlarla görünür komşularına bağlanır
+        let mut graph: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 40.0, y: 20.0 }, 10.0, 1.0);
+        graph.goal_connect_interval = 1;
+        graph.cross_edges = true;
+        graph.cross_edge_radius = 50.0;
+        graph.reseed(3);
+        assert!(matches!(graph.step(), StepResult::GoalReached { .. }));
+        let goal = graph.goal_node.unwrap();
+        assert_eq!(graph.nodes[goal].parent, Some(1));
+        assert!(graph.graph_edges.contains(&(0, goal)));
+    }
+
+    #[test]
+    fn growing_the_robot_radius_prunes_edges_that_now_collide() {
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 10.0, 10.0);
+        rrt.obstacles.push(Obstacle::Circle { center: Point { x: 200.0, y: 200.0 }, radius: 40.0 });
+        rrt.reseed(6);
+        rrt.plan(3000);
+        let before = rrt.nodes.len();
+        let removed = rrt.set_robot_radius(15.0);
+        assert!(removed > 0);
+        assert_eq!(rrt.nodes.len(), before - removed);
+        assert_eq!(rrt.robot_radius(), 15.0);
+        for (point, parent) in rrt.nodes() {
+            if let Some(parent) = parent {
+                assert!(rrt.is_edge_collision_free(&rrt.nodes[parent].point, point));
+            }
+        }
+        // Küçülen yarıçap kenarları bozmaz; negatif değer sıfır olur
+        assert_eq!(rrt.set_robot_radius(5.0), 0);
+        assert_eq!(rrt.set_robot_radius(-1.0), 0);
+        assert_eq!((rrt.nodes.len(), rrt.robot_radius()), (before - removed, 0.0));
+    }
+
+    #[test]
+    fn is_path_valid_reports_the_first_segment_through_an_obstacle() {
+        let mut rrt = empty_tree();
+        rrt.obstacles.push(Obstacle::Rect { min: Point { x: 150.0, y: 150.0 }, max: Point { x: 250.0, y: 250.0 } });
+        let p = |x: f32, y: f32| Point { x, y };
+        // Engelin çevresinden dolaşan yol
+        let clean = [p(20.0, 20.0), p(300.0, 20.0), p(300.0, 300.0), p(380.0, 380.0)];
+        assert_eq!(rrt.is_path_valid(&clean), Ok(()));
+        // Yalnızca üçüncü parça engelin içinden geçer
+        let clipping = [p(20.0, 20.0), p(100.0, 20.0), p(100.0, 200.0), p(300.0, 200.0), p(300.0, 300.0)];
+        assert_eq!(rrt.is_path_valid(&clipping), Err(2));
+        // Alan dışına çıkan parça da geçersizdir
+        assert_eq!(rrt.is_path_valid(&[p(20.0, 20.0), p(-50.0, 20.0)]), Err(0));
+        assert_eq!(rrt.is_path_valid(&[p(200.0, 200.0)]), Err(0));
+    }
+
+    #[test]
+    fn goal_node_survives_nodes_added_after_the_goal_is_reached() {
+        let mut rrt = RRT::new(Point { x: 0.0, y: 0.0 }, Point { x: 30.0, y: 0.0 }, 10.0, 5.0);
+        rrt.add_node(Point { x: 10.0, y: 0.0 }, 0);
+        rrt.add_node(Point { x: 20.0, y: 0.0 }, 1);
+        rrt.add_node(Point { x: 28.0, y: 0.0 }, 2);
+        assert_eq!(rrt.goal_node, Some(3));
+        // Hedefe ulaşıldıktan sonra eklenen düğümler, hedef bölgesindekiler de dahil, hedef düğümünü değiştirmez
+        rrt.add_node(Point { x: 29.0, y: 1.0 }, 3);
+        rrt.add_node(Point { x: 0.0, y: 10.0 }, 0);
+        rrt.add_node(Point { x: 0.0, y: 20.0 }, 5);
+        assert_eq!(rrt.goal_node, Some(3));
+        let path = rrt.trace_path().expect("goal was reached");
+        assert_eq!(path.to_vec(), vec![Point { x: 0.0, y: 0.0 }, Point { x: 10.0, y: 0.0 }, Point { x: 20.0, y: 0.0 }, Point { x: 28.0, y: 0.0 }]);
+        assert_ne!(path.to_vec().last(), Some(&rrt.node(rrt.node_count() - 1).point));
+    }
+
+    #[test]
+    fn any_angle_shortcuts_to_visible_ancestors_and_not_through_walls() {
+        let plan = |any_angle: bool| {
+            let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 20.0 }, Point { x: 380.0, y: 380.0 }, 10.0, 10.0);
+            rrt.any_angle = any_angle;
+            rrt.reseed(6);
+            let path = rrt.plan(20_000).path().cloned().expect("empty map has a path");
+            assert_eq!(rrt.validate_path(&path), Ok(()));
+            path.len() - 1
+        };
+        let (shortcut, plain) = (plan(true), plan(false));
+        // Boş haritada her yeni düğüm köke kadar kısayol bulur
+        assert!(shortcut <= 3 && shortcut < plain, "{} segments with any-angle, {} without", shortcut, plain);
+
+        // Zikzaklı bir zincirin ucuna eklenen nokta görebildiği en uzak ataya bağlanır
+        let mut rrt = RRT::new(Point { x: 0.0, y: 50.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+        rrt.add_node(Point { x: 10.0, y: 60.0 }, 0);
+        rrt.add_node(Point { x: 20.0, y: 40.0 }, 1);
+        rrt.add_node(Point { x: 30.0, y: 60.0 }, 2);
+        let new_point = Point { x: 40.0, y: 50.0 };
+        assert_eq!(rrt.farthest_visible_ancestor(3, &new_point), 0);
+        // Kök ile yeni nokta arasındaki duvar kısayolu bir sonraki ataya düşürür
+        rrt.obstacles = vec![Obstacle::Rect { min: Point { x: 5.0, y: 45.0 }, max: Point { x: 7.0, y: 52.0 } }];
+        assert_eq!(rrt.farthest_visible_ancestor(3, &new_point), 1);
+        rrt.any_angle_depth = 1;
+        assert_eq!(rrt.farthest_visible_ancestor(3, &new_point), 2);
+    }
+
+    #[test]
+    fn lazy_validation_prunes_the_blocked_branch_and_falls_back_to_a_clear_one() {
+        let mut rrt = RRT::new(Point { x: 0.0, y: 50.0 }, Point { x: 100.0, y: 50.0 }, 10.0, 5.0);
+        rrt.lazy_collision = true;
+        // Duvarı kesen dal önce hedefe ulaşır, duvarın üstünden dolaşan dal da hedef bölgesindedir
+        rrt.add_node(Point { x: 40.0, y: 50.0 }, 0);
+        rrt.add_node(Point { x: 60.0, y: 50.0 }, 1);
+        rrt.add_node(Point { x: 98.0, y: 50.0 }, 2);
+        rrt.add_node(Point { x: 30.0, y: 90.0 }, 0);
+        rrt.add_node(Point { x: 70.0, y: 90.0 }, 4);
+        rrt.add_node(Point { x: 97.0, y: 52.0 }, 5);
+        for node in &mut rrt.nodes[1..] {
+            node.edge_checked = false;
+        }
+        rrt.obstacles = vec![Obstacle::Rect { min: Point { x: 45.0, y: 0.0 }, max: Point { x: 55.0, y: 80.0 } }];
+        assert_eq!(rrt.goal_node, Some(3));
+
+        assert_eq!(rrt.validate_goal_path(), vec![2]);
+        assert_eq!(rrt.node_count(), 5);
+        assert_rooted_tree(&rrt);
+        assert_eq!(rrt.goal_node, Some(4));
+        assert!(rrt.nodes[2..].iter().all(|n| n.edge_checked));
+        let path = rrt.trace_path().expect("the detour still reaches the goal");
+        assert_eq!(rrt.validate_path(&path), Ok(()));
+        assert_eq!(rrt.lazy_stats.subtrees_pruned, 1);
+        // Doğrulanmış yol ikinci kez kontrol edilmez
+        let performed = rrt.lazy_stats.checks_performed;
+        assert!(rrt.validate_goal_path().is_empty());
+        assert_eq!(rrt.lazy_stats.checks_performed, performed);
+
+        // Planlama sırasında budanan ağaç da yalnızca geçerli bir yol döndürür
+        let mut rrt: RRT = RRT::new(Point { x: 20.0, y: 200.0 }, Point { x: 380.0, y: 200.0 }, 15.0, 10.0);
+        rrt.obstacles = vec![Obstacle::Rect { min: Point { x: 190.0, y: 0.0 }, max: Point { x: 210.0, y: 320.0 } }];
+        rrt.lazy_collision = true;
+        rrt.reseed(9);
+        let path = rrt.plan(50_000).path().cloned().expect("lazy planning finds a path");
+        assert_eq!(rrt.validate_path(&path), Ok(()));
+        assert!(rrt.lazy_stats.checks_deferred > 0);
+        assert_rooted_tree(&rrt);
+    }
+
+    #[test]
+    fn paths_grazing_an_obstacle_become_invalid_once_the_robot_has_a_radius() {
+        let mut rrt = empty_tree();
+        rrt.obstacles = vec![Obstacle::Circle { center: Point { x: 100.0, y: 100.0 }, radius: 20.0 }];
+        // Daireden 3 piksel uzaktan geçen yol
+        let path = [Point { x: 60.0, y: 123.0 }, Point { x: 100.0, y: 123.0 }, Point { x: 140.0, y: 123.0 }];
+        assert_eq!(rrt.is_path_valid(&path), Ok(()));
+        rrt.set_robot_radius(2.0);
+        assert_eq!(rrt.is_path_valid(&path), Ok(()));
+        rrt.set_robot_radius(5.0);
+        assert_eq!(rrt.is_path_valid(&path), Err(0));
+        assert!(!rrt.is_collision_free(&path[1]));
+        assert!(rrt.is_collision_free(&Point { x: 100.0, y: 126.0 }));
+    }
+
+    #[test]
+    fn tree_bounds_enclose_exactly_the_nodes() {
+        let mut rrt = RRT::new(Point { x: 50.0, y: 40.0 }, Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+        let bounds = rrt.tree_bounds();
+        // Tek düğümlü ağacın kutusu köke daralır
+        assert_eq!((bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y), (50.0, 50.0, 40.0, 40.0));
+        rrt.add_node(Point { x: 10.0, y: 70.0 }, 0);
+        rrt.add_node(Point { x: 90.0, y: 55.0 }, 1);
+        let bounds = rrt.tree_bounds();
+        assert_eq!((bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y), (10.0, 90.0, 40.0, 70.0));
+        assert!(rrt.nodes().all(|(p, _)| bounds.contains(p)));
+    }
+}
@@ -0,0 +1,40 @@
+// Planlayıcının koordinat tipi. Görselleştirme f32 kullanır; büyük koordinatlı dünyalarda
+// (ör. UTM metreleri) f32 hassasiyeti yetmediğinden kütüphane f64 ile de kullanılabilir.
+use std::fmt::{Debug, Display};
+
+use num_traits::{Float, NumAssign, ToPrimitive};
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+
+pub trait Scalar: Float + NumAssign + SampleUniform + Default + Debug + Display + Send + Sync + 'static {
+    // Sabitleri ve sayaçları koordinat tipine çevirir
+    fn cast<N: ToPrimitive>(n: N) -> Self {
+        <Self as num_traits::NumCast>::from(n).expect("value representable as a float")
+    }
+
+    // [0, 1) aralığında düzgün dağılımlı sayı; f32 için önceki sürümlerle aynı sayı dizisini üretir
+    fn unit<R: Rng + ?Sized>(rng: &mut R) -> Self;
+
+    // Çizim ve örnek yoğunluğu haritası gibi f32 çalışan yardımcılar için
+    fn as_f32(self) -> f32;
+}
+
+impl Scalar for f32 {
+    fn unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    fn as_f32(self) -> f32 {
+        self
+    }
+}
+
+impl Scalar for f64 {
+    fn unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.gen()
+    }
+
+    fn as_f32(self) -> f32 {
+        self as f32
+    }
+}
@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+// Öncelik kuyruğunda en küçük tahmini maliyeti öne alan kayıt
+struct Entry {
+    estimate: f32,
+    index: usize,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    // BinaryHeap en büyüğü verdiğinden karşılaştırma ters çevrilir; eşitlikte küçük indeks önce gelir
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .total_cmp(&self.estimate)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+// `node_count` düğümlü bir grafta A* araması yapar. `neighbors` bir düğümün komşularını
+// kenar ağırlıklarıyla birlikte verir, `heuristic` hedefe kalan maliyetin iyimser tahminidir
+// (sıfır döndüren bir sezgisel Dijkstra'ya eşdeğerdir). Bulunursa düğüm indekslerinden oluşan
+// yolu başlangıçtan hedefe sıralı döndürür.
+pub fn astar<N, H>(node_count: usize, start: usize, goal: usize, mut neighbors: N, heuristic: H) -> Option<Vec<usize>>
+where
+    N: FnMut(usize, &mut Vec<(usize, f32)>),
+    H: Fn(usize) -> f32,
+{
+    if start >= node_count || goal >= node_count {
+        return None;
+    }
+    let mut cost = vec![f32::INFINITY; node_count];
+    let mut came_from: Vec<Option<usize>> = vec![None; node_count];
+    let mut closed = vec![false; node_count];
+    let mut open = BinaryHeap::new();
+    let mut buffer = Vec::new();
+
+    cost[start] = 0.0;
+    open.push(Entry { estimate: heuristic(start), index: start });
+
+    while let Some(Entry { index, .. }) = open.pop() {
+        if index == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(previous) = came_from[current] {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if closed[index] {
+            continue;
+        }
+        closed[index] = true;
+
+        buffer.clear();
+        neighbors(index, &mut buffer);
+        for &(next, weight) in &buffer {
+            let candidate = cost[index] + weight;
+            if candidate < cost[next] {
+                cost[next] = candidate;
+                came_from[next] = Some(index);
+                open.push(Entry { estimate: candidate + heuristic(next), index: next });
+            }
+        }
+    }
+    None
+}
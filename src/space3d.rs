@@ -0,0 +1,152 @@
+// Üç boyutlu kutu içinde planlama için alan, engeller ve çarpışma kontrolü. Durumlar `[f32; 3]`
+// dizileridir; ağaç ve en yakın düğüm araması `StateTree` tarafından yapılır.
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::config::ConfigError;
+use crate::state::{State, StateTree};
+
+pub type Point3 = [f32; 3];
+
+// Örnekleme ve çarpışma kontrolü için kutu biçimli çalışma alanı
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct Bounds3 {
+    pub min_x: f32,
+    pub max_x: f32,
+    pub min_y: f32,
+    pub max_y: f32,
+    pub min_z: f32,
+    pub max_z: f32,
+}
+
+impl Bounds3 {
+    pub fn contains(&self, p: &Point3) -> bool {
+        self.ranges().iter().zip(p).all(|(&(min, max), &c)| c >= min && c <= max)
+    }
+
+    // Düzgün örneklemede kullanılan eksen aralıkları
+    pub fn ranges(&self) -> [(f32, f32); 3] {
+        [(self.min_x, self.max_x), (self.min_y, self.max_y), (self.min_z, self.max_z)]
+    }
+}
+
+impl Default for Bounds3 {
+    fn default() -> Self {
+        Bounds3 { min_x: 0.0, max_x: 100.0, min_y: 0.0, max_y: 100.0, min_z: 0.0, max_z: 100.0 }
+    }
+}
+
+// Üç boyutlu engeller: küre veya eksen hizalı kutu
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Obstacle3 {
+    Sphere { center: Point3, radius: f32 },
+    Aabb { min: Point3, max: Point3 },
+}
+
+impl Obstacle3 {
+    pub fn contains(&self, p: &Point3) -> bool {
+        match self {
+            Obstacle3::Sphere { center, radius } => center.distance(p) <= *radius,
+            Obstacle3::Aabb { min, max } => (0..3).all(|i| p[i] >= min[i] && p[i] <= max[i]),
+        }
+    }
+}
+
+// İki boyutlu `CollisionChecker`'ın sabit adımlı kontrolünün üç boyutlu karşılığı
+#[derive(Clone, Copy)]
+pub struct CollisionChecker3<'a> {
+    pub bounds: &'a Bounds3,
+    pub obstacles: &'a [Obstacle3],
+    pub resolution: f32,
+}
+
+impl CollisionChecker3<'_> {
+    pub fn is_point_free(&self, p: &Point3) -> bool {
+        self.bounds.contains(p) && !self.obstacles.iter().any(|o| o.contains(p))
+    }
+
+    // Doğru parçasını `resolution` aralıklarla örnekleyerek kontrol eder
+    pub fn is_edge_free(&self, from: &Point3, to: &Point3) -> bool {
+        let steps = (from.distance(to) / self.resolution).ceil().max(1.0) as usize;
+        (0..=steps).all(|i| self.is_point_free(&from.interpolate(to, i as f32 / steps as f32)))
+    }
+}
+
+// Üç boyutlu senaryo dosyası; fare ile seçim olmadığından uç noktalar dosyada verilir
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct Scenario3 {
+    pub start: Point3,
+    pub goal: Point3,
+    pub step_size: f32,
+    pub goal_threshold: f32,
+    pub goal_bias: f32,
+    pub bounds: Bounds3,
+    pub seed: Option<u64>,
+    pub obstacles: Vec<Obstacle3>,
+    pub collision_resolution: f32,
+    pub max_iters: usize,
+    pub iterations_per_frame: usize, // Görselleştirmede çizilen her karede çalışan planlama iterasyonu
+}
+
+impl Default for Scenario3 {
+    fn default() -> Self {
+        Scenario3 {
+            start: [10.0, 10.0, 10.0],
+            goal: [90.0, 90.0, 90.0],
+            step_size: 4.0,
+            goal_threshold: 4.0,
+            goal_bias: 0.05,
+            bounds: Bounds3::default(),
+            seed: None,
+            obstacles: Vec::new(),
+            collision_resolution: 0.5,
+            max_iters: 20_000,
+            iterations_per_frame: 10,
+        }
+    }
+}
+
+impl Scenario3 {
+    pub fn from_toml_str(s: &str) -> Result<Scenario3, ConfigError> {
+        Ok(toml::from_str(s)?)
+    }
+
+    pub fn from_toml_file<P: AsRef<Path>>(path: P) -> Result<Scenario3, ConfigError> {
+        Scenario3::from_toml_str(&fs::read_to_string(path)?)
+    }
+
+    // Senaryonun uç noktaları ve parametreleriyle henüz büyütülmemiş ağaç
+    pub fn tree(&self) -> StateTree<Point3> {
+        let mut tree = StateTree::new(self.start, self.goal, self.bounds.ranges(), self.step_size, self.goal_threshold);
+        tree.goal_bias = self.goal_bias;
+        if let Some(seed) = self.seed {
+            tree.reseed(seed);
+        }
+        tree
+    }
+
+    pub fn checker(&self) -> CollisionChecker3<'_> {
+        CollisionChecker3 { bounds: &self.bounds, obstacles: &self.obstacles, resolution: self.collision_resolution }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_3d_scenario_plans_around_the_sphere() {
+        let scenario = Scenario3::from_toml_file("scenarios/rrt3d.toml").expect("scenario loads");
+        let checker = scenario.checker();
+        let mut tree = scenario.tree();
+        let path = tree.plan(scenario.max_iters, |a, b| checker.is_edge_free(a, b)).expect("path found");
+        assert!(path.last().unwrap().distance(&scenario.goal) < scenario.goal_threshold);
+        assert!(path.windows(2).all(|s| checker.is_edge_free(&s[0], &s[1])));
+        // Düz çizgi kürenin içinden geçer
+        assert!(!checker.is_edge_free(&scenario.start, &scenario.goal));
+    }
+}
@@ -0,0 +1,458 @@
+// En yakın düğüm aramasını doğrusal taramadan hızlandıran uzamsal indeksler
+use std::cmp::Ordering;
+
+use crate::obstacle::Bounds;
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+// Uzaklığı eşit olan adaylardan indeksi küçük olan seçilir; böylece her indeks doğrusal
+// taramayla (ilk en küçük) aynı düğümü döndürür
+fn closer<T: Scalar>(candidate: (T, usize), best: Option<(T, usize)>) -> bool {
+    match best {
+        None => true,
+        Some(best) => candidate.0 < best.0 || (candidate.0 == best.0 && candidate.1 < best.1),
+    }
+}
+
+// (uzaklık, indeks) çiftlerinin sırası: önce uzaklık, eşitlikte indeks
+fn by_distance<T: Scalar>(a: &(T, usize), b: &(T, usize)) -> Ordering {
+    a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal).then(a.1.cmp(&b.1))
+}
+
+// Adayları uzaklığa göre sıralanmış indekslere çevirir; tüm indeksler ve doğrusal tarama aynı
+// sırayı döndürür
+pub(crate) fn sorted_indices<T: Scalar>(mut candidates: Vec<(T, usize)>) -> Vec<usize> {
+    candidates.sort_by(by_distance);
+    candidates.into_iter().map(|(_, index)| index).collect()
+}
+
+// Sıralı ve en fazla `k` elemanlı en iyiler listesine adayı yerleştirir
+fn insert_k_best<T: Scalar>(best: &mut Vec<(T, usize)>, candidate: (T, usize), k: usize) {
+    let position = best.partition_point(|b| by_distance(b, &candidate) == Ordering::Less);
+    if position < k {
+        best.insert(position, candidate);
+        best.truncate(k);
+    }
+}
+
+// Alanı eşit boyutlu hücrelere bölen ızgara; her hücre içine düşen düğümlerin indekslerini tutar.
+// Alan dışındaki noktalar en yakın kenar hücresine yazılır, bu arama sonucunu bozmaz.
+#[derive(Clone, Debug)]
+pub struct SpatialGrid<T = f32> {
+    bounds: Bounds<T>,
+    cell_size: T,
+    cols: usize,
+    rows: usize,
+    cells: Vec<Vec<(usize, Point<T>)>>,
+}
+
+// Hücre sayısına çevrilen değer; negatifler sıfıra, sığmayan değerler en büyük sayıya kırpılır
+fn cell_count<T: Scalar>(v: T) -> usize {
+    v.max(T::zero()).to_usize().unwrap_or(usize::MAX)
+}
+
+impl<T: Scalar> SpatialGrid<T> {
+    pub fn new(bounds: Bounds<T>, cell_size: T) -> Self {
+        let cell_size = cell_size.max(T::epsilon());
+        let cols = cell_count(((bounds.max_x - bounds.min_x) / cell_size).ceil()).max(1);
+        let rows = cell_count(((bounds.max_y - bounds.min_y) / cell_size).ceil()).max(1);
+        SpatialGrid { bounds, cell_size, cols, rows, cells: vec![Vec::new(); cols * rows] }
+    }
+
+    pub fn cell_size(&self) -> T {
+        self.cell_size
+    }
+
+    fn cell_of(&self, p: &Point<T>) -> (usize, usize) {
+        let col = cell_count(((p.x - self.bounds.min_x) / self.cell_size).floor());
+        let row = cell_count(((p.y - self.bounds.min_y) / self.cell_size).floor());
+        (col.min(self.cols - 1), row.min(self.rows - 1))
+    }
+
+    pub fn insert(&mut self, index: usize, point: Point<T>) {
+        let (col, row) = self.cell_of(&point);
+        self.cells[row * self.cols + col].push((index, point));
+    }
+
+    pub fn clear(&mut self) {
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+    }
+
+    // Sorgu hücresinden başlayarak halka halka dışarı doğru tarar. `k`. halka tarandıktan sonra
+    // taranmamış hücrelerdeki her nokta en az `k * cell_size` uzaktadır; bulunan en iyi uzaklık
+    // bunun altındaysa daha uzağa bakmaya gerek yoktur.
+    pub fn nearest(&self, p: &Point<T>) -> Option<usize> {
+        self.nearest_where(p, |_| true)
+    }
+
+    // `keep` koşulunu sağlayan indeksler arasında en yakın nokta; aynı halka taraması
+    pub fn nearest_where<F: Fn(usize) -> bool>(&self, p: &Point<T>, keep: F) -> Option<usize> {
+        let (qc, qr) = self.cell_of(p);
+        let (qc, qr) = (qc as isize, qr as isize);
+        let max_ring = self.cols.max(self.rows) as isize;
+        let mut best: Option<(T, usize)> = None;
+        for ring in 0..=max_ring {
+            for row in (qr - ring)..=(qr + ring) {
+                if row < 0 || row >= self.rows as isize {
+                    continue;
+                }
+                // Halkanın iç kısmı önceki turlarda tarandı; ilk ve son satır dışında yalnızca kenarlar
+                let on_edge_row = row == qr - ring || row == qr + ring;
+                let step = if on_edge_row || ring == 0 { 1 } else { 2 * ring as usize };
+                for col in ((qc - ring)..=(qc + ring)).step_by(step) {
+                    if col < 0 || col >= self.cols as isize {
+                        continue;
+                    }
+                    for &(index, point) in &self.cells[row as usize * self.cols + col as usize] {
+                        let candidate = (point.distance(p), index);
+                        if keep(index) && closer(candidate, best) {
+                            best = Some(candidate);
+                        }
+                    }
+                }
+            }
+            if best.is_some_and(|(d, _)| d < T::cast(ring) * self.cell_size) {
+                break;
+            }
+        }
+        best.map(|(_, index)| index)
+    }
+
+    // `p` noktasına en fazla `r` uzaklıktaki noktalar; yalnızca çemberi çevreleyen kutudaki
+    // hücreler taranır. Sonuç uzaklığa göre sıralı değildir.
+    pub fn within(&self, p: &Point<T>, r: T) -> Vec<(T, usize)> {
+        let mut found = Vec::new();
+        if r < T::zero() || r.is_nan() {
+            return found;
+        }
+        let (min_col, min_row) = self.cell_of(&Point { x: p.x - r, y: p.y - r });
+        let (max_col, max_row) = self.cell_of(&Point { x: p.x + r, y: p.y + r });
+        for row in min_row..=max_row {
+            for &(index, point) in self.cells[row * self.cols + min_col..=row * self.cols + max_col].iter().flatten() {
+                let d = point.distance(p);
+                if d <= r {
+                    found.push((d, index));
+                }
+            }
+        }
+        found
+    }
+
+    // En yakın `k` nokta, uzaklığa göre sıralı. `nearest` gibi halka halka taranır; `k`. en iyi
+    // uzaklık taranmamış hücrelerin alt sınırının altına indiğinde durulur.
+    pub fn k_nearest(&self, p: &Point<T>, k: usize) -> Vec<(T, usize)> {
+        let mut best = Vec::new();
+        if k == 0 {
+            return best;
+        }
+        let (qc, qr) = self.cell_of(p);
+        let (qc, qr) = (qc as isize, qr as isize);
+        let max_ring = self.cols.max(self.rows) as isize;
+        for ring in 0..=max_ring {
+            for row in (qr - ring)..=(qr + ring) {
+                if row < 0 || row >= self.rows as isize {
+                    continue;
+                }
+                let on_edge_row = row == qr - ring || row == qr + ring;
+                let step = if on_edge_row || ring == 0 { 1 } else { 2 * ring as usize };
+                for col in ((qc - ring)..=(qc + ring)).step_by(step) {
+                    if col < 0 || col >= self.cols as isize {
+                        continue;
+                    }
+                    for &(index, point) in &self.cells[row as usize * self.cols + col as usize] {
+                        insert_k_best(&mut best, (point.distance(p), index), k);
+                    }
+                }
+            }
+            if best.len() == k && best[k - 1].0 < T::cast(ring) * self.cell_size {
+                break;
+            }
+        }
+        best
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct KdNode<T> {
+    index: usize,
+    point: Point<T>,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+// Düğümler eklendikçe büyüyen iki boyutlu k-d ağacı; derinliğe göre sırayla x ve y ekseninde
+// bölünür. Eklemeler ağacı dengesiz büyütebildiğinden (ör. ağaç bir yöne doğru ilerlerken), son
+// yeniden kurulumdan beri yapılan ekleme sayısı ağaç boyunun `rebalance_fraction` oranını aşınca
+// ağaç ortancalardan bölünerek baştan kurulur; maliyet eklemelere yayılır. Oran sıfırsa yeniden
+// dengeleme yapılmaz. Eşit uzaklıklarda küçük indeks seçildiğinden sorgu sonuçları ağacın
+// biçiminden bağımsızdır.
+#[derive(Clone, Debug)]
+pub struct KdTree<T = f32> {
+    nodes: Vec<KdNode<T>>,
+    rebalance_fraction: T,
+    inserted_since_rebuild: usize,
+    rebuilds: usize,
+}
+
+impl<T: Scalar> Default for KdTree<T> {
+    fn default() -> Self {
+        KdTree { nodes: Vec::new(), rebalance_fraction: T::zero(), inserted_since_rebuild: 0, rebuilds: 0 }
+    }
+}
+
+impl<T: Scalar> KdTree<T> {
+    pub fn new() -> Self {
+        KdTree::default()
+    }
+
+    pub fn with_rebalance_fraction(rebalance_fraction: T) -> Self {
+        KdTree { rebalance_fraction, ..KdTree::default() }
+    }
+
+    pub fn rebalance_fraction(&self) -> T {
+        self.rebalance_fraction
+    }
+
+    // Sonraki eklemelerden itibaren geçerlidir; ağaç hemen yeniden kurulmaz
+    pub fn set_rebalance_fraction(&mut self, rebalance_fraction: T) {
+        self.rebalance_fraction = rebalance_fraction;
+    }
+
+    // Ağacın kaç kez baştan kurulduğu
+    pub fn rebuilds(&self) -> usize {
+        self.rebuilds
+    }
+
+    // Kökten en derin yaprağa kadar olan düğüm sayısı
+    pub fn depth(&self) -> usize {
+        let mut deepest = 0;
+        let mut stack = if self.nodes.is_empty() { Vec::new() } else { vec![(0usize, 1usize)] };
+        while let Some((current, depth)) = stack.pop() {
+            deepest = deepest.max(depth);
+            let node = &self.nodes[current];
+            stack.extend(node.left.into_iter().chain(node.right).map(|child| (child, depth + 1)));
+        }
+        deepest
+    }
+
+    fn axis_value(p: &Point<T>, depth: usize) -> T {
+        if depth.is_multiple_of(2) {
+            p.x
+        } else {
+            p.y
+        }
+    }
+
+    pub fn insert(&mut self, index: usize, point: Point<T>) {
+        self.insert_unbalanced(index, point);
+        self.inserted_since_rebuild += 1;
+        if self.rebalance_fraction > T::zero() && T::cast(self.inserted_since_rebuild) > self.rebalance_fraction * T::cast(self.nodes.len()) {
+            let entries: Vec<(usize, Point<T>)> = self.nodes.iter().map(|n| (n.index, n.point)).collect();
+            self.rebuild(entries);
+        }
+    }
+
+    fn insert_unbalanced(&mut self, index: usize, point: Point<T>) {
+        let new = self.nodes.len();
+        self.nodes.push(KdNode { index, point, left: None, right: None });
+        if new == 0 {
+            return;
+        }
+        let (mut current, mut depth) = (0, 0);
+        loop {
+            let node = self.nodes[current];
+            let go_left = Self::axis_value(&point, depth) < Self::axis_value(&node.point, depth);
+            let child = if go_left { node.left } else { node.right };
+            match child {
+                Some(child) => current = child,
+                None => {
+                    if go_left {
+                        self.nodes[current].left = Some(new);
+                    } else {
+                        self.nodes[current].right = Some(new);
+                    }
+                    return;
+                }
+            }
+            depth += 1;
+        }
+    }
+
+    // Ağacı verilen noktalarla dengeli olarak baştan kurar: her düzeyde o eksendeki ortanca düğüm
+    // olur, küçükler sola, büyükler sağa gider (eşitler iki tarafa da düşebilir; arama yine doğrudur)
+    pub fn rebuild(&mut self, mut entries: Vec<(usize, Point<T>)>) {
+        self.nodes.clear();
+        self.nodes.reserve(entries.len());
+        self.build_balanced(&mut entries, 0);
+        self.inserted_since_rebuild = 0;
+        self.rebuilds += 1;
+    }
+
+    fn build_balanced(&mut self, entries: &mut [(usize, Point<T>)], depth: usize) -> Option<usize> {
+        if entries.is_empty() {
+            return None;
+        }
+        let median = entries.len() / 2;
+        entries.select_nth_unstable_by(median, |a, b| {
+            Self::axis_value(&a.1, depth).partial_cmp(&Self::axis_value(&b.1, depth)).unwrap_or(Ordering::Equal)
+        });
+        let (index, point) = entries[median];
+        let current = self.nodes.len();
+        self.nodes.push(KdNode { index, point, left: None, right: None });
+        let (smaller, rest) = entries.split_at_mut(median);
+        let left = self.build_balanced(smaller, depth + 1);
+        let right = self.build_balanced(&mut rest[1..], depth + 1);
+        self.nodes[current].left = left;
+        self.nodes[current].right = right;
+        Some(current)
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.inserted_since_rebuild = 0;
+    }
+
+    // Önce sorgunun düştüğü taraf aranır; bölme düzlemine olan uzaklık bulunan en iyi uzaklıktan
+    // büyük değilse diğer tarafa da bakılır. Sıralı eklemelerde ağaç çok derinleşebildiğinden
+    // özyineleme yerine açık bir yığın kullanılır.
+    pub fn nearest(&self, p: &Point<T>) -> Option<usize> {
+        self.nearest_where(p, |_| true)
+    }
+
+    // `keep` koşulunu sağlayan indeksler arasında en yakın nokta; koşulu sağlamayan düğümler aday
+    // sayılmaz ama bölme düzlemi olarak aramayı yönlendirmeye devam eder
+    pub fn nearest_where<F: Fn(usize) -> bool>(&self, p: &Point<T>, keep: F) -> Option<usize> {
+        let mut best: Option<(T, usize)> = None;
+        if self.nodes.is_empty() {
+            return None;
+        }
+        // (düğüm, derinlik, bu alt ağaçtaki noktalara olan en küçük olası uzaklık)
+        let mut stack = vec![(0usize, 0usize, T::zero())];
+        while let Some((current, depth, bound)) = stack.pop() {
+            if best.is_some_and(|(d, _)| bound > d) {
+                continue;
+            }
+            let node = &self.nodes[current];
+            let candidate = (node.point.distance(p), node.index);
+            if keep(node.index) && closer(candidate, best) {
+                best = Some(candidate);
+            }
+            let diff = Self::axis_value(p, depth) - Self::axis_value(&node.point, depth);
+            let (near, far) = if diff < T::zero() { (node.left, node.right) } else { (node.right, node.left) };
+            // Yakın taraf önce aransın diye yığına en son o konur
+            if let Some(far) = far {
+                stack.push((far, depth + 1, bound.max(diff.abs())));
+            }
+            if let Some(near) = near {
+                stack.push((near, depth + 1, bound));
+            }
+        }
+        best.map(|(_, index)| index)
+    }
+
+    // `p` noktasına en fazla `r` uzaklıktaki noktalar; bölme düzlemi `r`'den uzak kalan taraflar
+    // atlanır. Sonuç uzaklığa göre sıralı değildir.
+    pub fn within(&self, p: &Point<T>, r: T) -> Vec<(T, usize)> {
+        let mut found = Vec::new();
+        if self.nodes.is_empty() || r < T::zero() || r.is_nan() {
+            return found;
+        }
+        let mut stack = vec![(0usize, 0usize)];
+        while let Some((current, depth)) = stack.pop() {
+            let node = &self.nodes[current];
+            let d = node.point.distance(p);
+            if d <= r {
+                found.push((d, node.index));
+            }
+            let diff = Self::axis_value(p, depth) - Self::axis_value(&node.point, depth);
+            let (near, far) = if diff < T::zero() { (node.left, node.right) } else { (node.right, node.left) };
+            if let Some(near) = near {
+                stack.push((near, depth + 1));
+            }
+            if let (Some(far), true) = (far, diff.abs() <= r) {
+                stack.push((far, depth + 1));
+            }
+        }
+        found
+    }
+
+    // En yakın `k` nokta, uzaklığa göre sıralı. `nearest` ile aynı arama; bir alt ağaç, alt sınırı
+    // bulunan `k`. en iyi uzaklıktan büyükse atlanır.
+    pub fn k_nearest(&self, p: &Point<T>, k: usize) -> Vec<(T, usize)> {
+        let mut best = Vec::new();
+        if self.nodes.is_empty() || k == 0 {
+            return best;
+        }
+        let mut stack = vec![(0usize, 0usize, T::zero())];
+        while let Some((current, depth, bound)) = stack.pop() {
+            if best.len() == k && bound > best[k - 1].0 {
+                continue;
+            }
+            let node = &self.nodes[current];
+            insert_k_best(&mut best, (node.point.distance(p), node.index), k);
+            let diff = Self::axis_value(p, depth) - Self::axis_value(&node.point, depth);
+            let (near, far) = if diff < T::zero() { (node.left, node.right) } else { (node.right, node.left) };
+            if let Some(far) = far {
+                stack.push((far, depth + 1, bound.max(diff.abs())));
+            }
+            if let Some(near) = near {
+                stack.push((near, depth + 1, bound));
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NeighborIndexKind;
+    use crate::rrt::RRT;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn kd_tree_rebalancing_keeps_query_results_and_bounds_depth() {
+        let mut rng = StdRng::seed_from_u64(4);
+        // Çapraz boyunca sıralı gelen noktalar dengelenmeyen ağacı zincire çevirir
+        let points: Vec<Point> = (0..3000)
+            .map(|i| {
+                let t = i as f32 * 0.1;
+                Point { x: t + rng.gen_range(-1.0..1.0), y: t + rng.gen_range(-1.0..1.0) }
+            })
+            .collect();
+        let mut plain = KdTree::new();
+        let mut balanced = KdTree::with_rebalance_fraction(0.3);
+        for (i, &p) in points.iter().enumerate() {
+            plain.insert(i, p);
+            balanced.insert(i, p);
+        }
+        assert_eq!(plain.rebuilds(), 0);
+        assert!(balanced.rebuilds() > 0);
+        assert!(plain.depth() > 500, "unbalanced depth {}", plain.depth());
+        assert!(balanced.depth() < 60, "balanced depth {}", balanced.depth());
+
+        let mut trees: Vec<RRT> = [0.0, 0.3]
+            .iter()
+            .map(|&fraction| {
+                let mut rrt = RRT::new(points[0], Point { x: 1e6, y: 1e6 }, 10.0, 1.0);
+                rrt.set_neighbor_index(NeighborIndexKind::KdTree, 10.0);
+                rrt.set_kd_tree_rebalance(fraction);
+                rrt
+            })
+            .collect();
+        for rrt in &mut trees {
+            for (i, &p) in points.iter().enumerate().skip(1) {
+                rrt.add_node(p, i - 1);
+            }
+        }
+        for _ in 0..300 {
+            let q = Point { x: rng.gen_range(-20.0..320.0), y: rng.gen_range(-20.0..320.0) };
+            assert_eq!(trees[0].find_nearest(&q), trees[1].find_nearest(&q));
+            assert_eq!(trees[0].k_nearest(&q, 7), trees[1].k_nearest(&q, 7));
+            assert_eq!(trees[0].neighbors_within(&q, 15.0), trees[1].neighbors_within(&q, 15.0));
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// Boyut sayısından bağımsız planlama: durum soyutlaması ve yalnızca bu soyutlamayı kullanan
+// çekirdek RRT ağacı. Görselleştirmedeki `RRT` iki boyutlu `Point` için bu soyutlamanın
+// uzaklık ve yönlendirme tanımlarını kullanır; engeller, ızgara indeksleri ve çizim ise yalnızca
+// iki boyutta tanımlı olduğundan oradadır.
+use std::fmt::Debug;
+
+use num_traits::Zero;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::obstacle::Bounds;
+use crate::rrt::{seeded_rng, Point};
+use crate::scalar::Scalar;
+
+// Yapılandırma uzayındaki bir durum (ör. düzlemde bir nokta veya bir kolun eklem açıları)
+pub trait State: Copy + Debug {
+    type Scalar: Scalar;
+    // Düzgün örneklemenin yapıldığı alan
+    type Bounds;
+
+    fn distance(&self, other: &Self) -> Self::Scalar;
+
+    // `self` ile `other` arasında doğrusal ara değer: t = 0'da `self`, t = 1'de `other`
+    fn interpolate(&self, other: &Self, t: Self::Scalar) -> Self;
+
+    fn sample_uniform<R: Rng + ?Sized>(bounds: &Self::Bounds, rng: &mut R) -> Self;
+
+    // `to` yönünde en fazla `step` kadar ilerler; `to` daha yakınsa üzerinde durur
+    fn steer(&self, to: &Self, step: Self::Scalar) -> Self {
+        let d = self.distance(to);
+        if d <= step {
+            *to
+        } else {
+            self.interpolate(to, step / d)
+        }
+    }
+}
+
+impl<T: Scalar> State for Point<T> {
+    type Scalar = T;
+    type Bounds = Bounds<T>;
+
+    fn distance(&self, other: &Self) -> T {
+        Point::distance(self, other)
+    }
+
+    fn interpolate(&self, other: &Self, t: T) -> Self {
+        Point::lerp(*self, *other, t)
+    }
+
+    // Önce x sonra y çekilir; `RectRegion` (dolayısıyla `RRT::random_point`) ile aynı sayı dizisi
+    fn sample_uniform<R: Rng + ?Sized>(bounds: &Bounds<T>, rng: &mut R) -> Self {
+        Point { x: rng.gen_range(bounds.min_x..bounds.max_x), y: rng.gen_range(bounds.min_y..bounds.max_y) }
+    }
+
+    // Bugüne kadarki açı tabanlı yönlendirme; iki boyutlu ağaçlar aynı noktaları üretmeye devam eder
+    fn steer(&self, to: &Self, step: T) -> Self {
+        let step = step.min(self.distance(to));
+        let angle = (to.y - self.y).atan2(to.x - self.x);
+        Point { x: self.x + step * angle.cos(), y: self.y + step * angle.sin() }
+    }
+}
+
+// N boyutlu öklid uzayında durum; alan her eksen için (en küçük, en büyük) çiftidir
+impl<T: Scalar, const N: usize> State for [T; N] {
+    type Scalar = T;
+    type Bounds = [(T, T); N];
+
+    fn distance(&self, other: &Self) -> T {
+        self.iter().zip(other).fold(T::zero(), |sum, (a, b)| sum + (*a - *b).powi(2)).sqrt()
+    }
+
+    fn interpolate(&self, other: &Self, t: T) -> Self {
+        std::array::from_fn(|i| self[i] + (other[i] - self[i]) * t)
+    }
+
+    fn sample_uniform<R: Rng + ?Sized>(bounds: &[(T, T); N], rng: &mut R) -> Self {
+        std::array::from_fn(|i| rng.gen_range(bounds[i].0..bounds[i].1))
+    }
+}
+
+// Çekirdek ağacın düğümü
+#[derive(Clone, Copy, Debug)]
+pub struct StateNode<S: State> {
+    pub state: S,
+    pub parent: Option<usize>,
+    pub cost: S::Scalar,
+}
+
+// Yalnızca `State` üzerinden çalışan RRT: en yakın düğüm doğrusal taramayla bulunur, durum ve
+// hareket geçerliliği çağıran tarafından verilir (ör. kolun engellere çarpıp çarpmadığı)
+pub struct StateTree<S: State> {
+    pub nodes: Vec<StateNode<S>>,
+    pub goal: S,
+    pub bounds: S::Bounds,
+    pub step_size: S::Scalar,
+    pub goal_threshold: S::Scalar,
+    pub goal_bias: S::Scalar, // Hedefin doğrudan örneklenme olasılığı
+    pub goal_node: Option<usize>, // Hedef bölgesine ilk ulaşan düğüm
+    rng: StdRng,
+}
+
+impl<S: State> StateTree<S> {
+    pub fn new(start: S, goal: S, bounds: S::Bounds, step_size: S::Scalar, goal_threshold: S::Scalar) -> Self {
+        StateTree {
+            nodes: vec![StateNode { state: start, parent: None, cost: S::Scalar::zero() }],
+            goal,
+            bounds,
+            step_size,
+            goal_threshold,
+            goal_bias: S::Scalar::zero(),
+            goal_node: None,
+            rng: seeded_rng(None),
+        }
+    }
+
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = seeded_rng(Some(seed));
+    }
+
+    pub fn sample(&mut self) -> S {
+        if S::Scalar::unit(&mut self.rng) < self.goal_bias {
+            return self.goal;
+        }
+        S::sample_uniform(&self.bounds, &mut self.rng)
+    }
+
+    // En yakın düğüm; uzaklığı eşit düğümlerden indeksi küçük olan döner
+    pub fn find_nearest(&self, state: &S) -> usize {
+        let mut best = (0, self.nodes[0].state.distance(state));
+        for (index, node) in self.nodes.iter().enumerate().skip(1) {
+            let d = node.state.distance(state);
+            if d < best.1 {
+                best = (index, d);
+            }
+        }
+        best.0
+    }
+
+    pub fn steer(&self, from: &S, to: &S) -> S {
+        from.steer(to, self.step_size)
+    }
+
+    pub fn in_goal_region(&self, state: &S) -> bool {
+        state.distance(&self.goal) < self.goal_threshold
+    }
+
+    pub fn add_node(&mut self, state: S, parent: usize) -> usize {
+        let cost = self.nodes[parent].cost + self.nodes[parent].state.distance(&state);
+        self.nodes.push(StateNode { state, parent: Some(parent), cost });
+        let index = self.nodes.len() - 1;
+        if self.goal_node.is_none() && self.in_goal_region(&state) {
+            self.goal_node = Some(index);
+        }
+        index
+    }
+
+    // Tek bir genişletme iterasyonu; hareket geçerliyse eklenen düğümün indeksi döner
+    pub fn extend<F: FnMut(&S, &S) -> bool>(&mut self, is_motion_valid: &mut F) -> Option<usize> {
+        let sample = self.sample();
+        let nearest = self.find_nearest(&sample);
+        let from = self.nodes[nearest].state;
+        let new_state = self.steer(&from, &sample);
+        is_motion_valid(&from, &new_state).then(|| self.add_node(new_state, nearest))
+    }
+
+    // Kökten düğüme giden durumlar
+    pub fn path_to(&self, index: usize) -> Vec<S> {
+        let mut path = Vec::new();
+        let mut current = Some(index);
+        while let Some(i) = current {
+            path.push(self.nodes[i].state);
+            current = self.nodes[i].parent;
+        }
+        path.reverse();
+        path
+    }
+
+    // Hedefe ulaşılana veya iterasyon sınırına kadar büyür
+    pub fn plan<F: FnMut(&S, &S) -> bool>(&mut self, max_iters: usize, mut is_motion_valid: F) -> Option<Vec<S>> {
+        for _ in 0..max_iters {
+            if self.goal_node.is_some() {
+                break;
+            }
+            self.extend(&mut is_motion_valid);
+        }
+        self.goal_node.map(|goal| self.path_to(goal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_tree_plans_in_three_dimensions() {
+        let goal = [9.0, 9.0, 9.0];
+        let mut tree = StateTree::new([1.0, 1.0, 1.0], goal, [(0.0, 10.0); 3], 0.5, 0.5);
+        tree.goal_bias = 0.1;
+        tree.reseed(4);
+        // Ortadaki küpün içine düşen yeni durumlar geçersizdir
+        let outside_cube = |s: &[f64; 3]| s.iter().any(|c| !(3.0..=7.0).contains(c));
+        let path = tree.plan(20_000, |_, to| outside_cube(to)).expect("path found");
+        assert_eq!(path[0], [1.0, 1.0, 1.0]);
+        assert!(path.last().unwrap().distance(&goal) < 0.5);
+        for segment in path.windows(2) {
+            assert!(segment[0].distance(&segment[1]) <= 0.5 + 1e-9);
+            assert!(outside_cube(&segment[1]));
+        }
+    }
+}
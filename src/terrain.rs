@@ -0,0 +1,186 @@
+// Arazi maliyet haritası: serbest alanın her hücresi, içinden geçen kenarın metre başına maliyet
+// çarpanını taşır (ör. çamur geçilebilir ama yavaştır). Çarpışma kontrolünü değiştirmez; kenar
+// maliyeti uzunluk çarpı kenar boyunca ortalama çarpandır.
+use std::path::Path;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::grid::CellFrame;
+use crate::obstacle::Bounds;
+use crate::rrt::Point;
+use crate::scalar::Scalar;
+
+// Senaryo dosyasındaki `[terrain]` tablosu. Izgarada `costs[satır][sütun]` çarpanlardır; ilk satır
+// alanın küçük y kenarındadır ve hücreler alanı tam olarak böler. Gri tonlu görüntüde beyaz piksel
+// `min_cost`, siyah piksel `max_cost` çarpanıdır; görüntünün ilk satırı yine küçük y kenarıdır.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TerrainSpec {
+    Grid { costs: Vec<Vec<f32>> },
+    Image { path: String, min_cost: f32, max_cost: f32 },
+}
+
+impl TerrainSpec {
+    // Görüntüyü okuyup aynı çarpanlarla ızgaraya çevirir; ızgara olduğu gibi döner
+    pub fn load(self) -> image::ImageResult<TerrainSpec> {
+        match self {
+            TerrainSpec::Image { path, min_cost, max_cost } => {
+                Ok(TerrainSpec::Grid { costs: image_costs(path, min_cost, max_cost)? })
+            }
+            grid => Ok(grid),
+        }
+    }
+
+    pub fn cost_map<T: Scalar>(&self, bounds: Bounds<T>) -> image::ImageResult<CostMap<T>> {
+        let rows = match self {
+            TerrainSpec::Grid { costs } => costs.clone(),
+            TerrainSpec::Image { path, min_cost, max_cost } => image_costs(path, *min_cost, *max_cost)?,
+        };
+        let rows: Vec<Vec<T>> = rows.iter().map(|row| row.iter().map(|&c| T::cast(c)).collect()).collect();
+        Ok(CostMap::from_rows(bounds, &rows))
+    }
+}
+
+fn image_costs<P: AsRef<Path>>(path: P, min_cost: f32, max_cost: f32) -> image::ImageResult<Vec<Vec<f32>>> {
+    let image = image::open(path)?.into_luma8();
+    Ok(image
+        .rows()
+        .map(|row| row.map(|pixel| max_cost + (min_cost - max_cost) * f32::from(pixel.0[0]) / 255.0).collect())
+        .collect())
+}
+
+// Çarpanlar pozitiftir; sonsuz çarpanlı hücreden geçen kenarın maliyeti sonsuzdur ve hücre
+// örneklenmez
+#[derive(Clone, Debug)]
+pub struct CostMap<T = f32> {
+    pub frame: CellFrame<T>,
+    costs: Vec<T>, // Satır satır hücre çarpanları
+    min_cost: T,
+    max_cost: T,
+    // Maliyetle ters orantılı hücre ağırlıklarının birikimli toplamları; harita kurulurken bir kez
+    // hesaplanır, her örnekte ikili aramayla hücre seçilir
+    cumulative_weights: Vec<T>,
+}
+
+impl<T: Scalar> CostMap<T> {
+    // `costs` satır satır `frame` hücrelerinin çarpanlarıdır; uzunluk hücre sayısı olmalıdır
+    pub fn new(frame: CellFrame<T>, costs: Vec<T>) -> Self {
+        assert_eq!(costs.len(), frame.len(), "one cost per cell");
+        let min_cost = costs.iter().copied().fold(T::infinity(), T::min);
+        let max_cost = costs.iter().copied().fold(T::neg_infinity(), T::max);
+        let mut total = T::zero();
+        let cumulative_weights = costs
+            .iter()
+            .map(|&cost| {
+                total += T::one() / cost;
+                total
+            })
+            .collect();
+        CostMap { frame, costs, min_cost, max_cost, cumulative_weights }
+    }
+
+    // Alanı satır ve sütun sayısına göre tam olarak bölen ızgara; satırlar eşit uzunlukta olmalıdır
+    pub fn from_rows(bounds: Bounds<T>, rows: &[Vec<T>]) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(rows.iter().all(|row| row.len() == cols), "cost map rows differ in length");
+        CostMap::new(CellFrame::fitted(bounds, cols, rows.len()), rows.concat())
+    }
+
+    // Noktanın bulunduğu hücrenin çarpanı; alan dışında 1'dir
+    pub fn cost_at(&self, p: &Point<T>) -> T {
+        match self.frame.cell_of(p) {
+            Some((col, row)) => self.costs[row * self.frame.cols + col],
+            None => T::one(),
+        }
+    }
+
+    pub fn cell_cost(&self, col: usize, row: usize) -> T {
+        self.costs[row * self.frame.cols + col]
+    }
+
+    // Çarpanlar satır satır; `from_rows` ile aynı haritayı yeniden kurar
+    pub fn rows(&self) -> Vec<Vec<T>> {
+        self.costs.chunks(self.frame.cols.max(1)).map(<[T]>::to_vec).collect()
+    }
+
+    // Kenar boyunca `resolution` aralıklarla örneklenen noktalardaki çarpanların ortalaması
+    pub fn mean_cost(&self, from: &Point<T>, to: &Point<T>, resolution: T) -> T {
+        let steps = (from.distance(to) / resolution).ceil().max(T::one()).to_usize().unwrap_or(1);
+        let total = (0..=steps).fold(T::zero(), |total, i| total + self.cost_at(&Point::lerp(*from, *to, T::cast(i) / T::cast(steps))));
+        total / T::cast(steps + 1)
+    }
+
+    // Hücreyi maliyetiyle ters orantılı olasılıkla seçip içinde düzgün bir nokta çeker; tüm
+    // hücreler sonsuz maliyetliyse `None`
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Point<T>> {
+        let total = *self.cumulative_weights.last()?;
+        if total.is_nan() || total <= T::zero() {
+            return None;
+        }
+        let target = T::unit(rng) * total;
+        let cell = self.cumulative_weights.partition_point(|&w| w <= target).min(self.costs.len() - 1);
+        let (col, row) = (cell % self.frame.cols, cell / self.frame.cols);
+        let frame = &self.frame;
+        Some(Point {
+            x: frame.bounds.min_x + (T::cast(col) + T::unit(rng)) * frame.cell_width,
+            y: frame.bounds.min_y + (T::cast(row) + T::unit(rng)) * frame.cell_height,
+        })
+    }
+
+    pub fn min_cost(&self) -> T {
+        self.min_cost
+    }
+
+    pub fn max_cost(&self) -> T {
+        self.max_cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RRTConfig;
+    use crate::rrt::RRT;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn terrain_costs_steer_paths_onto_a_cheap_detour() {
+        // Başlangıç ile hedef arasında on kat pahalı bir bant; yalnızca sol kenardaki hücre ucuzdur
+        let config = RRTConfig::from_toml_file("scenarios/terrain.toml").expect("scenario loads");
+        let mut rrt = RRT::from_config(&config);
+        let terrain = rrt.terrain.as_ref().expect("terrain loaded");
+        assert_eq!(terrain.cost_at(&Point { x: 40.0, y: 200.0 }), 1.0);
+        assert_eq!(terrain.cost_at(&Point { x: 200.0, y: 200.0 }), 10.0);
+        let (a, b) = (Point { x: 200.0, y: 150.0 }, Point { x: 200.0, y: 250.0 });
+        assert!(rrt.edge_cost(&a, &b) > 5.0 * a.distance(&b));
+
+        rrt.plan(20_000).path().expect("path found");
+        for _ in 0..1_500 {
+            rrt.extend();
+        }
+        let path = rrt.best_path().unwrap();
+        let in_band = |p: &Point| p.y > 165.0 && p.y < 235.0;
+        assert!(path.iter().filter(|p| in_band(p)).all(|p| p.x < 80.0), "path crosses the expensive band");
+        // Dolambaçlı yol düz çizgiden uzun ama ucuzdur
+        let straight = rrt.edge_cost(&rrt.starts[0], &rrt.goal());
+        assert!(path.length() > 400.0 && rrt.best_cost().unwrap() < straight, "{} vs {}", rrt.best_cost().unwrap(), straight);
+    }
+
+    #[test]
+    fn terrain_images_map_white_to_min_cost_and_black_to_max_cost() {
+        let path = std::env::temp_dir().join(format!("rrt_terrain_{}.png", std::process::id()));
+        image::GrayImage::from_raw(2, 1, vec![255, 0]).unwrap().save(&path).unwrap();
+        let spec = TerrainSpec::Image { path: path.display().to_string(), min_cost: 1.0, max_cost: 5.0 };
+        let loaded = spec.load().expect("image loads");
+        std::fs::remove_file(&path).ok();
+        let TerrainSpec::Grid { costs } = &loaded else { panic!("image not converted to a grid") };
+        assert_eq!(costs, &vec![vec![1.0, 5.0]]);
+        let map = loaded.cost_map(Bounds::new(0.0, 100.0, 0.0, 50.0)).unwrap();
+        assert_eq!((map.cost_at(&Point { x: 10.0, y: 10.0 }), map.cost_at(&Point { x: 90.0, y: 40.0 })), (1.0, 5.0));
+        assert_close(map.mean_cost(&Point { x: 10.0, y: 25.0 }, &Point { x: 85.0, y: 25.0 }, 25.0), 3.0);
+    }
+}